@@ -0,0 +1,254 @@
+//! Interprets the fuzzer input as a sequence of operations, runs them on a `ReversibleList` and
+//! a `VecDeque` side by side and checks that both always agree, including where cursors point.
+//!
+//! Run with `cargo +nightly fuzz run differential` from the repository root.
+
+#![no_main]
+
+use std::collections::VecDeque;
+
+use arbitrary::{Result, Unstructured};
+use casual_linked_list::{cursor::CursorMut, ReversibleList};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // running out of input just ends the run early, everything up to there has been checked
+    let _ = run(&mut Unstructured::new(data));
+});
+
+fn run(u: &mut Unstructured<'_>) -> Result<()> {
+    let mut list = ReversibleList::new();
+    let mut model = VecDeque::new();
+    while !u.is_empty() {
+        step(u, &mut list, &mut model)?;
+        check(&list, &model);
+    }
+    Ok(())
+}
+
+fn check(list: &ReversibleList<u8>, model: &VecDeque<u8>) {
+    list.assert_invariants();
+    assert_eq!(list.len(), model.len());
+    assert!(list.iter().eq(model));
+    assert!(list.iter().rev().eq(model.iter().rev()));
+}
+
+fn step(
+    u: &mut Unstructured<'_>,
+    list: &mut ReversibleList<u8>,
+    model: &mut VecDeque<u8>,
+) -> Result<()> {
+    match u.choose_index(9)? {
+        0 => {
+            let item = u.arbitrary()?;
+            list.push_front(item);
+            model.push_front(item);
+        }
+        1 => {
+            let item = u.arbitrary()?;
+            list.push_back(item);
+            model.push_back(item);
+        }
+        2 => assert_eq!(list.pop_front(), model.pop_front()),
+        3 => assert_eq!(list.pop_back(), model.pop_back()),
+        4 => {
+            let idx = u.int_in_range(0..=model.len())?;
+            let item = u.arbitrary()?;
+            list.insert(idx, item);
+            model.insert(idx, item);
+        }
+        5 => {
+            // out of bounds on purpose every now and then
+            let idx = u.int_in_range(0..=model.len())?;
+            assert_eq!(list.get(idx), model.get(idx));
+            if let Some(item) = list.get_mut(idx) {
+                *item = item.wrapping_add(1);
+                model[idx] = model[idx].wrapping_add(1);
+            }
+        }
+        6 => {
+            // splice a whole other list in at some index
+            let items: Vec<u8> = u.arbitrary()?;
+            let idx = u.int_in_range(0..=model.len())?;
+            let mut rest = {
+                let mut cursor = list.cursor_mut_front();
+                if idx < model.len() {
+                    cursor.move_to(idx);
+                    cursor.split_rest()
+                } else {
+                    ReversibleList::new()
+                }
+            };
+            list.append(&mut items.iter().copied().collect());
+            list.append(&mut rest);
+            for (offset, &item) in items.iter().enumerate() {
+                model.insert(idx + offset, item);
+            }
+        }
+        7 => {
+            let divisor = u.int_in_range(1..=8)?;
+            let removed = list.remove_all(|item| item % divisor == 0);
+            let before = model.len();
+            model.retain(|item| item % divisor != 0);
+            assert_eq!(removed, before - model.len());
+        }
+        _ => {
+            let (cursor, idx) = if u.arbitrary()? {
+                (list.cursor_mut_front(), (!model.is_empty()).then_some(0))
+            } else {
+                (list.cursor_mut_back(), model.len().checked_sub(1))
+            };
+            cursor_session(u, cursor, model, idx)?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs a few cursor operations in a row, tracking the index the cursor should be at in `idx`.
+fn cursor_session(
+    u: &mut Unstructured<'_>,
+    mut cursor: CursorMut<'_, u8>,
+    model: &mut VecDeque<u8>,
+    mut idx: Option<usize>,
+) -> Result<()> {
+    for _ in 0..u.int_in_range(1..=16)? {
+        let len = model.len();
+        match u.choose_index(14)? {
+            0 => {
+                cursor.move_next();
+                idx = idx.map(|i| (i + 1) % len);
+            }
+            1 => {
+                cursor.move_prev();
+                idx = idx.map(|i| (i + len - 1) % len);
+            }
+            2 => {
+                let n = usize::from(u.arbitrary::<u8>()?);
+                cursor.move_next_n(n);
+                idx = idx.map(|i| (i + n % len) % len);
+            }
+            3 => {
+                let n = usize::from(u.arbitrary::<u8>()?);
+                cursor.move_prev_n(n);
+                idx = idx.map(|i| (i + len - n % len) % len);
+            }
+            4 if u.arbitrary()? => {
+                let n = usize::from(u.arbitrary::<u8>()?);
+                let target = if u.arbitrary()? {
+                    assert_eq!(cursor.try_move_next_n(n), idx.is_some_and(|i| i + n < len));
+                    idx.map(|i| i + n)
+                } else {
+                    assert_eq!(cursor.try_move_prev_n(n), idx.is_some_and(|i| n <= i));
+                    idx.and_then(|i| i.checked_sub(n))
+                };
+                idx = target.filter(|&i| i < len).or(idx);
+            }
+            4 if len > 0 => {
+                let target = u.int_in_range(0..=len - 1)?;
+                cursor.move_to(target);
+                idx = Some(target);
+            }
+            5 => {
+                let item = u.arbitrary()?;
+                cursor.insert_after(item);
+                match idx {
+                    Some(i) => model.insert(i + 1, item),
+                    None => {
+                        model.push_back(item);
+                        idx = Some(0);
+                    }
+                }
+            }
+            6 => {
+                let item = u.arbitrary()?;
+                cursor.insert_before(item);
+                match idx {
+                    Some(i) => {
+                        model.insert(i, item);
+                        idx = Some(i + 1);
+                    }
+                    None => {
+                        model.push_back(item);
+                        idx = Some(0);
+                    }
+                }
+            }
+            7 => {
+                let expected = idx.and_then(|i| model.remove(i));
+                assert_eq!(cursor.remove_current(), expected);
+                idx = step_off(idx, model.len());
+            }
+            8 => {
+                let swappable = idx.filter(|&i| i + 1 < len);
+                assert_eq!(cursor.swap_with_next(), swappable.is_some());
+                if let Some(i) = swappable {
+                    model.swap(i, i + 1);
+                    idx = Some(i + 1);
+                }
+            }
+            9 => {
+                let swappable = idx.filter(|&i| i > 0);
+                assert_eq!(cursor.swap_with_prev(), swappable.is_some());
+                if let Some(i) = swappable {
+                    model.swap(i, i - 1);
+                    idx = Some(i - 1);
+                }
+            }
+            10 => {
+                cursor.move_current_to_front();
+                if let Some(i) = idx {
+                    let item = model.remove(i).expect("cursor index is in bounds");
+                    model.push_front(item);
+                    idx = Some(step_off(idx, len - 1).map_or(0, |i| i + 1));
+                }
+            }
+            11 => {
+                cursor.move_current_to_back();
+                if let Some(i) = idx {
+                    let item = model.remove(i).expect("cursor index is in bounds");
+                    model.push_back(item);
+                    idx = Some(step_off(idx, len - 1).unwrap_or(0));
+                }
+            }
+            12 => {
+                let n = u.int_in_range(0..=len + 1)?;
+                let removed = cursor.remove_n(n);
+                removed.assert_invariants();
+                match idx.filter(|_| n > 0) {
+                    Some(i) => {
+                        let expected: Vec<u8> = model.drain(i..len.min(i + n)).collect();
+                        assert!(removed.iter().eq(&expected));
+                        idx = step_off(idx, model.len());
+                    }
+                    None => assert!(removed.is_empty()),
+                }
+            }
+            _ => {
+                let rest = cursor.split_rest();
+                rest.assert_invariants();
+                match idx {
+                    Some(i) => {
+                        assert!(rest.iter().eq(&model.split_off(i)));
+                        idx = i.checked_sub(1);
+                    }
+                    None => assert!(rest.is_empty()),
+                }
+            }
+        }
+
+        assert_eq!(cursor.index(), idx);
+        assert_eq!(cursor.current(), idx.map(|i| &model[i]));
+    }
+    Ok(())
+}
+
+/// Where the cursor ends up after the node at `idx` has been taken out, leaving `len` nodes:
+/// on the next node if there is one, otherwise on the previous one.
+fn step_off(idx: Option<usize>, len: usize) -> Option<usize> {
+    let i = idx?;
+    if i < len {
+        Some(i)
+    } else {
+        i.checked_sub(1)
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * C bindings for casual_linked_list, a doubly linked list of untyped pointers.
+ *
+ * Build the crate with the `capi` feature as a static or dynamic library, e.g. with
+ * `cargo rustc --release --features capi --crate-type staticlib`, and link against it.
+ *
+ * The list only stores the element pointers, whatever they point at stays owned by the caller.
+ * Functions returning `bool` return `false` on failure and leave their out-parameters untouched.
+ * See `src/capi.rs` for the full safety requirements of each function.
+ */
+
+#ifndef CASUAL_LINKED_LIST_H
+#define CASUAL_LINKED_LIST_H
+
+#include <stdbool.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct rl_list rl_list;
+typedef struct rl_cursor rl_cursor;
+
+rl_list *rl_new(void);
+void rl_free(rl_list *list);
+size_t rl_len(const rl_list *list);
+
+void rl_push_front(rl_list *list, void *item);
+void rl_push_back(rl_list *list, void *item);
+bool rl_pop_front(rl_list *list, void **out);
+bool rl_pop_back(rl_list *list, void **out);
+bool rl_get(const rl_list *list, size_t index, void **out);
+bool rl_insert(rl_list *list, size_t index, void *item);
+
+/* Until a cursor is freed, its list must only be accessed through it. */
+rl_cursor *rl_cursor_front(rl_list *list);
+rl_cursor *rl_cursor_back(rl_list *list);
+void rl_cursor_free(rl_cursor *cursor);
+
+void rl_cursor_move_next(rl_cursor *cursor);
+void rl_cursor_move_prev(rl_cursor *cursor);
+bool rl_cursor_current(const rl_cursor *cursor, void **out);
+bool rl_cursor_index(const rl_cursor *cursor, size_t *out);
+void rl_cursor_insert_after(rl_cursor *cursor, void *item);
+void rl_cursor_insert_before(rl_cursor *cursor, void *item);
+bool rl_cursor_remove_current(rl_cursor *cursor, void **out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
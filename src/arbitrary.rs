@@ -0,0 +1,41 @@
+//! [`Arbitrary`] implementation, behind the `arbitrary` feature.
+//!
+//! Instead of just collecting arbitrary elements, lists are built by a sequence of mixed
+//! [`push_front`](ReversibleList::push_front) and [`push_back`](ReversibleList::push_back)
+//! calls, so that fuzzers exercise lists which were built the same way as in the wild.
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+use allocator_api2::alloc::Allocator;
+
+use crate::ReversibleList;
+
+impl<'a, T: Arbitrary<'a>, A: Allocator + Default> Arbitrary<'a> for ReversibleList<T, A> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut list = ReversibleList::new_in(A::default());
+        for step in u.arbitrary_iter::<(bool, T)>()? {
+            push_in_dir(&mut list, step?);
+        }
+        Ok(list)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut list = ReversibleList::new_in(A::default());
+        for step in u.arbitrary_take_rest_iter::<(bool, T)>()? {
+            push_in_dir(&mut list, step?);
+        }
+        Ok(list)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// Pushes the item to the front if `to_front` is set, otherwise to the back.
+fn push_in_dir<T, A: Allocator>(list: &mut ReversibleList<T, A>, (to_front, item): (bool, T)) {
+    if to_front {
+        list.push_front(item);
+    } else {
+        list.push_back(item);
+    }
+}
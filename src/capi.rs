@@ -0,0 +1,266 @@
+//! C bindings for a list of untyped pointers.
+//!
+//! With the `capi` feature, the crate exports `extern "C"` functions for a
+//! [`ReversibleList<*mut c_void>`](ReversibleList), so C and C++ code can use the list too. The
+//! matching declarations are in `include/casual_linked_list.h`. To get something to link
+//! against, build the crate as a static or dynamic library, e.g. with
+//! `cargo rustc --release --features capi --crate-type staticlib`.
+//!
+//! Lists and cursors are handed out as opaque pointers, which have to be released again with
+//! [`rl_free`] and [`rl_cursor_free`]. The list only stores the element pointers, whatever they
+//! point at stays owned by the caller.
+//!
+//! None of the functions panic. Instead, those which can fail return `false` and leave their
+//! out-parameters untouched.
+
+use std::ffi::c_void;
+
+use crate::{cursor::CursorMut, ReversibleList};
+
+/// Opaque list handle, `rl_list` in C.
+pub type RlList = ReversibleList<*mut c_void>;
+
+/// Opaque mutable cursor handle, `rl_cursor` in C.
+///
+/// The lifetime is a lie: a cursor is only valid until it's freed, and the list it points into
+/// must not be used or freed in the meantime.
+pub type RlCursor = CursorMut<'static, *mut c_void>;
+
+/// Creates an empty list. Never returns null.
+#[no_mangle]
+pub extern "C" fn rl_new() -> *mut RlList {
+    Box::into_raw(Box::new(ReversibleList::new()))
+}
+
+/// Frees the list and all its nodes. The elements themselves aren't touched. Does nothing if
+/// `list` is null.
+///
+/// # Safety
+///
+/// `list` must be null or have been returned by [`rl_new`], and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn rl_free(list: *mut RlList) {
+    if !list.is_null() {
+        // SAFETY: Delegated to the caller.
+        drop(unsafe { Box::from_raw(list) });
+    }
+}
+
+/// Returns how many elements are in the list.
+///
+/// # Safety
+///
+/// `list` must be a valid list, as returned by [`rl_new`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_len(list: *const RlList) -> usize {
+    // SAFETY: Delegated to the caller.
+    unsafe { (*list).len() }
+}
+
+/// Inserts `item` before the first element.
+///
+/// # Safety
+///
+/// `list` must be a valid list, as returned by [`rl_new`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_push_front(list: *mut RlList, item: *mut c_void) {
+    // SAFETY: Delegated to the caller.
+    unsafe { (*list).push_front(item) };
+}
+
+/// Appends `item` after the last element.
+///
+/// # Safety
+///
+/// `list` must be a valid list, as returned by [`rl_new`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_push_back(list: *mut RlList, item: *mut c_void) {
+    // SAFETY: Delegated to the caller.
+    unsafe { (*list).push_back(item) };
+}
+
+/// Removes the first element and writes it to `out`. Returns `false` if the list is empty.
+///
+/// # Safety
+///
+/// `list` must be a valid list, as returned by [`rl_new`], and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn rl_pop_front(list: *mut RlList, out: *mut *mut c_void) -> bool {
+    // SAFETY: Delegated to the caller.
+    unsafe { write_out((*list).pop_front(), out) }
+}
+
+/// Removes the last element and writes it to `out`. Returns `false` if the list is empty.
+///
+/// # Safety
+///
+/// `list` must be a valid list, as returned by [`rl_new`], and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn rl_pop_back(list: *mut RlList, out: *mut *mut c_void) -> bool {
+    // SAFETY: Delegated to the caller.
+    unsafe { write_out((*list).pop_back(), out) }
+}
+
+/// Writes the element at `index` to `out`. Returns `false` if `index` is out of bounds.
+///
+/// # Safety
+///
+/// `list` must be a valid list, as returned by [`rl_new`], and `out` must be valid for
+/// writes.
+#[no_mangle]
+pub unsafe extern "C" fn rl_get(list: *const RlList, index: usize, out: *mut *mut c_void) -> bool {
+    // SAFETY: Delegated to the caller.
+    unsafe { write_out((*list).get(index).copied(), out) }
+}
+
+/// Inserts `item` at `index`, shifting all elements after it back. Returns `false` if `index`
+/// is larger than the length of the list.
+///
+/// # Safety
+///
+/// `list` must be a valid list, as returned by [`rl_new`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_insert(list: *mut RlList, index: usize, item: *mut c_void) -> bool {
+    // SAFETY: Delegated to the caller.
+    let list = unsafe { &mut *list };
+    if list.len() < index {
+        return false;
+    }
+    list.insert(index, item);
+    true
+}
+
+/// Creates a cursor pointing at the first element, or nowhere if the list is empty. Never
+/// returns null.
+///
+/// # Safety
+///
+/// `list` must be a valid list, as returned by [`rl_new`]. Until the cursor is freed with
+/// [`rl_cursor_free`], the list must only be accessed through the cursor.
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_front(list: *mut RlList) -> *mut RlCursor {
+    // SAFETY: Delegated to the caller.
+    Box::into_raw(Box::new(unsafe { (*list).cursor_mut_front() }))
+}
+
+/// Creates a cursor pointing at the last element, see [`rl_cursor_front`].
+///
+/// # Safety
+///
+/// Same as [`rl_cursor_front`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_back(list: *mut RlList) -> *mut RlCursor {
+    // SAFETY: Delegated to the caller.
+    Box::into_raw(Box::new(unsafe { (*list).cursor_mut_back() }))
+}
+
+/// Frees the cursor, giving access to its list back. Does nothing if `cursor` is null.
+///
+/// # Safety
+///
+/// `cursor` must be null or have been returned by [`rl_cursor_front`] or [`rl_cursor_back`],
+/// and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_free(cursor: *mut RlCursor) {
+    if !cursor.is_null() {
+        // SAFETY: Delegated to the caller.
+        drop(unsafe { Box::from_raw(cursor) });
+    }
+}
+
+/// Moves the cursor to the next element, wrapping around to the first one at the end.
+///
+/// # Safety
+///
+/// `cursor` must be a valid cursor, as returned by [`rl_cursor_front`] or [`rl_cursor_back`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_move_next(cursor: *mut RlCursor) {
+    // SAFETY: Delegated to the caller.
+    unsafe { (*cursor).move_next() };
+}
+
+/// Moves the cursor to the previous element, wrapping around to the last one at the start.
+///
+/// # Safety
+///
+/// Same as [`rl_cursor_move_next`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_move_prev(cursor: *mut RlCursor) {
+    // SAFETY: Delegated to the caller.
+    unsafe { (*cursor).move_prev() };
+}
+
+/// Writes the current element to `out`. Returns `false` if the list is empty.
+///
+/// # Safety
+///
+/// Same as [`rl_cursor_move_next`], and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_current(cursor: *const RlCursor, out: *mut *mut c_void) -> bool {
+    // SAFETY: Delegated to the caller.
+    unsafe { write_out((*cursor).current().copied(), out) }
+}
+
+/// Writes the index of the current element to `out`. Returns `false` if the list is empty.
+///
+/// # Safety
+///
+/// Same as [`rl_cursor_move_next`], and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_index(cursor: *const RlCursor, out: *mut usize) -> bool {
+    // SAFETY: Delegated to the caller.
+    unsafe { write_out((*cursor).index(), out) }
+}
+
+/// Inserts `item` after the current element, or as the only one if the list is empty.
+///
+/// # Safety
+///
+/// Same as [`rl_cursor_move_next`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_insert_after(cursor: *mut RlCursor, item: *mut c_void) {
+    // SAFETY: Delegated to the caller.
+    unsafe { (*cursor).insert_after(item) };
+}
+
+/// Inserts `item` before the current element, or as the only one if the list is empty.
+///
+/// # Safety
+///
+/// Same as [`rl_cursor_move_next`].
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_insert_before(cursor: *mut RlCursor, item: *mut c_void) {
+    // SAFETY: Delegated to the caller.
+    unsafe { (*cursor).insert_before(item) };
+}
+
+/// Removes the current element and writes it to `out`. Afterwards, the cursor points at the
+/// next element, or the previous one if there is none. Returns `false` if the list is empty.
+///
+/// # Safety
+///
+/// Same as [`rl_cursor_move_next`], and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn rl_cursor_remove_current(
+    cursor: *mut RlCursor,
+    out: *mut *mut c_void,
+) -> bool {
+    // SAFETY: Delegated to the caller.
+    unsafe { write_out((*cursor).remove_current(), out) }
+}
+
+/// Writes `value` to `out` if there is one, and returns whether there was.
+///
+/// # Safety
+///
+/// `out` must be valid for writes.
+unsafe fn write_out<T>(value: Option<T>, out: *mut T) -> bool {
+    let Some(value) = value else {
+        return false;
+    };
+    // SAFETY: Delegated to the caller.
+    unsafe { out.write(value) };
+    true
+}
@@ -0,0 +1,130 @@
+//! Fixed-capacity inline storage, used as node payload by [`crate::unrolled`] and as inline
+//! storage by [`crate::small`].
+
+use std::{mem::MaybeUninit, ptr, slice};
+
+/// Up to `N` elements stored inline, always starting at index 0.
+pub(crate) struct Chunk<T, const N: usize> {
+    pub(crate) len: usize,
+    items: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> Chunk<T, N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            len: 0,
+            items: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    pub(crate) fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `len` items are always initialized.
+        unsafe { slice::from_raw_parts(self.items.as_ptr().cast(), self.len) }
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: Same as `Self::as_slice`.
+        unsafe { slice::from_raw_parts_mut(self.items.as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Inserts the given item at `idx`, shifting everything after it by one. The chunk must not
+    /// be full and `idx` must not be larger than its length.
+    pub(crate) fn insert(&mut self, idx: usize, item: T) {
+        assert!(!self.is_full() && idx <= self.len);
+
+        // SAFETY: There's space for one more item, and only initialized items are shifted.
+        unsafe {
+            let at = self.items.as_mut_ptr().add(idx);
+            ptr::copy(at, at.add(1), self.len - idx);
+            at.write(MaybeUninit::new(item));
+        }
+        self.len += 1;
+    }
+
+    /// Removes the item at `idx`, shifting everything after it by one. `idx` must be in range.
+    pub(crate) fn remove(&mut self, idx: usize) -> T {
+        assert!(idx < self.len);
+
+        // SAFETY: The item at `idx` is initialized and is moved out exactly once, the gap is
+        //         closed right after.
+        unsafe {
+            let at = self.items.as_mut_ptr().add(idx);
+            let item = at.read().assume_init();
+            ptr::copy(at.add(1), at, self.len - idx - 1);
+            self.len -= 1;
+            item
+        }
+    }
+
+    /// Moves the upper half of the items into a new chunk and returns it.
+    pub(crate) fn split_off_half(&mut self) -> Self {
+        let keep = self.len / 2;
+        let mut upper = Self::new();
+
+        // SAFETY: The items in `keep..len` are initialized and moved over exactly once.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.items.as_ptr().add(keep),
+                upper.items.as_mut_ptr(),
+                self.len - keep,
+            );
+        }
+        upper.len = self.len - keep;
+        self.len = keep;
+
+        upper
+    }
+
+    /// Moves the first `count` items of `other` to the end of this chunk, which must have space
+    /// for them.
+    pub(crate) fn take_front_of(&mut self, other: &mut Self, count: usize) {
+        assert!(count <= other.len && self.len + count <= N);
+
+        // SAFETY: The first `count` items of `other` are initialized and moved over exactly
+        //         once, then the rest of `other` is shifted down to close the gap.
+        unsafe {
+            let from = other.items.as_mut_ptr();
+            ptr::copy_nonoverlapping(from, self.items.as_mut_ptr().add(self.len), count);
+            ptr::copy(from.add(count), from, other.len - count);
+        }
+        self.len += count;
+        other.len -= count;
+    }
+
+    /// Moves the last `count` items of `other` to the start of this chunk, which must have space
+    /// for them.
+    pub(crate) fn take_back_of(&mut self, other: &mut Self, count: usize) {
+        assert!(count <= other.len && self.len + count <= N);
+
+        // SAFETY: Own items are shifted up to make room, then the last `count` items of `other`
+        //         are moved over exactly once.
+        unsafe {
+            let to = self.items.as_mut_ptr();
+            ptr::copy(to, to.add(count), self.len);
+            ptr::copy_nonoverlapping(other.items.as_ptr().add(other.len - count), to, count);
+        }
+        self.len += count;
+        other.len -= count;
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for Chunk<T, N> {
+    fn clone(&self) -> Self {
+        let mut chunk = Self::new();
+        for item in self.as_slice() {
+            chunk.insert(chunk.len, item.clone());
+        }
+        chunk
+    }
+}
+
+impl<T, const N: usize> Drop for Chunk<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: The first `len` items are initialized and won't be accessed anymore.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
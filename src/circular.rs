@@ -0,0 +1,294 @@
+//! A ring of elements without a fixed start, for round-robin scheduling and the like.
+//!
+//! A [`CircularList`] is a [`ReversibleList`] whose last element is followed by its first one.
+//! Instead of a front and a back, it has a _head_, which is simply where iteration currently
+//! begins. Rotating the ring just picks another head: once the new head is found, the old ends
+//! are linked together and the ring is opened up right before the new head, so every rotation
+//! relinks only four pointers, no matter how far it goes.
+
+use std::{fmt, iter::FusedIterator};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::{cursor, handle, MaybePointer, Pointer, ReversibleList};
+
+/// A circular doubly linked list.
+///
+/// See the module docs for details.
+pub struct CircularList<T, A: Allocator = Global> {
+    /// The head is the start of this list.
+    list: ReversibleList<T, A>,
+}
+
+impl<T> CircularList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from(ReversibleList::new())
+    }
+
+    /// Splices all elements of `other` into this ring right before the head, in _O_(1), so
+    /// they're reached last when going around from the head. Leaves `other` empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.list.append(&mut other.list);
+    }
+}
+
+impl<T, A: Allocator> CircularList<T, A> {
+    /// Creates an empty ring which allocates its nodes using the given allocator.
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
+        Self::from(ReversibleList::new_in(alloc))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the element at the head, or `None` if the ring is empty.
+    #[must_use]
+    pub fn head(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    /// Returns the element at the head mutably, or `None` if the ring is empty.
+    pub fn head_mut(&mut self) -> Option<&mut T> {
+        self.list.get_mut(0)
+    }
+
+    /// Returns the element `idx` steps after the head, going around as often as needed, or
+    /// `None` if the ring is empty.
+    #[must_use]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        self.list.get(idx.checked_rem(self.len())?)
+    }
+
+    /// Returns an iterator going around the ring exactly once, starting at the head.
+    pub fn iter(&self) -> Iter<'_, T, A> {
+        Iter {
+            node: self.list.start,
+            remaining: self.len(),
+            list: &self.list,
+        }
+    }
+
+    /// Returns an iterator going around the ring exactly once, starting `idx` steps after the
+    /// head, without rotating it.
+    pub fn iter_from(&self, idx: usize) -> Iter<'_, T, A> {
+        let node = idx
+            .checked_rem(self.len())
+            .map(|idx| self.list.node_at(idx));
+        Iter {
+            node,
+            remaining: self.len(),
+            list: &self.list,
+        }
+    }
+
+    /// Inserts the given item right before the head, so it's reached last when going around
+    /// from the head. Returns a [`handle::NodeHandle`] to the new node.
+    pub fn push(&mut self, item: T) -> handle::NodeHandle<T> {
+        self.list.push_back(item)
+    }
+
+    /// Inserts the given item right after the head, or as the head if the ring is empty.
+    /// Returns a [`handle::NodeHandle`] to the new node.
+    pub fn push_after_head(&mut self, item: T) -> handle::NodeHandle<T> {
+        let idx = usize::from(!self.is_empty());
+        self.list.insert(idx, item)
+    }
+
+    /// Removes the head and returns it. The element after it becomes the new head.
+    pub fn pop_head(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Makes the element after the head the new head, in _O_(1).
+    pub fn rotate_next(&mut self) {
+        self.rotate_forward(1);
+    }
+
+    /// Makes the element before the head the new head, in _O_(1).
+    pub fn rotate_prev(&mut self) {
+        self.rotate_backward(1);
+    }
+
+    /// Makes the element `n` steps after the head the new head. Finding it takes
+    /// _O_(min(`n`, _len_ - `n`)), the rotation itself _O_(1).
+    pub fn rotate_forward(&mut self, n: usize) {
+        if let Some(n) = n.checked_rem(self.len()) {
+            self.rotate_to(n);
+        }
+    }
+
+    /// Makes the element `n` steps before the head the new head. Finding it takes
+    /// _O_(min(`n`, _len_ - `n`)), the rotation itself _O_(1).
+    pub fn rotate_backward(&mut self, n: usize) {
+        if let Some(n) = n.checked_rem(self.len()) {
+            self.rotate_to((self.len() - n) % self.len());
+        }
+    }
+
+    /// Makes the element with the given index, counted from the current head, the new head.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn rotate_to(&mut self, idx: usize) {
+        let node = self.list.node_at(idx);
+        self.rotate_to_node(node);
+    }
+
+    /// Makes the node the given handle points at the new head, in _O_(1).
+    ///
+    /// # Safety
+    ///
+    /// Same as [`ReversibleList::get_by_handle`].
+    pub unsafe fn rotate_to_handle(&mut self, handle: handle::NodeHandle<T>) {
+        #[cfg(feature = "checked-handles")]
+        debug_assert!(self.list.contains_handle(handle), "handle is dangling");
+
+        self.rotate_to_node(handle.node);
+    }
+
+    /// Returns the head and makes the next element the new head, in _O_(1). Calling this
+    /// repeatedly visits all elements in turn, forever. Returns `None` if the ring is empty.
+    pub fn round_robin(&mut self) -> Option<&mut T> {
+        self.rotate_next();
+        self.list.get_mut(self.len().checked_sub(1)?)
+    }
+
+    /// Creates a cursor pointing at the head. Since cursors wrap around at the ends, it can
+    /// walk around the ring in both directions indefinitely.
+    pub fn cursor_head(&self) -> cursor::Cursor<'_, T, A> {
+        self.list.cursor_front()
+    }
+
+    /// Creates a mutable cursor pointing at the head, see [`Self::cursor_head`].
+    pub fn cursor_mut_head(&mut self) -> cursor::CursorMut<'_, T, A> {
+        self.list.cursor_mut_front()
+    }
+
+    /// Cuts the ring open right before the head, returning a list starting at the head.
+    pub fn into_list(self) -> ReversibleList<T, A> {
+        self.list
+    }
+
+    fn rotate_to_node(&mut self, node: Pointer<T>) {
+        // SAFETY: All callers pass nodes owned by this list.
+        unsafe { self.list.rotate_to_node(node) };
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for CircularList<T, A> {
+    fn clone(&self) -> Self {
+        Self::from(self.list.clone())
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for CircularList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for CircularList<T, A> {
+    /// Inserts all items right before the head, in order.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list.extend(iter);
+    }
+}
+
+impl<T> FromIterator<T> for CircularList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<ReversibleList<T>>())
+    }
+}
+
+impl<T, A: Allocator> From<ReversibleList<T, A>> for CircularList<T, A> {
+    /// Closes the list into a ring, with its first element as the head.
+    fn from(value: ReversibleList<T, A>) -> Self {
+        Self { list: value }
+    }
+}
+
+impl<'list, T, A: Allocator> IntoIterator for &'list CircularList<T, A> {
+    type Item = &'list T;
+    type IntoIter = Iter<'list, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for CircularList<T, A> {
+    /// Compares element by element starting at the heads, so rotations of the same ring are
+    /// not equal.
+    fn eq(&self, other: &Self) -> bool {
+        self.list == other.list
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for CircularList<T, A> {}
+
+/// Iterator going once around a [`CircularList`], from any node.
+pub struct Iter<'list, T, A: Allocator = Global> {
+    node: MaybePointer<T>,
+    remaining: usize,
+    list: &'list ReversibleList<T, A>,
+}
+
+impl<T, A: Allocator> Clone for Iter<'_, T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            node: self.node,
+            remaining: self.remaining,
+            list: self.list,
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Iter<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&crate::iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'list, T, A: Allocator> Iterator for Iter<'list, T, A> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.node?;
+        // SAFETY: The node is owned by the list, which is borrowed for as long as the
+        //         iterator lives.
+        let node = unsafe { node.as_ref() };
+        self.node = node.next.or(self.list.start);
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Iter<'_, T, A> {}
+
+impl<T, A: Allocator> FusedIterator for Iter<'_, T, A> {}
@@ -0,0 +1,115 @@
+//! A list which can be cloned in _O_(1), and only copies its nodes once it's actually modified.
+//!
+//! [`CowList`] shares one [`ReversibleList`] between all its clones. Reading goes straight
+//! through to the shared list via [`Deref`], while [`CowList::make_mut`] deep-copies it first if
+//! any other clone still refers to it. This is worth it for read-mostly workloads which take
+//! lots of snapshots, but rarely change them.
+//!
+//! The copy always covers the whole list, not just the part being touched. In a doubly linked
+//! list, every node is pointed at by both neighbours, so two lists can't share only their
+//! common tail like [`PersistentList`](crate::persistent::PersistentList) does.
+
+use std::{fmt, ops::Deref, sync::Arc};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::ReversibleList;
+
+/// A [`ReversibleList`] with _O_(1) clones, copied on the first write.
+///
+/// See the module docs for details.
+pub struct CowList<T, A: Allocator = Global> {
+    list: Arc<ReversibleList<T, A>>,
+}
+
+impl<T> CowList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from(ReversibleList::new())
+    }
+}
+
+impl<T, A: Allocator> CowList<T, A> {
+    /// Returns if any other clone still shares the list with this one, so the next
+    /// [`make_mut`](Self::make_mut) would need to copy it.
+    #[must_use]
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.list) > 1
+    }
+
+    /// Returns if both share the very same list.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.list, &other.list)
+    }
+
+    /// Returns the list for modification, copying all nodes first if it's shared with another
+    /// clone.
+    pub fn make_mut(&mut self) -> &mut ReversibleList<T, A>
+    where
+        T: Clone,
+        A: Clone,
+    {
+        Arc::make_mut(&mut self.list)
+    }
+
+    /// Returns the list, copying it if it's shared with another clone.
+    pub fn into_inner(self) -> ReversibleList<T, A>
+    where
+        T: Clone,
+        A: Clone,
+    {
+        Arc::unwrap_or_clone(self.list)
+    }
+}
+
+impl<T, A: Allocator> Clone for CowList<T, A> {
+    /// Clones the list in _O_(1), by sharing it.
+    fn clone(&self) -> Self {
+        Self {
+            list: Arc::clone(&self.list),
+        }
+    }
+}
+
+impl<T, A: Allocator> Deref for CowList<T, A> {
+    type Target = ReversibleList<T, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for CowList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.list, f)
+    }
+}
+
+impl<T> Default for CowList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> From<ReversibleList<T, A>> for CowList<T, A> {
+    fn from(value: ReversibleList<T, A>) -> Self {
+        Self {
+            list: Arc::new(value),
+        }
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for CowList<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || *self.list == *other.list
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for CowList<T, A> {}
+
+impl<T> FromIterator<T> for CowList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<ReversibleList<T>>())
+    }
+}
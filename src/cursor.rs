@@ -28,30 +28,91 @@
 //! [`index`]: Cursor::index
 //! [`None`]: Option::None
 
-use std::cmp::{
-    self,
-    Ordering::{Equal, Greater, Less},
+use std::{
+    cmp, fmt,
+    hash::{Hash, Hasher},
+    ptr,
 };
 
-use crate::{Direction, MaybePointer, ReversibleList};
+use allocator_api2::alloc::{Allocator, Global, Layout};
+
+use crate::{
+    handle::NodeHandle, Direction, MaybePointer, Node, Pointer, ReversibleList, TryInsertError,
+};
 
 /// Immutable edition.
 ///
 /// See the module docs for details.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Cursor<'a, T> {
+pub struct Cursor<'a, T, A: Allocator = Global> {
+    node: MaybePointer<T>,
+    index: usize,
+    list: &'a ReversibleList<T, A>,
+}
+
+// SAFETY: The cursor only ever accesses the list through the borrow of it, the node pointer
+//         is just a shortcut into it.
+unsafe impl<T: Sync, A: Allocator + Sync> Send for Cursor<'_, T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Cursor<'_, T, A> {}
+
+impl<T, A: Allocator> Clone for Cursor<'_, T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            node: self.node,
+            index: self.index,
+            list: self.list,
+        }
+    }
+}
+
+/// A saved cursor position, created by [`Cursor::save`] or [`CursorMut::save`].
+///
+/// It's only valid as long as the node it points at still exists, see [`Cursor::restore`] for
+/// the details.
+pub struct Position<T> {
     node: MaybePointer<T>,
     index: usize,
-    list: &'a ReversibleList<T>,
+    #[cfg(feature = "checked-versions")]
+    version: u64,
 }
 
+impl<T> Clone for Position<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Position<T> {}
+
+// SAFETY: A position can only be used through `restore`, which requires access to the list
+//         anyway. On its own, it's just an address.
+unsafe impl<T> Send for Position<T> {}
+unsafe impl<T> Sync for Position<T> {}
+
+impl<T> fmt::Debug for Position<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("Position");
+        f.field("node", &self.node).field("index", &self.index);
+        #[cfg(feature = "checked-versions")]
+        f.field("version", &self.version);
+        f.finish()
+    }
+}
+
+impl<T> PartialEq for Position<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && self.index == other.index
+    }
+}
+
+impl<T> Eq for Position<T> {}
+
 macro_rules! impl_common_cursor {
     ($name:ident $($mut:ident)?) => {
-        impl<'a, T: 'a> $name<'a, T> {
+        impl<'a, T: 'a, A: Allocator> $name<'a, T, A> {
             /// # Safety
             ///
             /// `list.start` must be a valid pointer to the first list element.
-            pub(crate) unsafe fn new_front(list: &'a $($mut)? ReversibleList<T>) -> Self {
+            pub(crate) unsafe fn new_front(list: &'a $($mut)? ReversibleList<T, A>) -> Self {
                 Self {
                     node: list.start,
                     index: 0,
@@ -62,7 +123,7 @@ macro_rules! impl_common_cursor {
             /// # Safety
             ///
             /// `list.end` must be a valid pointer to the last list element.
-            pub(crate) unsafe fn new_back(list: &'a $($mut)? ReversibleList<T>) -> Self {
+            pub(crate) unsafe fn new_back(list: &'a $($mut)? ReversibleList<T, A>) -> Self {
                 Self {
                     node: list.end,
                     index: list.len.saturating_sub(1),
@@ -70,18 +131,51 @@ macro_rules! impl_common_cursor {
                 }
             }
 
+            /// # Safety
+            ///
+            /// `node` must be a valid pointer to an element in `list`, and `index` must be its
+            /// index.
+            pub(crate) unsafe fn new_at(
+                list: &'a $($mut)? ReversibleList<T, A>,
+                node: Pointer<T>,
+                index: usize,
+            ) -> Self {
+                Self {
+                    node: Some(node),
+                    index,
+                    list,
+                }
+            }
+
             /// Returns the data stored on the current node, or `None` if the list is empty.
             pub fn current(&self) -> Option<&T> {
                 // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
                 self.node.map(|node| unsafe { &(*node.as_ptr()).data })
             }
 
+            /// Returns a [`NodeHandle`] to the current node, or `None` if the list is empty.
+            pub fn handle(&self) -> Option<NodeHandle<T>> {
+                self.node.map(|node| self.list.handle_for(node))
+            }
+
             /// Returns the index of the current node, or `None` if the list is empty.
             pub fn index(&self) -> Option<usize> {
                 let _ = self.node?;
                 Some(self.index)
             }
 
+            /// Returns how many elements come **after** the current one, not counting itself.
+            /// That's 0 at the end of the list or if it's empty.
+            pub fn remaining_len(&self) -> usize {
+                self.index().map_or(0, |index| self.list.len - index - 1)
+            }
+
+            /// Returns how many elements come **before** the current one, not counting itself.
+            /// That's 0 at the start of the list or if it's empty.
+            pub fn remaining_len_back(&self) -> usize {
+                self.index().unwrap_or(0)
+            }
+
             /// Makes this cursor look at the **previous** node in the list. If there is none, the cursor will
             /// point at the **end** of the list. Does nothing if the list is empty.
             pub fn move_prev(&mut self) {
@@ -121,7 +215,11 @@ macro_rules! impl_common_cursor {
             }
 
             /// Moves this cursor `n` nodes backward. Note that wrapping behavior still applies.
+            /// Does nothing if the list is empty.
             pub fn move_prev_n(&mut self, n: usize) {
+                if self.list.len == 0 {
+                    return;
+                }
                 // filter out how many times we we really need to move
                 let n = n % self.list.len;
                 for _ in 0..n {
@@ -130,14 +228,45 @@ macro_rules! impl_common_cursor {
             }
 
             /// Moves this cursor `n` nodes forward. Note that wrapping behavior still applies.
+            /// Does nothing if the list is empty.
             pub fn move_next_n(&mut self, n: usize) {
+                if self.list.len == 0 {
+                    return;
+                }
                 let n = n % self.list.len;
                 for _ in 0..n {
                     self.move_next();
                 }
             }
 
-            /// Moves this cursor to the given absolute list index.
+            /// Moves this cursor `n` nodes backward if that doesn't go past the start of the
+            /// list, and returns whether it did. Otherwise, and if the list is empty, the
+            /// cursor stays where it is.
+            pub fn try_move_prev_n(&mut self, n: usize) -> bool {
+                let Some(target_idx) = self.index().and_then(|index| index.checked_sub(n)) else {
+                    return false;
+                };
+                self.move_to(target_idx);
+                true
+            }
+
+            /// Moves this cursor `n` nodes forward if that doesn't go past the end of the list,
+            /// and returns whether it did. Otherwise, and if the list is empty, the cursor stays
+            /// where it is.
+            pub fn try_move_next_n(&mut self, n: usize) -> bool {
+                let Some(target_idx) = self.index().and_then(|index| index.checked_add(n)) else {
+                    return false;
+                };
+                if self.list.len <= target_idx {
+                    return false;
+                }
+                self.move_to(target_idx);
+                true
+            }
+
+            /// Moves this cursor to the given absolute list index. Walks from whichever is
+            /// closest to it, the current node, the start or the end of the list, so it takes
+            /// at most _len_ / 2 steps.
             ///
             /// # Panics
             ///
@@ -148,22 +277,120 @@ macro_rules! impl_common_cursor {
                     panic!("tried to move to index {target_idx} but the len is {}", self.list.len);
                 }
 
-                // check if wrapping or going straight through the list is shorter
-                let direct_distance = self.index.abs_diff(target_idx);
-                let wrapping_distance = cmp::min(self.index, target_idx)
-                    + cmp::max(self.index, target_idx).abs_diff(self.list.len);
-
-                match (
-                    self.index.cmp(&target_idx),
-                    direct_distance.cmp(&wrapping_distance),
-                ) {
-                    (Less, Less | Equal) => self.move_next_n(direct_distance),
-                    (Less, Greater) => self.move_prev_n(wrapping_distance),
-                    (Greater, Less | Equal) => self.move_prev_n(direct_distance),
-                    (Greater, Greater) => self.move_next_n(wrapping_distance),
-                    (Equal, _) => (),
+                // restart from either end if that's closer than the current node
+                let from_here = self.index.abs_diff(target_idx);
+                let from_start = target_idx;
+                let from_end = self.list.len - 1 - target_idx;
+                if from_start < from_here && from_start <= from_end {
+                    self.node = self.list.start;
+                    self.index = 0;
+                } else if from_end < from_here {
+                    self.node = self.list.end;
+                    self.index = self.list.len - 1;
+                }
+
+                // the target is in bounds, so walking straight to it never wraps
+                while self.index < target_idx {
+                    self.move_next();
+                }
+                while target_idx < self.index {
+                    self.move_prev();
+                }
+            }
+
+            /// Captures the current position of this cursor as a lightweight token, which can be
+            /// used to jump back to it later in _O_(1) using [`restore`](Self::restore).
+            pub fn save(&self) -> Position<T> {
+                Position {
+                    node: self.node,
+                    index: self.index,
+                    #[cfg(feature = "checked-versions")]
+                    version: self.list.version,
                 }
             }
+
+            /// Jumps back to the position captured by [`save`](Self::save) in _O_(1).
+            ///
+            /// # Safety
+            ///
+            /// `position` must have been saved by a cursor into this very list, and the node it
+            /// points at must not have been removed since. In addition, no nodes may have been
+            /// inserted or removed before it in the meantime, so the saved index is still
+            /// accurate.
+            ///
+            /// With the `checked-versions` feature, this panics if the node has been removed or
+            /// its index changed, or if the position was saved on an empty list which has
+            /// elements now. Checking that takes _O_(_n_), but only if the list changed
+            /// structurally at all since the position was saved, see
+            /// [`ReversibleList::version`].
+            pub unsafe fn restore(&mut self, position: Position<T>) {
+                #[cfg(feature = "checked-versions")]
+                if position.version != self.list.version {
+                    match position.node {
+                        // the list was empty when saving, so it has to be now, too
+                        None => assert!(
+                            self.list.is_empty(),
+                            "tried to restore a position saved on an empty list, which has elements now"
+                        ),
+                        Some(node) => {
+                            let mut current = self.list.start;
+                            let mut index = 0;
+                            while let Some(other) = current.filter(|&other| other != node) {
+                                // SAFETY: All nodes reachable from the start are owned by the list.
+                                current = unsafe { other.as_ref().next };
+                                index += 1;
+                            }
+                            assert!(
+                                current.is_some(),
+                                "tried to restore a position whose node has been removed since"
+                            );
+                            assert_eq!(
+                                index, position.index,
+                                "tried to restore a position at index {} but its node moved to index {index} since",
+                                position.index
+                            );
+                        }
+                    }
+                }
+
+                self.node = position.node;
+                self.index = position.index;
+            }
+        }
+
+        impl<T: fmt::Debug, A: Allocator> fmt::Debug for $name<'_, T, A> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("node", &self.node)
+                    .field("index", &self.index)
+                    .field("list", &self.list)
+                    .finish()
+            }
+        }
+
+        /// Cursors are equal if they point into the same list and at the same node, what the
+        /// elements themselves look like doesn't matter.
+        impl<T, A: Allocator> PartialEq for $name<'_, T, A> {
+            fn eq(&self, other: &Self) -> bool {
+                ptr::eq(&*self.list, &*other.list) && self.node == other.node
+            }
+        }
+
+        impl<T, A: Allocator> Eq for $name<'_, T, A> {}
+
+        /// Cursors into the same list are ordered by their index, like
+        /// `assert!(start <= end)`. Cursors into different lists can't be compared.
+        impl<T, A: Allocator> PartialOrd for $name<'_, T, A> {
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                ptr::eq(&*self.list, &*other.list).then(|| self.index.cmp(&other.index))
+            }
+        }
+
+        impl<T, A: Allocator> Hash for $name<'_, T, A> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                ptr::from_ref(&*self.list).hash(state);
+                self.node.hash(state);
+            }
         }
     };
 }
@@ -173,16 +400,19 @@ impl_common_cursor!(Cursor);
 /// Mutable edition.
 ///
 /// See the module docs for details.
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct CursorMut<'a, T> {
+pub struct CursorMut<'a, T, A: Allocator = Global> {
     node: MaybePointer<T>,
     index: usize,
-    list: &'a mut ReversibleList<T>,
+    list: &'a mut ReversibleList<T, A>,
 }
 
+// SAFETY: Same as for `Cursor`, but with a mutable borrow.
+unsafe impl<T: Send, A: Allocator + Send> Send for CursorMut<'_, T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for CursorMut<'_, T, A> {}
+
 impl_common_cursor!(CursorMut mut);
 
-impl<'a, T: 'a> CursorMut<'a, T> {
+impl<'a, T: 'a, A: Allocator> CursorMut<'a, T, A> {
     /// Returns a mutable reference to the data stored on the current node, or `None` if the
     /// list is empty.
     pub fn current_mut(&mut self) -> Option<&mut T> {
@@ -190,10 +420,21 @@ impl<'a, T: 'a> CursorMut<'a, T> {
         self.node.map(|node| unsafe { &mut (*node.as_ptr()).data })
     }
 
+    /// Returns mutable references to the data of the current and the **next** node at once, or
+    /// `None` if there is no next node. Doesn't wrap around the list.
+    pub(crate) fn current_and_next_mut(&mut self) -> Option<(&mut T, &mut T)> {
+        let node = self.node?;
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
+        let next = (unsafe { node.as_ref() }).next?;
+
+        // SAFETY: Both nodes belong to the mutably borrowed list and are distinct.
+        Some(unsafe { (&mut (*node.as_ptr()).data, &mut (*next.as_ptr()).data) })
+    }
+
     /// Returns an immutable [`Cursor`] at the same position as this cursor. Note that this cursor
     /// will be frozen and unusable until the returned cursor (and all its clones and returned
     /// references) have been dropped.
-    pub fn cursor(&self) -> Cursor<'_, T> {
+    pub fn cursor(&self) -> Cursor<'_, T, A> {
         Cursor {
             node: self.node,
             index: self.index,
@@ -202,32 +443,101 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     }
 
     /// Inserts the given item **after** the current node, creating a new node between the
-    /// current one and the currently next one.
-    pub fn insert_after(&mut self, item: T) {
+    /// current one and the currently next one. Returns a [`NodeHandle`] to the new node.
+    pub fn insert_after(&mut self, item: T) -> NodeHandle<T> {
         // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
-        unsafe {
-            self.list.insert_in_dir(self.node, Direction::After, item);
-        }
+        let new_node = unsafe { self.list.insert_in_dir(self.node, Direction::After, item) };
+        self.inserted(new_node, Direction::After)
+    }
 
-        if self.list.len == 1 {
-            // list was previously empty, so the cursor now needs to point at the new element
-            self.node = self.list.start;
-        }
+    /// Like [`Self::insert_after`], but returns an error holding `item` instead of aborting
+    /// if the allocator fails.
+    pub fn try_insert_after(&mut self, item: T) -> Result<NodeHandle<T>, TryInsertError<T>> {
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
+        let new_node = unsafe {
+            self.list
+                .try_insert_in_dir(self.node, Direction::After, item)
+        }?;
+        Ok(self.inserted(new_node, Direction::After))
     }
 
     /// Inserts the given item **before** the current node, creating a new node between the
-    /// current one and the currently previous one.
-    pub fn insert_before(&mut self, item: T) {
+    /// current one and the currently previous one. Returns a [`NodeHandle`] to the new node.
+    pub fn insert_before(&mut self, item: T) -> NodeHandle<T> {
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
+        let new_node = unsafe { self.list.insert_in_dir(self.node, Direction::Before, item) };
+        self.inserted(new_node, Direction::Before)
+    }
+
+    /// Like [`Self::insert_before`], but returns an error holding `item` instead of aborting
+    /// if the allocator fails.
+    pub fn try_insert_before(&mut self, item: T) -> Result<NodeHandle<T>, TryInsertError<T>> {
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
+        let new_node = unsafe {
+            self.list
+                .try_insert_in_dir(self.node, Direction::Before, item)
+        }?;
+        Ok(self.inserted(new_node, Direction::Before))
+    }
+
+    /// Inserts all items **after** the current node in one go, keeping their order. The new
+    /// nodes are chained up first and then linked into the list at once. If the list is
+    /// empty, the cursor ends up at the first new element, otherwise it stays where it is.
+    ///
+    /// If the iterator panics, the items yielded until then are still inserted.
+    pub fn insert_all_after<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        self.insert_all(items, Direction::After);
+    }
+
+    /// Like [`Self::insert_all_after`], but inserts the items **before** the current node.
+    pub fn insert_all_before<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        self.insert_all(items, Direction::Before);
+    }
+
+    fn insert_all<I: IntoIterator<Item = T>>(&mut self, items: I, direction: Direction) {
+        /// Keeps the cursor on its node even if the iterator panics.
+        struct Shift<'cursor, 'a, T, A: Allocator> {
+            cursor: &'cursor mut CursorMut<'a, T, A>,
+            len: usize,
+            direction: Direction,
+        }
+
+        impl<T, A: Allocator> Drop for Shift<'_, '_, T, A> {
+            fn drop(&mut self) {
+                let count = self.cursor.list.len - self.len;
+                self.cursor.shift_for(count, self.direction);
+            }
+        }
+
+        let shift = Shift {
+            len: self.list.len,
+            cursor: self,
+            direction,
+        };
         // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
         unsafe {
-            self.list.insert_in_dir(self.node, Direction::Before, item);
+            shift
+                .cursor
+                .list
+                .insert_all_in_dir(shift.cursor.node, direction, items);
         }
+    }
 
-        if self.list.len == 1 {
+    /// Keeps the cursor on its node after `new_node` has been linked in the given direction of
+    /// it, and returns a handle to the new node.
+    fn inserted(&mut self, new_node: Pointer<T>, direction: Direction) -> NodeHandle<T> {
+        self.shift_for(1, direction);
+        self.list.handle_for(new_node)
+    }
+
+    /// Keeps the cursor on its node after `count` nodes have been linked in the given
+    /// direction of it.
+    fn shift_for(&mut self, count: usize, direction: Direction) {
+        if self.node.is_none() {
             // list was previously empty, so the cursor now needs to point at the new element
             self.node = self.list.start;
-        } else {
-            self.index += 1;
+        } else if let Direction::Before = direction {
+            self.index += count;
         }
     }
 
@@ -240,10 +550,121 @@ impl<'a, T: 'a> CursorMut<'a, T> {
     /// - If the list only contains **one** node, the cursor will point "nowhere", since the
     ///   list will be empty.
     pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.step_off_current()?;
+
         // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`, the
-        // pointer is updated appropiately.
+        // pointer has been updated appropiately by `step_off_current`.
+        Some(unsafe { self.list.remove(node) })
+    }
+
+    /// Relinks the current node to the **front** of the list, without reallocating it. Does
+    /// nothing if the list is empty.
+    ///
+    /// Afterwards, the cursor points at the node which was after the moved one, or the one before
+    /// it if there was none, just like [`remove_current`](Self::remove_current). If the list
+    /// contains only one node, the cursor stays on it.
+    pub fn move_current_to_front(&mut self) {
+        let Some(node) = self.step_off_current() else {
+            return;
+        };
+
+        // SAFETY: `node` has been owned by this list and is still alive, it's only moved around.
+        unsafe {
+            self.list.unlink(node);
+            self.list
+                .link_in_dir(self.list.start, Direction::Before, node);
+        }
+
+        if self.node.is_none() {
+            // node was the only one in the list, so just stay on it
+            self.node = Some(node);
+        } else {
+            // everything up until the cursor shifted by one
+            self.index += 1;
+        }
+    }
+
+    /// Relinks the current node to the **back** of the list, without reallocating it. Does
+    /// nothing if the list is empty.
+    ///
+    /// Afterwards, the cursor points at the node which was after the moved one, or the one before
+    /// it if there was none, just like [`remove_current`](Self::remove_current). If the list
+    /// contains only one node, the cursor stays on it.
+    pub fn move_current_to_back(&mut self) {
+        let Some(node) = self.step_off_current() else {
+            return;
+        };
+
+        // SAFETY: Same as in `move_current_to_front`.
+        unsafe {
+            self.list.unlink(node);
+            self.list.link_in_dir(self.list.end, Direction::After, node);
+        }
+
+        if self.node.is_none() {
+            self.node = Some(node);
+        }
+    }
+
+    /// Swaps the current node with the **next** one by relinking them, without touching their
+    /// data. The cursor stays on the same node, so it moves one position forward as well.
+    ///
+    /// Returns `false` and does nothing if there is no next node, since swapping doesn't wrap
+    /// around the list.
+    pub fn swap_with_next(&mut self) -> bool {
+        let Some(node) = self.node else {
+            return false;
+        };
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
+        let Some(next) = (unsafe { node.as_ref() }).next else {
+            return false;
+        };
+
+        // SAFETY: Both nodes are owned by this list and stay alive, they're only moved around.
+        unsafe {
+            self.list.unlink(node);
+            self.list.link_in_dir(Some(next), Direction::After, node);
+        }
+        self.index += 1;
+
+        true
+    }
+
+    /// Swaps the current node with the **previous** one by relinking them, without touching
+    /// their data. The cursor stays on the same node, so it moves one position backward as well.
+    ///
+    /// Returns `false` and does nothing if there is no previous node, since swapping doesn't
+    /// wrap around the list.
+    pub fn swap_with_prev(&mut self) -> bool {
+        let Some(node) = self.node else {
+            return false;
+        };
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
+        let Some(prev) = (unsafe { node.as_ref() }).prev else {
+            return false;
+        };
+
+        // SAFETY: Same as in `swap_with_next`.
+        unsafe {
+            self.list.unlink(node);
+            self.list.link_in_dir(Some(prev), Direction::Before, node);
+        }
+        self.index -= 1;
+
+        true
+    }
+
+    /// Moves the cursor off the current node as if it had been removed already, and returns the
+    /// node it was on previously. Returns `None` if the list is empty.
+    ///
+    /// - If there is a node **after** the current one, the cursor will point at that one.
+    /// - If the cursor is at the end of the list, the cursor will point at the node
+    ///   **before** the current one.
+    /// - If the list only contains **one** node, the cursor will point "nowhere".
+    fn step_off_current(&mut self) -> Option<Pointer<T>> {
         let node = self.node?;
 
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`.
         let node_ref = unsafe { node.as_ref() };
         self.node = match (node_ref.prev, node_ref.next) {
             // start/mid of the list; index stays the same
@@ -257,6 +678,197 @@ impl<'a, T: 'a> CursorMut<'a, T> {
             (None, None) => None,
         };
 
-        Some(unsafe { self.list.remove(node) })
+        Some(node)
+    }
+}
+
+impl<'a, T: 'a, A: Allocator + Clone> CursorMut<'a, T, A> {
+    /// Detaches everything from the current node (inclusive) up to the end of the list and
+    /// returns it as a new list, without reallocating any nodes. Returns an empty list if this
+    /// one is empty.
+    ///
+    /// Afterwards, the cursor points at the new last node of this list, or "nowhere" if the
+    /// cursor was at the start and the whole list has been split off.
+    pub fn split_rest(&mut self) -> ReversibleList<T, A> {
+        let (Some(node), Some(end)) = (self.node, self.list.end) else {
+            return ReversibleList::new_in(self.list.alloc.clone());
+        };
+
+        let rest_len = self.list.len - self.index;
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`. The cursor index
+        // is always in sync with its node, so `rest_len` is exact.
+        let rest = unsafe { self.list.detach_range(node, end, rest_len) };
+
+        self.node = self.list.end;
+        self.index = self.index.saturating_sub(1);
+        rest
+    }
+
+    /// Removes `n` nodes starting at the current one (inclusive) and returns them as a new list,
+    /// without reallocating any nodes. If there are less than `n` nodes until the end of the
+    /// list, only those are removed, since removal doesn't wrap around the list.
+    ///
+    /// Afterwards, the cursor points at the node after the removed ones, or the one before them
+    /// if there is none, just like [`remove_current`](Self::remove_current).
+    pub fn remove_n(&mut self, n: usize) -> ReversibleList<T, A> {
+        let Some(first) = self.node else {
+            return ReversibleList::new_in(self.list.alloc.clone());
+        };
+        if n == 0 {
+            return ReversibleList::new_in(self.list.alloc.clone());
+        }
+
+        let removed_len = cmp::min(n, self.list.len - self.index);
+        let mut last = first;
+        for _ in 1..removed_len {
+            // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`, `removed_len`
+            // ensures we never walk past the end of the list.
+            last = unsafe { last.as_ref().next.unwrap_unchecked() };
+        }
+
+        // SAFETY: Same as above, the range has been walked and counted just now.
+        let (before_range, after_range) = unsafe { (first.as_ref().prev, last.as_ref().next) };
+        self.node = match (before_range, after_range) {
+            // the node after the range takes over the current index
+            (_, Some(next)) => Some(next),
+            // range reaches until the end of the list
+            (Some(prev), None) => {
+                self.index -= 1;
+                Some(prev)
+            }
+            // the whole list is removed
+            (None, None) => None,
+        };
+
+        // SAFETY: See above.
+        unsafe { self.list.detach_range(first, last, removed_len) }
+    }
+}
+
+/// Cutting and pasting whole nodes is only available for the global allocator, since a node cut
+/// out of one list may be pasted into any other, which then has to be able to deallocate it.
+impl<'a, T: 'a> CursorMut<'a, T> {
+    /// Unlinks the current node from the list and returns it as a [`DetachedNode`], without
+    /// deallocating it or moving its data. Returns `None` if the list is empty.
+    ///
+    /// Afterwards, the cursor points at the node after the unlinked one, or the one before it if
+    /// there is none, just like [`remove_current`](Self::remove_current).
+    pub fn unlink_current(&mut self) -> Option<DetachedNode<T>> {
+        let node = self.step_off_current()?;
+
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`, the
+        // pointer has been updated appropiately by `step_off_current`.
+        unsafe { self.list.unlink(node) };
+
+        Some(DetachedNode {
+            node,
+            #[cfg(feature = "checked-handles")]
+            generation: self
+                .list
+                .registry
+                .unregister(node)
+                .expect("node should be registered in its own list"),
+        })
+    }
+
+    /// Links the given detached node **after** the current one in _O_(1), without allocating
+    /// or moving its data. Returns a [`NodeHandle`] to it, which is the same one the node had
+    /// before being unlinked.
+    pub fn insert_node_after(&mut self, node: DetachedNode<T>) -> NodeHandle<T> {
+        let node = self.adopt(node, Direction::After);
+        self.inserted(node, Direction::After)
+    }
+
+    /// Links the given detached node **before** the current one in _O_(1), without allocating
+    /// or moving its data. Returns a [`NodeHandle`] to it, which is the same one the node had
+    /// before being unlinked.
+    pub fn insert_node_before(&mut self, node: DetachedNode<T>) -> NodeHandle<T> {
+        let node = self.adopt(node, Direction::Before);
+        self.inserted(node, Direction::Before)
+    }
+
+    /// Links the given detached node in the given direction of the current one, and takes over
+    /// ownership of it.
+    fn adopt(&mut self, detached: DetachedNode<T>, direction: Direction) -> Pointer<T> {
+        let detached = std::mem::ManuallyDrop::new(detached);
+        let node = detached.node;
+
+        // SAFETY: Delegated to the unsafe contract of `new_front`/`new_back`, and the detached
+        //         node was allocated by the global allocator and isn't linked anywhere.
+        unsafe { self.list.link_in_dir(self.node, direction, node) };
+        #[cfg(feature = "checked-handles")]
+        self.list.registry.adopt(node, detached.generation);
+
+        node
+    }
+}
+
+/// A node which has been cut out of a list by [`CursorMut::unlink_current`], still holding its
+/// data and allocation.
+///
+/// It can be pasted into any list again using [`CursorMut::insert_node_after`] or
+/// [`CursorMut::insert_node_before`], neither of which needs to allocate or move the data. If
+/// it's dropped instead, the data is dropped and the node deallocated.
+pub struct DetachedNode<T> {
+    node: Pointer<T>,
+    #[cfg(feature = "checked-handles")]
+    generation: u64,
+}
+
+// SAFETY: The node is exclusively owned by the token, just like a `Box<T>`.
+unsafe impl<T: Send> Send for DetachedNode<T> {}
+unsafe impl<T: Sync> Sync for DetachedNode<T> {}
+
+impl<T> DetachedNode<T> {
+    /// Returns a reference to the data stored on the node.
+    #[must_use]
+    pub fn get(&self) -> &T {
+        // SAFETY: The node is exclusively owned by this token and its data is initialized.
+        unsafe { &(*self.node.as_ptr()).data }
+    }
+
+    /// Returns a mutable reference to the data stored on the node.
+    pub fn get_mut(&mut self) -> &mut T {
+        // SAFETY: Same as in `get`.
+        unsafe { &mut (*self.node.as_ptr()).data }
+    }
+
+    /// Moves the data out of the node and deallocates it.
+    pub fn into_inner(self) -> T {
+        let this = std::mem::ManuallyDrop::new(self);
+        // SAFETY: Same as in `get`, and the data is moved out exactly once since the token is
+        //         consumed without running its destructor.
+        unsafe {
+            let data = ptr::read(ptr::addr_of!((*this.node.as_ptr()).data));
+            this.deallocate();
+            data
+        }
+    }
+
+    /// Returns the node memory to the global allocator, without touching its data.
+    ///
+    /// # Safety
+    ///
+    /// The token must not be used anymore afterwards.
+    unsafe fn deallocate(&self) {
+        // SAFETY: Nodes of lists using the global allocator are allocated with exactly this
+        //         layout, delegated to the caller otherwise.
+        unsafe { Global.deallocate(self.node.cast(), Layout::new::<Node<T>>()) };
+    }
+}
+
+impl<T> Drop for DetachedNode<T> {
+    fn drop(&mut self) {
+        // SAFETY: Same as in `into_inner`, and the token is gone afterwards.
+        unsafe {
+            ptr::drop_in_place(ptr::addr_of_mut!((*self.node.as_ptr()).data));
+            self.deallocate();
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DetachedNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DetachedNode").field(self.get()).finish()
     }
 }
@@ -0,0 +1,571 @@
+//! A facade with the API of [`VecDeque`], for swapping a linked list into existing code.
+//!
+//! [`ReversibleList`] names a few things differently than [`VecDeque`] does, like
+//! [`insert`](ReversibleList::insert) returning a handle or
+//! [`binary_search`](ReversibleList::binary_search) returning a cursor. A [`ListDeque`] wraps a
+//! list and offers the methods and trait implementations of [`VecDeque`] with the very same
+//! signatures instead, so code written against it only needs the type name changed.
+//!
+//! A few methods can't be offered:
+//!
+//! - The elements are never contiguous, so [`as_slices`](VecDeque::as_slices) and
+//!   [`as_mut_slices`](VecDeque::as_mut_slices) don't exist, and
+//!   [`make_contiguous`](ListDeque::make_contiguous) does nothing and returns nothing.
+//! - [`try_reserve`](VecDeque::try_reserve) and friends are missing, since there's no way to
+//!   construct a [`TryReserveError`](std::collections::TryReserveError) outside of `std`.
+//!
+//! Since there's no random access, anything taking an index walks to it, in _O_(min(_i_,
+//! _n_ - _i_)) from either end. Lookups close to the previous one are fast though, see
+//! [`ReversibleList::get`].
+
+use std::{
+    cmp,
+    collections::VecDeque,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{Skip, Take},
+    mem,
+    ops::{Bound, Index, IndexMut, RangeBounds},
+};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::{iter, ReversibleList};
+
+/// A [`ReversibleList`] behind the API of [`VecDeque`].
+///
+/// See the module docs for details.
+pub struct ListDeque<T, A: Allocator = Global> {
+    list: ReversibleList<T, A>,
+}
+
+impl<T> ListDeque<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from(ReversibleList::new())
+    }
+
+    /// Creates an empty deque with at least `capacity` nodes pre-allocated.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from(ReversibleList::with_capacity(capacity))
+    }
+
+    /// Moves all elements of `other` to the back of this deque in _O_(1), leaving `other`
+    /// empty.
+    pub fn append(&mut self, other: &mut Self) {
+        self.list.append(&mut other.list);
+    }
+}
+
+impl<T, A: Allocator> ListDeque<T, A> {
+    /// Creates an empty deque which allocates its nodes using the given allocator.
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
+        Self::from(ReversibleList::new_in(alloc))
+    }
+
+    /// Creates an empty deque with at least `capacity` nodes pre-allocated using the given
+    /// allocator.
+    #[must_use]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self::from(ReversibleList::with_capacity_in(capacity, alloc))
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.list.allocator()
+    }
+
+    /// Returns the underlying list.
+    pub fn into_list(self) -> ReversibleList<T, A> {
+        self.list
+    }
+
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_mut(index)
+    }
+
+    /// Swaps the elements at indices `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        assert!(i < self.len(), "assertion failed: i < self.len()");
+        assert!(j < self.len(), "assertion failed: j < self.len()");
+        if let Some([a, b]) = self.list.get_disjoint_mut([i, j]) {
+            mem::swap(a, b);
+        }
+    }
+
+    /// Returns how many elements the deque can hold without allocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.list.capacity()
+    }
+
+    /// Same as [`Self::reserve`], since nodes are allocated one by one anyway.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.list.reserve(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.list.shrink_to_fit();
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.list.shrink_to(min_capacity);
+    }
+
+    /// Removes all elements after the first `len` ones, dropping them from the back.
+    pub fn truncate(&mut self, len: usize) {
+        self.list.truncate(len);
+    }
+
+    pub fn iter(&self) -> iter::Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> iter::IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+
+    /// Does nothing, since the elements of a linked list are never contiguous. Only exists so
+    /// calls don't need to be removed.
+    pub fn make_contiguous(&mut self) {}
+
+    /// Moves the first `n` elements to the back. Finding the new front takes
+    /// _O_(min(`n`, _len_ - `n`)), the rotation itself _O_(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than the length of the deque.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len(), "assertion failed: n <= self.len()");
+        if n < self.len() {
+            let node = self.list.node_at(n);
+            // SAFETY: `node_at` only returns nodes owned by this list.
+            unsafe { self.list.rotate_to_node(node) };
+        }
+    }
+
+    /// Moves the last `n` elements to the front, see [`Self::rotate_left`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is larger than the length of the deque.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len(), "assertion failed: n <= self.len()");
+        self.rotate_left(self.len() - n);
+    }
+
+    /// Returns an iterator through the elements in the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn range<R: RangeBounds<usize>>(&self, range: R) -> Take<Skip<iter::Iter<'_, T>>> {
+        let (start, end) = self.bounds(range);
+        self.list.iter().skip(start).take(end - start)
+    }
+
+    /// Returns an iterator through the elements in the given range, yielding mutable
+    /// references.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn range_mut<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Take<Skip<iter::IterMut<'_, T>>> {
+        let (start, end) = self.bounds(range);
+        self.list.iter_mut().skip(start).take(end - start)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == x)
+    }
+
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.list.iter().next()
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.list.iter_mut().next()
+    }
+
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.list.iter().next_back()
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.list.iter_mut().next_back()
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.list.push_front(value);
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.list.push_back(value);
+    }
+
+    /// Removes the element at `index` and puts the first element in its place, returning the
+    /// removed one, or `None` if `index` is out of bounds.
+    pub fn swap_remove_front(&mut self, index: usize) -> Option<T> {
+        if self.len() <= index {
+            return None;
+        }
+        self.swap(0, index);
+        self.pop_front()
+    }
+
+    /// Removes the element at `index` and puts the last element in its place, returning the
+    /// removed one, or `None` if `index` is out of bounds.
+    pub fn swap_remove_back(&mut self, index: usize) -> Option<T> {
+        if self.len() <= index {
+            return None;
+        }
+        self.swap(index, self.len() - 1);
+        self.pop_back()
+    }
+
+    /// Inserts the given element at `index`, shifting all elements after it back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is larger than the length of the deque.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.list.insert(index, value);
+    }
+
+    /// Removes the element at `index` and returns it, or `None` if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if self.len() <= index {
+            return None;
+        }
+        let node = self.list.node_at(index);
+        // SAFETY: `node_at` only returns nodes owned by this list.
+        Some(unsafe { self.list.remove(node) })
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, in order.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.list.remove_all(|item| !f(item));
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, in order, passing them mutably.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let mut current = self.list.start;
+        while let Some(node) = current {
+            // SAFETY: `node` is owned by this list, and the list is borrowed mutably, so no
+            //         other reference to it exists.
+            unsafe {
+                current = node.as_ref().next;
+                if !f(&mut (*node.as_ptr()).data) {
+                    self.list.remove(node);
+                }
+            }
+        }
+    }
+
+    /// Changes the length to `new_len`, either by truncating or by appending the values
+    /// returned by `generator`.
+    pub fn resize_with<F>(&mut self, new_len: usize, generator: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.truncate(new_len);
+        let missing = new_len - self.len();
+        self.list
+            .extend(std::iter::repeat_with(generator).take(missing));
+    }
+
+    /// Changes the length to `new_len`, either by truncating or by appending clones of
+    /// `value`.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.resize_with(new_len, || value.clone());
+    }
+
+    /// Like [`VecDeque::binary_search`], returns the index of a matching element, or the
+    /// index it could be inserted at.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|item| item.cmp(x))
+    }
+
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> cmp::Ordering,
+    {
+        self.list
+            .binary_search_by(f)
+            .map(|cursor| cursor.index().expect("cursor points at a match"))
+    }
+
+    pub fn binary_search_by_key<B: Ord, F>(&self, b: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+    {
+        self.list
+            .binary_search_by_key(b, f)
+            .map(|cursor| cursor.index().expect("cursor points at a match"))
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`, assuming all
+    /// elements for which it returns `true` come first.
+    pub fn partition_point<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.binary_search_by(|item| {
+            if pred(item) {
+                cmp::Ordering::Less
+            } else {
+                cmp::Ordering::Greater
+            }
+        })
+        .unwrap_or_else(|idx| idx)
+    }
+
+    /// Turns the given range into `start..end`, panicking if it's out of bounds.
+    fn bounds<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        assert!(
+            start <= end && end <= self.len(),
+            "range {start}..{end} out of bounds for len {}",
+            self.len()
+        );
+        (start, end)
+    }
+}
+
+impl<T, A: Allocator + Clone> ListDeque<T, A> {
+    /// Removes the elements in the given range right away and returns an iterator over them.
+    /// The nodes are only relinked, not reallocated.
+    ///
+    /// Unlike with [`VecDeque::drain`], the elements are removed even if the iterator is
+    /// leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> iter::IntoIter<T, A> {
+        let (start, end) = self.bounds(range);
+        self.detach(start, end).into_iter()
+    }
+
+    /// Splits the deque in two at the given index, returning everything from `at` on. The
+    /// nodes are only relinked, not reallocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is larger than the length of the deque.
+    #[must_use = "use `.truncate()` if you don't need the other half"]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let (start, end) = self.bounds(at..);
+        Self::from(self.detach(start, end))
+    }
+
+    fn detach(&mut self, start: usize, end: usize) -> ReversibleList<T, A> {
+        if start == end {
+            return ReversibleList::new_in(self.list.allocator().clone());
+        }
+        let first = self.list.node_at(start);
+        let last = self.list.node_at(end - 1);
+        // SAFETY: Both are owned by this list, and `last` comes `end - start - 1` nodes after
+        //         `first`.
+        unsafe { self.list.detach_range(first, last, end - start) }
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for ListDeque<T, A> {
+    fn clone(&self) -> Self {
+        Self::from(self.list.clone())
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for ListDeque<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+impl<T> Default for ListDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for ListDeque<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list.extend(iter);
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for ListDeque<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.list.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for ListDeque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<ReversibleList<T>>())
+    }
+}
+
+impl<T, A: Allocator> From<ReversibleList<T, A>> for ListDeque<T, A> {
+    fn from(value: ReversibleList<T, A>) -> Self {
+        Self { list: value }
+    }
+}
+
+impl<T> From<VecDeque<T>> for ListDeque<T> {
+    fn from(value: VecDeque<T>) -> Self {
+        Self::from(ReversibleList::from(value))
+    }
+}
+
+impl<T, A: Allocator> From<ListDeque<T, A>> for VecDeque<T> {
+    fn from(value: ListDeque<T, A>) -> Self {
+        value.list.into()
+    }
+}
+
+impl<T> From<Vec<T>> for ListDeque<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::from(ReversibleList::from(value))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ListDeque<T> {
+    fn from(value: [T; N]) -> Self {
+        Self::from(ReversibleList::from(value))
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for ListDeque<T, A> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("Out of bounds access")
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for ListDeque<T, A> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("Out of bounds access")
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for ListDeque<T, A> {
+    type Item = T;
+    type IntoIter = iter::IntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.into_iter()
+    }
+}
+
+impl<'list, T, A: Allocator> IntoIterator for &'list ListDeque<T, A> {
+    type Item = &'list T;
+    type IntoIter = iter::Iter<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'list, T, A: Allocator> IntoIterator for &'list mut ListDeque<T, A> {
+    type Item = &'list mut T;
+    type IntoIter = iter::IterMut<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for ListDeque<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.list.hash(state);
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for ListDeque<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.list == other.list
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for ListDeque<T, A> {}
+
+impl<T: PartialOrd, A: Allocator> PartialOrd for ListDeque<T, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.list.partial_cmp(&other.list)
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for ListDeque<T, A> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.list.cmp(&other.list)
+    }
+}
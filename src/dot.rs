@@ -0,0 +1,101 @@
+//! Dumping the node topology as [Graphviz](https://graphviz.org) DOT, for debugging.
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Write},
+};
+
+use allocator_api2::alloc::Allocator;
+
+use crate::{MaybePointer, Pointer, ReversibleList};
+
+impl<T: fmt::Debug, A: Allocator> ReversibleList<T, A> {
+    /// Writes the node graph in Graphviz DOT format to `out`, with one vertex per node
+    /// labeled by its address and data, and one edge per `prev`/`next` pointer.
+    ///
+    /// Nodes are discovered by walking forward from the start and backward from the end, each
+    /// node is visited at most once, so even cycles or asymmetric links show up as they are.
+    /// Render the output with e.g. `dot -Tsvg`.
+    pub fn debug_graph(&self, out: &mut impl Write) -> fmt::Result {
+        writeln!(out, "digraph ReversibleList {{")?;
+        writeln!(out, "    rankdir=LR;")?;
+        writeln!(out, "    node [shape=box];")?;
+        writeln!(out, "    start [shape=plaintext];")?;
+        writeln!(out, "    end [shape=plaintext];")?;
+
+        let mut visited = HashSet::new();
+        let mut index = 0;
+        for (from, dir) in [(self.start, Dir::Next), (self.end, Dir::Prev)] {
+            let mut current = from;
+            while let Some(node) = current {
+                if !visited.insert(node) {
+                    break;
+                }
+                // SAFETY: Reachable nodes are owned by this list if the links are intact, which
+                //         is the best we can do.
+                let node_ref = unsafe { node.as_ref() };
+
+                let label = format!("{:?}", node_ref.data)
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"");
+                writeln!(
+                    out,
+                    "    {} [label=\"#{index}\\n{node:p}\\n{label}\"];",
+                    id(node)
+                )?;
+                edge(out, node, node_ref.next, "next", "solid")?;
+                edge(out, node, node_ref.prev, "prev", "dashed")?;
+
+                index += 1;
+                current = match dir {
+                    Dir::Next => node_ref.next,
+                    Dir::Prev => node_ref.prev,
+                };
+            }
+        }
+
+        if let Some(start) = self.start {
+            writeln!(out, "    start -> {};", id(start))?;
+        }
+        if let Some(end) = self.end {
+            writeln!(out, "    end -> {};", id(end))?;
+        }
+        writeln!(out, "}}")
+    }
+
+    /// Returns the node graph in Graphviz DOT format. See [`Self::debug_graph`] for details.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        self.debug_graph(&mut out)
+            .expect("writing into a string never fails");
+        out
+    }
+}
+
+enum Dir {
+    Next,
+    Prev,
+}
+
+fn id<T>(node: Pointer<T>) -> String {
+    format!("\"{node:p}\"")
+}
+
+fn edge<T>(
+    out: &mut impl Write,
+    from: Pointer<T>,
+    to: MaybePointer<T>,
+    label: &str,
+    style: &str,
+) -> fmt::Result {
+    match to {
+        Some(to) => writeln!(
+            out,
+            "    {} -> {} [label={label}, style={style}];",
+            id(from),
+            id(to)
+        ),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,67 @@
+//! The last node looked up by index, so that lookups close to it don't need to walk from either
+//! end of the list.
+
+use std::{
+    cell::UnsafeCell,
+    marker::PhantomData,
+    panic::RefUnwindSafe,
+    ptr::NonNull,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::Pointer;
+
+/// A cached `(node, index)` pair of a list.
+///
+/// Lookups through a shared reference update it too, and a list can be shared between threads,
+/// so it's guarded by a flag. The finger is only an optimization, so whoever finds the flag
+/// already taken just goes without it instead of waiting.
+pub(crate) struct Finger<T> {
+    taken: AtomicBool,
+    /// The node is stored type-erased, since an `UnsafeCell` would make the whole list
+    /// invariant over `T`.
+    spot: UnsafeCell<Option<(NonNull<()>, usize)>>,
+    _node: PhantomData<Pointer<T>>,
+}
+
+// The spot is never left half-written, no matter where a panic happens.
+impl<T> RefUnwindSafe for Finger<T> {}
+
+impl<T> Finger<T> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            taken: AtomicBool::new(false),
+            spot: UnsafeCell::new(None),
+            _node: PhantomData,
+        }
+    }
+
+    /// Returns the cached node and its index, if any.
+    pub(crate) fn get(&self) -> Option<(Pointer<T>, usize)> {
+        self.with(|spot| *spot)
+            .flatten()
+            .map(|(node, index)| (node.cast(), index))
+    }
+
+    /// Remembers the given node to be at the given index.
+    pub(crate) fn set(&self, node: Pointer<T>, index: usize) {
+        self.with(|spot| *spot = Some((node.cast(), index)));
+    }
+
+    /// Forgets the cached node. Needs to be called whenever nodes are linked or unlinked,
+    /// since that could shift the index or free the node.
+    pub(crate) fn clear(&mut self) {
+        *self.spot.get_mut() = None;
+    }
+
+    fn with<R>(&self, f: impl FnOnce(&mut Option<(NonNull<()>, usize)>) -> R) -> Option<R> {
+        self.taken
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()?;
+        // SAFETY: The flag was just taken, so nobody else accesses the spot until it's released
+        //         again.
+        let result = f(unsafe { &mut *self.spot.get() });
+        self.taken.store(false, Ordering::Release);
+        Some(result)
+    }
+}
@@ -0,0 +1,94 @@
+//! A doubly linked list with a fixed capacity, keeping all nodes inline.
+//!
+//! A [`StaticReversibleList`] stores up to `N` nodes in an array inside the list itself, linked
+//! by indices, so it never allocates. It can be created in a `const` context and put into a
+//! `static`, and it works where there's no allocator at all. Otherwise, it's the same
+//! [`SlotList`] as a `SlabList` of the `slab` feature: removed nodes are reused by later
+//! insertions, and handles are checked in _O_(1) by a generation counter on each slot.
+//!
+//! Once all `N` slots are taken, inserting panics. The `try_` variants of the insertion methods
+//! hand the item back in a [`TryInsertError`](crate::TryInsertError) instead.
+
+use crate::slots::{sealed::Slots, Link, Slot, SlotList, NONE};
+
+pub use crate::slots::{Iter, IterMut, SlotHandle as StaticHandle};
+
+/// A doubly linked list holding at most `N` elements, without ever allocating.
+///
+/// See the module docs for details.
+pub type StaticReversibleList<T, const N: usize> = SlotList<T, ArraySlots<T, N>>;
+
+/// Owning iterator through a [`StaticReversibleList`].
+pub type IntoIter<T, const N: usize> = crate::slots::IntoIter<T, ArraySlots<T, N>>;
+
+/// Immutable cursor into a [`StaticReversibleList`], like [`crate::cursor::Cursor`].
+pub type Cursor<'list, T, const N: usize> = crate::slots::Cursor<'list, T, ArraySlots<T, N>>;
+
+/// Mutable cursor into a [`StaticReversibleList`], like [`crate::cursor::CursorMut`].
+pub type CursorMut<'list, T, const N: usize> = crate::slots::CursorMut<'list, T, ArraySlots<T, N>>;
+
+/// The `N` slots of a [`StaticReversibleList`].
+pub struct ArraySlots<T, const N: usize> {
+    slots: [Slot<T>; N],
+    /// How many slots have ever been used. All slots after them are fresh.
+    used: usize,
+}
+
+impl<T, const N: usize> Default for ArraySlots<T, N> {
+    fn default() -> Self {
+        Self::FRESH
+    }
+}
+
+impl<T, const N: usize> ArraySlots<T, N> {
+    const FRESH: Self = {
+        assert!(
+            N < NONE as usize,
+            "static lists hold at most u32::MAX - 1 elements"
+        );
+        Self {
+            slots: [const { Slot::FRESH }; N],
+            used: 0,
+        }
+    };
+}
+
+impl<T, const N: usize> Slots<T> for ArraySlots<T, N> {
+    fn slots(&self) -> &[Slot<T>] {
+        &self.slots[..self.used]
+    }
+
+    fn slots_mut(&mut self) -> &mut [Slot<T>] {
+        &mut self.slots[..self.used]
+    }
+
+    fn grow(&mut self) -> Option<Link> {
+        (self.used < N).then(|| {
+            self.used += 1;
+            (self.used - 1) as Link
+        })
+    }
+
+    fn max_len(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize> SlotList<T, ArraySlots<T, N>> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_storage(ArraySlots::FRESH)
+    }
+
+    /// Returns how many elements the list can hold, which is always `N`.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns if all `N` slots are taken, so inserting would fail.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len() == N
+    }
+}
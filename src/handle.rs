@@ -0,0 +1,137 @@
+//! Stable handles to single nodes in the list.
+//!
+//! A [`NodeHandle`] is returned whenever a node is inserted, e.g. by
+//! [`ReversibleList::push_back`] or [`CursorMut::insert_after`]. It keeps pointing at the same
+//! node, no matter how much the rest of the list is modified, moved around or even reordered.
+//! This allows accessing the node again in _O_(1) using [`get_by_handle`] or
+//! [`remove_by_handle`], without having to walk through the list.
+//!
+//! However, the handle itself can't tell if its node has been removed already. That's why all
+//! methods taking a handle are `unsafe` and require the caller to ensure that the node still
+//! exists in the list the handle is used with.
+//!
+//! # Checked handles
+//!
+//! With the `checked-handles` feature enabled, each list additionally keeps track of all its
+//! live nodes and the generation they were created in. This enables the safe `try_*` methods
+//! like [`try_get_by_handle`], which return `None` instead of causing UB if the handle is
+//! dangling or belongs to another list. The unsafe methods also check this with a
+//! `debug_assert!`.
+//!
+//! [`ReversibleList::push_back`]: crate::ReversibleList::push_back
+//! [`CursorMut::insert_after`]: crate::cursor::CursorMut::insert_after
+//! [`get_by_handle`]: crate::ReversibleList::get_by_handle
+//! [`remove_by_handle`]: crate::ReversibleList::remove_by_handle
+//! [`try_get_by_handle`]: crate::ReversibleList::try_get_by_handle
+
+#[cfg(feature = "checked-handles")]
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::Pointer;
+
+/// Opaque handle to one node in a [`ReversibleList`](crate::ReversibleList).
+///
+/// See the module docs for details.
+pub struct NodeHandle<T> {
+    pub(crate) node: Pointer<T>,
+    #[cfg(feature = "checked-handles")]
+    pub(crate) generation: u64,
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for NodeHandle<T> {}
+
+// SAFETY: A handle can only be dereferenced through the list it belongs to, so on its own it's
+//         just an address.
+unsafe impl<T> Send for NodeHandle<T> {}
+unsafe impl<T> Sync for NodeHandle<T> {}
+
+impl<T> fmt::Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("NodeHandle");
+        f.field("node", &self.node);
+        #[cfg(feature = "checked-handles")]
+        f.field("generation", &self.generation);
+        f.finish()
+    }
+}
+
+impl<T> PartialEq for NodeHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "checked-handles")]
+        if self.generation != other.generation {
+            return false;
+        }
+
+        self.node == other.node
+    }
+}
+
+impl<T> Eq for NodeHandle<T> {}
+
+impl<T> Hash for NodeHandle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.hash(state);
+        #[cfg(feature = "checked-handles")]
+        self.generation.hash(state);
+    }
+}
+
+/// Source of generations for all lists, so that a node allocated at the address of a freed node
+/// from _another_ list still can't be confused with it.
+#[cfg(feature = "checked-handles")]
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Keeps track of all live nodes of one list, mapping their addresses to their generation.
+///
+/// The node memory itself is never looked at, since it might be deallocated already.
+#[cfg(feature = "checked-handles")]
+#[derive(Debug, Default)]
+pub(crate) struct Registry {
+    live: HashMap<usize, u64>,
+}
+
+#[cfg(feature = "checked-handles")]
+impl Registry {
+    /// Records the given new node as live and returns its generation.
+    pub(crate) fn register<T>(&mut self, node: Pointer<T>) -> u64 {
+        let generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+        self.live.insert(node.as_ptr().addr(), generation);
+        generation
+    }
+
+    /// Records the given node as dead or moved elsewhere, returning its generation.
+    pub(crate) fn unregister<T>(&mut self, node: Pointer<T>) -> Option<u64> {
+        self.live.remove(&node.as_ptr().addr())
+    }
+
+    /// Records the given node which was previously live in another list.
+    pub(crate) fn adopt<T>(&mut self, node: Pointer<T>, generation: u64) {
+        self.live.insert(node.as_ptr().addr(), generation);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.live.len()
+    }
+
+    pub(crate) fn generation_of<T>(&self, node: Pointer<T>) -> Option<u64> {
+        self.live.get(&node.as_ptr().addr()).copied()
+    }
+
+    /// Returns if the given handle points at a live node in this registry.
+    pub(crate) fn contains<T>(&self, handle: NodeHandle<T>) -> bool {
+        self.generation_of(handle.node) == Some(handle.generation)
+    }
+}
@@ -0,0 +1,619 @@
+//! A linked list with _O_(log _n_) positional access, by keeping a skip list index on top.
+//!
+//! Walking to an index in a [`ReversibleList`](crate::ReversibleList) takes _O_(_n_), which adds
+//! up quickly for long lists. An [`IndexedList`] additionally gives each node a random number of
+//! "express lanes", each of which skips over several nodes at once and remembers how many. Any
+//! index can then be found by taking the longest skips which don't overshoot, so
+//! [`get`](IndexedList::get), [`insert`](IndexedList::insert),
+//! [`remove`](IndexedList::remove) and [`cursor_at`](IndexedList::cursor_at) take expected
+//! _O_(log _n_), while iteration still walks the plain doubly linked base lane.
+//!
+//! Every node stores its base lane inline, along with its height and where its further lanes
+//! start in one arena shared by the whole list. On average, there's about one third of a
+//! further lane per node, each of them a link and a skip width. Since every
+//! insertion and removal has to keep them in sync, this is a separate type instead of an option
+//! on [`ReversibleList`](crate::ReversibleList), which would have to pay for the bookkeeping in
+//! every single operation.
+//!
+//! Like [`XorList`](crate::xor::XorList), nodes live in a slab and link to each other by index.
+//!
+//! # Ranks
+//!
+//! The skip widths also answer the reverse question: [`index_of`](IndexedList::index_of) finds
+//! the current index of an element given its [`IndexedHandle`] in expected _O_(log _n_), by
+//! summing up the widths on the way to the end. Together with [`select`](IndexedList::select),
+//! which goes from an index to a handle, this supports ranked lists like leaderboards, where
+//! entries are tracked by handle while their rank keeps changing.
+
+use std::{fmt, iter::FusedIterator};
+
+/// Index of a node in the slab. The head sentinel is always at `HEAD`.
+type Link = usize;
+
+const HEAD: Link = 0;
+
+/// How many lanes there are at most, including the base one. With a promotion chance of
+/// one in four, that's plenty for any list fitting into memory.
+const MAX_LEVELS: usize = 16;
+
+#[derive(Clone, Copy)]
+struct Lane {
+    /// The next node on this lane, or `None` if this is the last one.
+    next: Option<Link>,
+    /// How many base lane steps `next` is away. For the last node on a lane, this is the
+    /// distance to the position after the last element.
+    width: usize,
+}
+
+struct Node<T> {
+    /// `None` only for the head sentinel and freed slots.
+    data: Option<T>,
+    /// Bumped whenever the slot is freed, so handles to the previous node stop matching.
+    generation: u32,
+    /// How many lanes this node has, including the base one.
+    height: u32,
+    /// The previous node on the base lane, `HEAD` for the first element.
+    prev: Link,
+    base: Lane,
+    /// Where the lanes above the base one start in the lane arena, if there are any.
+    upper: usize,
+}
+
+/// A reference to an element of an [`IndexedList`], which stays valid until exactly that
+/// element is removed, no matter how its index changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndexedHandle {
+    node: Link,
+    generation: u32,
+}
+
+/// A doubly linked list with a skip list index for fast positional access.
+///
+/// See the module docs for details.
+pub struct IndexedList<T> {
+    /// The head sentinel at `HEAD` has `MAX_LEVELS` lanes and sits at position 0, element `i`
+    /// is at position `i + 1`.
+    nodes: Vec<Node<T>>,
+    free: Vec<Link>,
+    /// The lanes above the base one of all nodes, `height - 1` consecutive ones per node.
+    lanes: Vec<Lane>,
+    /// Freed blocks in `lanes`, by how many lanes they hold.
+    free_lanes: [Vec<usize>; MAX_LEVELS],
+    end: Link,
+    len: usize,
+    rng: u64,
+}
+
+impl<T> IndexedList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        let empty = Lane {
+            next: None,
+            width: 1,
+        };
+        Self {
+            nodes: vec![Node {
+                data: None,
+                generation: 0,
+                height: MAX_LEVELS as u32,
+                prev: HEAD,
+                base: empty,
+                upper: 0,
+            }],
+            free: Vec::new(),
+            lanes: vec![empty; MAX_LEVELS - 1],
+            free_lanes: Default::default(),
+            end: HEAD,
+            len: 0,
+            rng: 0x2545_f491_4f6c_dd1d,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at the given index, in expected _O_(log _n_).
+    #[must_use]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        (idx < self.len).then(|| self.data(self.find(idx + 1)))
+    }
+
+    /// Returns the element at the given index mutably, in expected _O_(log _n_).
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        if self.len <= idx {
+            return None;
+        }
+        let node = self.find(idx + 1);
+        self.nodes[node].data.as_mut()
+    }
+
+    /// Returns a handle to the element at the given index, in expected _O_(log _n_), or `None`
+    /// if `idx` is out of bounds.
+    #[must_use]
+    pub fn select(&self, idx: usize) -> Option<IndexedHandle> {
+        (idx < self.len).then(|| self.handle(self.find(idx + 1)))
+    }
+
+    /// Returns the current index of the element the given handle points at, in expected
+    /// _O_(log _n_), or `None` if it has been removed already.
+    #[must_use]
+    pub fn index_of(&self, handle: IndexedHandle) -> Option<usize> {
+        if !self.contains_handle(handle) {
+            return None;
+        }
+
+        // the next node on the highest lane of a node is at least as high, so always taking
+        // the highest lane climbs up just like a search from the head would climb down
+        let mut node = handle.node;
+        let mut to_end = 0;
+        loop {
+            let lane = self.lane(node, self.nodes[node].height as usize - 1);
+            to_end += lane.width;
+            match lane.next {
+                Some(next) => node = next,
+                None => break,
+            }
+        }
+        // the widths add up to the distance to one past the last element at position `len`
+        Some(self.len - to_end)
+    }
+
+    /// Returns if the given handle points at an element of this list.
+    #[must_use]
+    pub fn contains_handle(&self, handle: IndexedHandle) -> bool {
+        self.nodes
+            .get(handle.node)
+            .is_some_and(|node| node.generation == handle.generation && node.data.is_some())
+    }
+
+    /// Returns the element the given handle points at, or `None` if it has been removed.
+    #[must_use]
+    pub fn try_get_by_handle(&self, handle: IndexedHandle) -> Option<&T> {
+        self.contains_handle(handle).then(|| self.data(handle.node))
+    }
+
+    /// Returns the element the given handle points at mutably, or `None` if it has been
+    /// removed.
+    pub fn try_get_by_handle_mut(&mut self, handle: IndexedHandle) -> Option<&mut T> {
+        if !self.contains_handle(handle) {
+            return None;
+        }
+        self.nodes[handle.node].data.as_mut()
+    }
+
+    /// Removes the element the given handle points at and returns it, in expected
+    /// _O_(log _n_), or `None` if it has been removed already.
+    pub fn try_remove_by_handle(&mut self, handle: IndexedHandle) -> Option<T> {
+        let idx = self.index_of(handle)?;
+        self.remove(idx)
+    }
+
+    /// Returns an iterator through this list.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            forward: self.nodes[HEAD].base.next,
+            backward: self.end,
+            remaining: self.len,
+        }
+    }
+
+    /// Creates a cursor pointing at the element with the given index, in expected
+    /// _O_(log _n_).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn cursor_at(&self, idx: usize) -> Cursor<'_, T> {
+        assert!(
+            idx < self.len,
+            "tried to move to index {idx} but the len is {}",
+            self.len
+        );
+        Cursor {
+            node: self.find(idx + 1),
+            index: idx,
+            list: self,
+        }
+    }
+
+    /// Inserts the given item at the given index, shifting all elements after it one position
+    /// back, in expected _O_(log _n_). Returns a handle to the new element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    pub fn insert(&mut self, idx: usize, item: T) -> IndexedHandle {
+        assert!(
+            idx <= self.len,
+            "tried to insert at index {idx} but the len is {}",
+            self.len
+        );
+
+        let (chain, chain_pos) = self.predecessors(idx);
+        let height = self.random_height();
+        let node = self.allocate(item, height);
+
+        for (level, (&pred, &pred_pos)) in chain.iter().zip(&chain_pos).enumerate() {
+            let lane = self.lane(pred, level);
+            if level < height {
+                // the new node sits at position `idx + 1`, right between `pred` and its next
+                *self.lane_mut(node, level) = Lane {
+                    next: lane.next,
+                    width: pred_pos + lane.width - idx,
+                };
+                *self.lane_mut(pred, level) = Lane {
+                    next: Some(node),
+                    width: idx + 1 - pred_pos,
+                };
+            } else {
+                self.lane_mut(pred, level).width += 1;
+            }
+        }
+
+        let next = self.nodes[node].base.next;
+        self.nodes[node].prev = chain[0];
+        match next {
+            Some(next) => self.nodes[next].prev = node,
+            None => self.end = node,
+        }
+        self.len += 1;
+        self.handle(node)
+    }
+
+    /// Removes the element at the given index and returns it, in expected _O_(log _n_).
+    /// Returns `None` if `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) -> Option<T> {
+        if self.len <= idx {
+            return None;
+        }
+
+        let (chain, _) = self.predecessors(idx);
+        let node = self.nodes[chain[0]]
+            .base
+            .next
+            .expect("predecessor of an element has a next node");
+
+        for (level, &pred) in chain.iter().enumerate() {
+            if self.lane(pred, level).next == Some(node) {
+                let lane = self.lane(node, level);
+                let pred_lane = self.lane_mut(pred, level);
+                pred_lane.next = lane.next;
+                pred_lane.width += lane.width - 1;
+            } else {
+                self.lane_mut(pred, level).width -= 1;
+            }
+        }
+
+        let (prev, next) = (self.nodes[node].prev, self.nodes[node].base.next);
+        match next {
+            Some(next) => self.nodes[next].prev = prev,
+            None => self.end = prev,
+        }
+        self.len -= 1;
+
+        let slot = &mut self.nodes[node];
+        let further = slot.height as usize - 1;
+        if further > 0 {
+            self.free_lanes[further].push(slot.upper);
+        }
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(node);
+        slot.data.take()
+    }
+
+    /// Inserts the given item before the first element of the list.
+    pub fn push_front(&mut self, item: T) -> IndexedHandle {
+        self.insert(0, item)
+    }
+
+    /// Appends the given item to the end of the list.
+    pub fn push_back(&mut self, item: T) -> IndexedHandle {
+        self.insert(self.len, item)
+    }
+
+    /// Removes the element at the beginning of the list.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    /// Removes the element at the end of the list.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.remove(self.len.checked_sub(1)?)
+    }
+
+    fn handle(&self, node: Link) -> IndexedHandle {
+        IndexedHandle {
+            node,
+            generation: self.nodes[node].generation,
+        }
+    }
+
+    /// Returns the lane of the given node at the given level, which must be below its height.
+    fn lane(&self, node: Link, level: usize) -> Lane {
+        let node = &self.nodes[node];
+        match level {
+            0 => node.base,
+            _ => self.lanes[node.upper + level - 1],
+        }
+    }
+
+    fn lane_mut(&mut self, node: Link, level: usize) -> &mut Lane {
+        let node = &mut self.nodes[node];
+        match level {
+            0 => &mut node.base,
+            _ => &mut self.lanes[node.upper + level - 1],
+        }
+    }
+
+    fn data(&self, node: Link) -> &T {
+        self.nodes[node]
+            .data
+            .as_ref()
+            .expect("only the head sentinel has no data")
+    }
+
+    /// Returns the node at the given position, which must be in `1..=len`.
+    fn find(&self, position: usize) -> Link {
+        let mut node = HEAD;
+        let mut pos = 0;
+        for level in (0..MAX_LEVELS).rev() {
+            // the width of a lane's last node reaches exactly one past the end, so it's never
+            // taken as long as `position` is in bounds
+            while pos + self.lane(node, level).width <= position {
+                let lane = self.lane(node, level);
+                pos += lane.width;
+                node = lane.next.expect("lane widths never skip past the end");
+            }
+        }
+        node
+    }
+
+    /// Returns for each level the last node at position `idx` or before, along with its
+    /// position.
+    fn predecessors(&self, idx: usize) -> ([Link; MAX_LEVELS], [usize; MAX_LEVELS]) {
+        let mut chain = [HEAD; MAX_LEVELS];
+        let mut chain_pos = [0; MAX_LEVELS];
+        let mut node = HEAD;
+        let mut pos = 0;
+        for level in (0..MAX_LEVELS).rev() {
+            while pos + self.lane(node, level).width <= idx {
+                let lane = self.lane(node, level);
+                pos += lane.width;
+                node = lane.next.expect("lane widths never skip past the end");
+            }
+            chain[level] = node;
+            chain_pos[level] = pos;
+        }
+        (chain, chain_pos)
+    }
+
+    /// Picks a random number of lanes for a new node, each further one with a chance of one in
+    /// four.
+    fn random_height(&mut self) -> usize {
+        // xorshift64, which is plenty random for balancing
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        let promotions = (self.rng.trailing_zeros() / 2) as usize;
+        (1 + promotions).min(MAX_LEVELS)
+    }
+
+    fn allocate(&mut self, item: T, height: usize) -> Link {
+        let empty = Lane {
+            next: None,
+            width: 0,
+        };
+        let further = height - 1;
+        let upper = if further == 0 {
+            0
+        } else if let Some(upper) = self.free_lanes[further].pop() {
+            upper
+        } else {
+            self.lanes.resize(self.lanes.len() + further, empty);
+            self.lanes.len() - further
+        };
+        let mut node = Node {
+            data: Some(item),
+            generation: 0,
+            height: height as u32,
+            prev: HEAD,
+            base: empty,
+            upper,
+        };
+
+        match self.free.pop() {
+            Some(slot) => {
+                node.generation = self.nodes[slot].generation;
+                self.nodes[slot] = node;
+                slot
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+}
+
+impl<T: Clone> Clone for IndexedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IndexedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for IndexedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for IndexedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for IndexedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'list, T> IntoIterator for &'list IndexedList<T> {
+    type Item = &'list T;
+    type IntoIter = Iter<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for IndexedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for IndexedList<T> {}
+
+/// Iterator through an [`IndexedList`].
+pub struct Iter<'list, T> {
+    list: &'list IndexedList<T>,
+    forward: Option<Link>,
+    backward: Link,
+    remaining: usize,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list,
+            forward: self.forward,
+            backward: self.backward,
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&crate::iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'list, T> Iterator for Iter<'list, T> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.forward?;
+        self.forward = self.list.nodes[node].base.next;
+        Some(self.list.data(node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'list, T> DoubleEndedIterator for Iter<'list, T> {
+    fn next_back(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.backward;
+        self.backward = self.list.nodes[node].prev;
+        Some(self.list.data(node))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Immutable cursor into an [`IndexedList`], like [`crate::cursor::Cursor`].
+pub struct Cursor<'list, T> {
+    node: Link,
+    index: usize,
+    list: &'list IndexedList<T>,
+}
+
+impl<T> Cursor<'_, T> {
+    /// Returns the data stored on the current node.
+    pub fn current(&self) -> &T {
+        self.list.data(self.node)
+    }
+
+    /// Returns the index of the current node.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a handle to the current node.
+    pub fn handle(&self) -> IndexedHandle {
+        self.list.handle(self.node)
+    }
+
+    /// Makes this cursor look at the **previous** node in the list. If there is none, the
+    /// cursor will point at the **end** of the list.
+    pub fn move_prev(&mut self) {
+        if self.index == 0 {
+            self.node = self.list.end;
+            self.index = self.list.len - 1;
+        } else {
+            self.node = self.list.nodes[self.node].prev;
+            self.index -= 1;
+        }
+    }
+
+    /// Makes this cursor look at the **next** node in the list. If there is none, the cursor
+    /// will point at the **beginning** of the list.
+    pub fn move_next(&mut self) {
+        match self.list.nodes[self.node].base.next {
+            Some(next) => {
+                self.node = next;
+                self.index += 1;
+            }
+            None => {
+                self.node = self.list.nodes[HEAD].base.next.expect("list isn't empty");
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves this cursor to the given index, in expected _O_(log _n_) regardless of where the
+    /// cursor is right now.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_idx` is out of bounds.
+    pub fn move_to(&mut self, target_idx: usize) {
+        *self = self.list.cursor_at(target_idx);
+    }
+}
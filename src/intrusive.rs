@@ -0,0 +1,495 @@
+//! A doubly linked list whose links live inside the elements themselves.
+//!
+//! Where [`ReversibleList`](crate::ReversibleList) allocates one node per element and moves the
+//! element into it, an [`IntrusiveList`] just borrows elements which are owned elsewhere, and
+//! chains them through a [`Link`] field embedded in each of them. So no allocation is needed at
+//! all, and one element can even be part of several lists at once, given it has one link for
+//! each.
+//!
+//! Which field to use is told by an [`Adapter`], most conveniently declared using
+//! [`intrusive_adapter!`](crate::intrusive_adapter):
+//!
+//! ```
+//! use casual_linked_list::{intrusive::{IntrusiveList, Link}, intrusive_adapter};
+//!
+//! struct Task {
+//!     name: &'static str,
+//!     queue: Link<Task>,
+//! }
+//!
+//! intrusive_adapter!(QueueAdapter = Task { queue });
+//!
+//! let laundry = Task { name: "laundry", queue: Link::new() };
+//! let dishes = Task { name: "dishes", queue: Link::new() };
+//!
+//! let mut queue = IntrusiveList::<QueueAdapter>::new();
+//! queue.push_back(&laundry);
+//! queue.push_front(&dishes);
+//! assert_eq!(queue.iter().map(|task| task.name).collect::<Vec<_>>(), ["dishes", "laundry"]);
+//! ```
+//!
+//! Since the list only holds shared references, the links use interior mutability. A link can
+//! only be part of one list at a time, linking it a second time panics. Dropping the list
+//! unlinks all elements again, so they can be reused afterwards.
+
+use std::{cell::Cell, fmt, iter::FusedIterator, marker::PhantomData, ptr::NonNull};
+
+/// Declares a zero-sized [`Adapter`] which chains `$value`s through their `$field`, which has to
+/// be a [`Link<$value>`].
+#[macro_export]
+macro_rules! intrusive_adapter {
+    ($vis:vis $name:ident = $value:ty { $field:ident }) => {
+        #[derive(Debug, Clone, Copy, Default)]
+        $vis struct $name;
+
+        // SAFETY: The link is a field of the value, so it's always the same one.
+        unsafe impl $crate::intrusive::Adapter for $name {
+            type Value = $value;
+
+            fn link(value: &$value) -> &$crate::intrusive::Link<$value> {
+                &value.$field
+            }
+        }
+    };
+}
+
+/// The links to the neighbours of an element in an [`IntrusiveList`], embedded in the element.
+pub struct Link<T> {
+    prev: Cell<Option<NonNull<T>>>,
+    next: Cell<Option<NonNull<T>>>,
+    linked: Cell<bool>,
+}
+
+impl<T> Link<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            prev: Cell::new(None),
+            next: Cell::new(None),
+            linked: Cell::new(false),
+        }
+    }
+
+    /// Returns if the element is currently part of a list through this link.
+    #[must_use]
+    pub fn is_linked(&self) -> bool {
+        self.linked.get()
+    }
+}
+
+impl<T> Default for Link<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Link<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Link")
+            .field("linked", &self.is_linked())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Tells an [`IntrusiveList`] where to find the [`Link`] in its elements.
+///
+/// # Safety
+///
+/// [`Adapter::link`] must always return the same link for the same value, and no two values
+/// may share one link. Using [`intrusive_adapter!`](crate::intrusive_adapter) ensures that.
+pub unsafe trait Adapter {
+    type Value;
+
+    fn link(value: &Self::Value) -> &Link<Self::Value>;
+}
+
+/// A doubly linked list of elements borrowed for `'a`, chained through their [`Link`]s.
+///
+/// See the module docs for details.
+pub struct IntrusiveList<'a, A: Adapter> {
+    start: Option<NonNull<A::Value>>,
+    end: Option<NonNull<A::Value>>,
+    len: usize,
+    _borrows: PhantomData<(&'a A::Value, A)>,
+}
+
+impl<'a, A: Adapter> IntrusiveList<'a, A> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            start: None,
+            end: None,
+            len: 0,
+            _borrows: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator through this list.
+    pub fn iter(&self) -> Iter<'_, 'a, A> {
+        Iter {
+            forward: self.start,
+            backward: self.end,
+            remaining: self.len,
+            _list: PhantomData,
+        }
+    }
+
+    /// Inserts the given element before the first one, in _O_(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is already part of a list through the link of `A`.
+    pub fn push_front(&mut self, value: &'a A::Value) {
+        self.link_between(None, self.start, value);
+    }
+
+    /// Appends the given element to the end of the list, in _O_(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is already part of a list through the link of `A`.
+    pub fn push_back(&mut self, value: &'a A::Value) {
+        self.link_between(self.end, None, value);
+    }
+
+    /// Unlinks the first element and returns it.
+    pub fn pop_front(&mut self) -> Option<&'a A::Value> {
+        let first = self.start?;
+        // SAFETY: All linked elements are borrowed for `'a`.
+        Some(unsafe { self.unlink(first) })
+    }
+
+    /// Unlinks the last element and returns it.
+    pub fn pop_back(&mut self) -> Option<&'a A::Value> {
+        let last = self.end?;
+        // SAFETY: See `pop_front`.
+        Some(unsafe { self.unlink(last) })
+    }
+
+    /// Creates a cursor pointing at the **first** element in the list.
+    pub fn cursor_front(&self) -> Cursor<'_, 'a, A> {
+        Cursor {
+            node: self.start,
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Creates a cursor pointing at the **last** element in the list.
+    pub fn cursor_back(&self) -> Cursor<'_, 'a, A> {
+        Cursor {
+            node: self.end,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    pub fn cursor_mut_front(&mut self) -> CursorMut<'_, 'a, A> {
+        CursorMut {
+            node: self.start,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_mut_back(&mut self) -> CursorMut<'_, 'a, A> {
+        CursorMut {
+            node: self.end,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    /// Links the given element between the two given neighbours, which must be adjacent
+    /// elements of this list, or `None` for the respective end.
+    fn link_between(
+        &mut self,
+        before: Option<NonNull<A::Value>>,
+        after: Option<NonNull<A::Value>>,
+        value: &'a A::Value,
+    ) {
+        let link = A::link(value);
+        assert!(!link.is_linked(), "element is already part of a list");
+
+        let node = NonNull::from(value);
+        link.prev.set(before);
+        link.next.set(after);
+        link.linked.set(true);
+
+        // SAFETY: The neighbours are elements of this list, so they're borrowed for `'a`.
+        match before {
+            Some(before) => A::link(unsafe { before.as_ref() }).next.set(Some(node)),
+            None => self.start = Some(node),
+        }
+        match after {
+            Some(after) => A::link(unsafe { after.as_ref() }).prev.set(Some(node)),
+            None => self.end = Some(node),
+        }
+        self.len += 1;
+    }
+
+    /// Unlinks the given element from its neighbours and returns it.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be an element of this list.
+    unsafe fn unlink(&mut self, node: NonNull<A::Value>) -> &'a A::Value {
+        // SAFETY: Delegated to the caller, all elements are borrowed for `'a`.
+        let value = unsafe { node.as_ref() };
+        let link = A::link(value);
+        let (before, after) = (link.prev.take(), link.next.take());
+        link.linked.set(false);
+
+        // SAFETY: See above.
+        match before {
+            Some(before) => A::link(unsafe { before.as_ref() }).next.set(after),
+            None => self.start = after,
+        }
+        match after {
+            Some(after) => A::link(unsafe { after.as_ref() }).prev.set(before),
+            None => self.end = before,
+        }
+        self.len -= 1;
+        value
+    }
+}
+
+impl<A: Adapter> Drop for IntrusiveList<'_, A> {
+    fn drop(&mut self) {
+        // reset all links, so the elements can be linked into other lists afterwards
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<A: Adapter> Default for IntrusiveList<'_, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Adapter<Value: fmt::Debug>> fmt::Debug for IntrusiveList<'_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, A: Adapter> Extend<&'a A::Value> for IntrusiveList<'a, A> {
+    fn extend<I: IntoIterator<Item = &'a A::Value>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<'list, 'a, A: Adapter> IntoIterator for &'list IntrusiveList<'a, A> {
+    type Item = &'a A::Value;
+    type IntoIter = Iter<'list, 'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator through an [`IntrusiveList`].
+pub struct Iter<'list, 'a, A: Adapter> {
+    forward: Option<NonNull<A::Value>>,
+    backward: Option<NonNull<A::Value>>,
+    remaining: usize,
+    _list: PhantomData<&'list IntrusiveList<'a, A>>,
+}
+
+impl<A: Adapter> Clone for Iter<'_, '_, A> {
+    fn clone(&self) -> Self {
+        Self {
+            forward: self.forward,
+            backward: self.backward,
+            remaining: self.remaining,
+            _list: PhantomData,
+        }
+    }
+}
+
+impl<A: Adapter<Value: fmt::Debug>> fmt::Debug for Iter<'_, '_, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&crate::iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'a, A: Adapter> Iterator for Iter<'_, 'a, A> {
+    type Item = &'a A::Value;
+
+    fn next(&mut self) -> Option<&'a A::Value> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: The list is borrowed, so all its elements stay linked and borrowed for `'a`.
+        let value = unsafe { self.forward?.as_ref() };
+        self.forward = A::link(value).next.get();
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, A: Adapter> DoubleEndedIterator for Iter<'_, 'a, A> {
+    fn next_back(&mut self) -> Option<&'a A::Value> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: See `next`.
+        let value = unsafe { self.backward?.as_ref() };
+        self.backward = A::link(value).prev.get();
+        Some(value)
+    }
+}
+
+impl<A: Adapter> ExactSizeIterator for Iter<'_, '_, A> {}
+
+impl<A: Adapter> FusedIterator for Iter<'_, '_, A> {}
+
+macro_rules! impl_common_cursor {
+    ($name:ident) => {
+        impl<'list, 'a, A: Adapter> $name<'list, 'a, A> {
+            /// Returns the current element, or `None` if the list is empty.
+            pub fn current(&self) -> Option<&'a A::Value> {
+                // SAFETY: The node is an element of the list, so it's borrowed for `'a`.
+                self.node.map(|node| unsafe { node.as_ref() })
+            }
+
+            /// Returns the index of the current element, or `None` if the list is empty.
+            pub fn index(&self) -> Option<usize> {
+                let _ = self.node?;
+                Some(self.index)
+            }
+
+            /// Makes this cursor look at the **previous** element in the list. If there is none,
+            /// the cursor will point at the **end** of the list. Does nothing if the list is empty.
+            pub fn move_prev(&mut self) {
+                let Some(current) = self.current() else {
+                    return;
+                };
+
+                if self.index == 0 {
+                    self.node = self.list.end;
+                    self.index = self.list.len - 1;
+                } else {
+                    self.node = A::link(current).prev.get();
+                    self.index -= 1;
+                }
+            }
+
+            /// Makes this cursor look at the **next** element in the list. If there is none, the
+            /// cursor will point at the **beginning** of the list. Does nothing if the list is
+            /// empty.
+            pub fn move_next(&mut self) {
+                let Some(current) = self.current() else {
+                    return;
+                };
+
+                if self.index == self.list.len - 1 {
+                    self.node = self.list.start;
+                    self.index = 0;
+                } else {
+                    self.node = A::link(current).next.get();
+                    self.index += 1;
+                }
+            }
+        }
+    };
+}
+
+/// Immutable cursor into an [`IntrusiveList`], like [`crate::cursor::Cursor`].
+pub struct Cursor<'list, 'a, A: Adapter> {
+    node: Option<NonNull<A::Value>>,
+    index: usize,
+    list: &'list IntrusiveList<'a, A>,
+}
+
+impl_common_cursor!(Cursor);
+
+/// Mutable cursor into an [`IntrusiveList`], like [`crate::cursor::CursorMut`].
+pub struct CursorMut<'list, 'a, A: Adapter> {
+    node: Option<NonNull<A::Value>>,
+    index: usize,
+    list: &'list mut IntrusiveList<'a, A>,
+}
+
+impl_common_cursor!(CursorMut);
+
+impl<'a, A: Adapter> CursorMut<'_, 'a, A> {
+    /// Links the given element **after** the current one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is already part of a list through the link of `A`.
+    pub fn insert_after(&mut self, value: &'a A::Value) {
+        let after = match self.current() {
+            Some(current) => A::link(current).next.get(),
+            None => None,
+        };
+        self.list.link_between(self.node, after, value);
+
+        if self.list.len == 1 {
+            // list was previously empty, so the cursor now needs to point at the new element
+            self.node = self.list.start;
+        }
+    }
+
+    /// Links the given element **before** the current one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is already part of a list through the link of `A`.
+    pub fn insert_before(&mut self, value: &'a A::Value) {
+        let before = match self.current() {
+            Some(current) => A::link(current).prev.get(),
+            None => None,
+        };
+        self.list.link_between(before, self.node, value);
+
+        if self.list.len == 1 {
+            self.node = self.list.start;
+        } else {
+            self.index += 1;
+        }
+    }
+
+    /// Unlinks the current element and returns it. Returns `None` if the list is empty.
+    ///
+    /// Afterwards, the cursor points at the element after the removed one, or the one before it
+    /// if there is none, just like [`crate::cursor::CursorMut::remove_current`].
+    pub fn remove_current(&mut self) -> Option<&'a A::Value> {
+        let current = self.current()?;
+        let link = A::link(current);
+        let node = self.node?;
+        self.node = match (link.prev.get(), link.next.get()) {
+            (_, Some(next)) => Some(next),
+            (Some(prev), None) => {
+                self.index -= 1;
+                Some(prev)
+            }
+            (None, None) => None,
+        };
+
+        // SAFETY: The node is an element of the list.
+        Some(unsafe { self.list.unlink(node) })
+    }
+}
@@ -12,70 +12,143 @@
 //! The core idea is to, in one step of iteration (where "current" depends on `next` or `next_back`
 //! being called):
 //!
-//! 1. If we're already finished, `None`
-//! 2. If forward and backward running pointers are equal, mark this iterator as finished,
-//!    but still yield *this* node
+//! 1. If there are no nodes remaining, `None`
+//! 2. Count down the remaining nodes
 //! 3. Return the data of the current node
 //! 4. Set the current node to the next node depending on the direction
+//!
+//! Since both running pointers meet exactly when the remaining count hits zero, they never
+//! yield the same node twice.
 
-use std::marker::PhantomData;
+use std::{fmt, iter::FusedIterator, marker::PhantomData, mem};
 
-use crate::MaybePointer;
+use allocator_api2::alloc::{Allocator, Global};
 
-pub struct Iter<'list, T: 'list> {
+use crate::{cursor::Cursor, MaybePointer, Pointer, ReversibleList};
+
+/// The running pointers shared by all borrowing iterators, which only differ in what kind of
+/// reference they create from the yielded nodes.
+struct Links<T> {
     forward_node: MaybePointer<T>,
     backward_node: MaybePointer<T>,
-    finished: bool,
-    _bound_to_list: PhantomData<&'list ()>,
+    remaining: usize,
 }
 
+impl<T> Clone for Links<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Links<T> {}
+
 #[derive(Clone, Copy)]
 enum Direction {
     Forward,
     Backward,
 }
 
-impl<'list, T: 'list> Iter<'list, T> {
+impl<T> Links<T> {
     /// # Safety
     ///
     /// The caller must ensure that the given two nodes are start and end of a valid linked
-    /// list.
-    pub(crate) unsafe fn new(
+    /// list with `len` nodes.
+    unsafe fn new(
         forward_start: MaybePointer<T>,
         backward_start: MaybePointer<T>,
+        len: usize,
     ) -> Self {
         Self {
             forward_node: forward_start,
             backward_node: backward_start,
-            finished: false,
-            _bound_to_list: PhantomData,
+            remaining: len,
         }
     }
 
-    fn next_in_dir(&mut self, direction: Direction) -> Option<&'list T> {
-        if self.finished {
+    fn next_in_dir(&mut self, direction: Direction) -> MaybePointer<T> {
+        if self.remaining == 0 {
             return None;
         }
+        self.remaining -= 1;
 
-        if self.forward_node == self.backward_node {
-            // only return this node, then stop
-            self.finished = true;
-        }
-
-        let old_node;
-
+        // the links are read through the raw pointer, so that no reference to the whole node
+        // is created, which would alias with the `&mut T` handed out by `IterMut`
+        // SAFETY: Delegated to the contract of `Self::new`.
         match direction {
             Direction::Forward => {
-                old_node = unsafe { self.forward_node?.as_ref() };
-                self.forward_node = old_node.next;
+                let old_node = self.forward_node?;
+                self.forward_node = unsafe { (*old_node.as_ptr()).next };
+                Some(old_node)
             }
             Direction::Backward => {
-                old_node = unsafe { self.backward_node?.as_ref() };
-                self.backward_node = old_node.prev;
+                let old_node = self.backward_node?;
+                self.backward_node = unsafe { (*old_node.as_ptr()).prev };
+                Some(old_node)
             }
-        };
+        }
+    }
 
-        Some(&old_node.data)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Shows the elements an iterator has yet to yield, without consuming it.
+pub(crate) struct Remaining<I>(pub(crate) I);
+
+impl<I: Clone + Iterator<Item: fmt::Debug>> fmt::Debug for Remaining<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.clone()).finish()
+    }
+}
+
+/// Iterator through shared references to all elements.
+pub struct Iter<'list, T: 'list> {
+    links: Links<T>,
+    _bound_to_list: PhantomData<&'list ()>,
+}
+
+// SAFETY: The iterator only hands out shared references, so it behaves like a `&T`.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+impl<'list, T: 'list> Iter<'list, T> {
+    /// # Safety
+    ///
+    /// The caller must ensure that the given two nodes are start and end of a valid linked
+    /// list with `len` nodes, which is borrowed for `'list`.
+    pub(crate) unsafe fn new(
+        forward_start: MaybePointer<T>,
+        backward_start: MaybePointer<T>,
+        len: usize,
+    ) -> Self {
+        Self {
+            // SAFETY: Delegated to the caller.
+            links: unsafe { Links::new(forward_start, backward_start, len) },
+            _bound_to_list: PhantomData,
+        }
+    }
+
+    fn data(node: Pointer<T>) -> &'list T {
+        // SAFETY: The list is borrowed for `'list`, so the node stays valid for that long.
+        unsafe { &(*node.as_ptr()).data }
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            links: self.links,
+            _bound_to_list: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&Remaining(self.clone()))
+            .finish()
     }
 }
 
@@ -83,12 +156,700 @@ impl<'list, T: 'list> Iterator for Iter<'list, T> {
     type Item = &'list T;
 
     fn next(&mut self) -> Option<&'list T> {
-        self.next_in_dir(Direction::Forward)
+        self.links.next_in_dir(Direction::Forward).map(Self::data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.links.size_hint()
     }
 }
 
 impl<'list, T: 'list> DoubleEndedIterator for Iter<'list, T> {
     fn next_back(&mut self) -> Option<&'list T> {
-        self.next_in_dir(Direction::Backward)
+        self.links.next_in_dir(Direction::Backward).map(Self::data)
+    }
+}
+
+impl<'list, T: 'list> ExactSizeIterator for Iter<'list, T> {}
+
+impl<'list, T: 'list> FusedIterator for Iter<'list, T> {}
+
+/// Iterator through mutable references to all elements.
+pub struct IterMut<'list, T: 'list> {
+    links: Links<T>,
+    _bound_to_list: PhantomData<&'list mut T>,
+}
+
+// SAFETY: The iterator hands out mutable references, so it behaves like a `&mut T`.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'list, T: 'list> IterMut<'list, T> {
+    /// # Safety
+    ///
+    /// The caller must ensure that the given two nodes are start and end of a valid linked
+    /// list with `len` nodes, which is borrowed mutably for `'list`.
+    pub(crate) unsafe fn new(
+        forward_start: MaybePointer<T>,
+        backward_start: MaybePointer<T>,
+        len: usize,
+    ) -> Self {
+        Self {
+            // SAFETY: Delegated to the caller.
+            links: unsafe { Links::new(forward_start, backward_start, len) },
+            _bound_to_list: PhantomData,
+        }
+    }
+
+    fn data(node: Pointer<T>) -> &'list mut T {
+        // SAFETY: The list is borrowed mutably for `'list`, and each node is yielded at most
+        //         once, so this is the only reference to the data.
+        unsafe { &mut (*node.as_ptr()).data }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remaining = Iter {
+            links: self.links,
+            _bound_to_list: PhantomData,
+        };
+        f.debug_tuple("IterMut")
+            .field(&Remaining(remaining))
+            .finish()
+    }
+}
+
+impl<'list, T: 'list> Iterator for IterMut<'list, T> {
+    type Item = &'list mut T;
+
+    fn next(&mut self) -> Option<&'list mut T> {
+        self.links.next_in_dir(Direction::Forward).map(Self::data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.links.size_hint()
+    }
+}
+
+impl<'list, T: 'list> DoubleEndedIterator for IterMut<'list, T> {
+    fn next_back(&mut self) -> Option<&'list mut T> {
+        self.links.next_in_dir(Direction::Backward).map(Self::data)
+    }
+}
+
+impl<'list, T: 'list> ExactSizeIterator for IterMut<'list, T> {}
+
+impl<'list, T: 'list> FusedIterator for IterMut<'list, T> {}
+
+/// Iterator through shared references to the values in a list of boxes, created by
+/// [`ReversibleList::iter_unboxed`].
+pub type Unboxed<'list, T> = std::iter::Map<Iter<'list, Box<T>>, fn(&Box<T>) -> &T>;
+
+/// Iterator through mutable references to the values in a list of boxes, created by
+/// [`ReversibleList::iter_mut_unboxed`].
+pub type UnboxedMut<'list, T> = std::iter::Map<IterMut<'list, Box<T>>, fn(&mut Box<T>) -> &mut T>;
+
+/// Iterator through all elements along with their index, created by
+/// [`ReversibleList::indexed_iter`].
+pub type Indexed<'list, T> = std::iter::Enumerate<Iter<'list, T>>;
+
+/// Iterator moving all elements out of a list.
+///
+/// Nodes are freed as soon as their element has been yielded. Dropping the iterator drops all
+/// remaining elements and frees their nodes, even if one of their destructors panics.
+pub struct IntoIter<T, A: Allocator = Global> {
+    list: ReversibleList<T, A>,
+}
+
+impl<T, A: Allocator> IntoIter<T, A> {
+    pub(crate) fn new(mut list: ReversibleList<T, A>) -> Self {
+        list.set_pool_limit(0);
+        Self { list }
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for IntoIter<T, A> {
+    fn clone(&self) -> Self {
+        Self::new(self.list.clone())
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list).finish()
+    }
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        // keeps going if an element panics while being dropped, so that no node is leaked
+        self.list.clear();
+    }
+}
+
+/// Iterator through the runs of elements separated by elements matching a predicate, created
+/// by [`ReversibleList::split`]. Each run is yielded as an [`Iter`] through it.
+pub struct Split<'list, T: 'list, F> {
+    next_start: MaybePointer<T>,
+    finished: bool,
+    pred: F,
+    _bound_to_list: PhantomData<&'list ()>,
+}
+
+// SAFETY: Only shared references to the elements are handed out, like with `Iter`.
+unsafe impl<T: Sync, F: Send> Send for Split<'_, T, F> {}
+unsafe impl<T: Sync, F: Sync> Sync for Split<'_, T, F> {}
+
+impl<'list, T: 'list, F: FnMut(&T) -> bool> Split<'list, T, F> {
+    /// # Safety
+    ///
+    /// The caller must ensure that `start` is the start of a valid linked list, which is
+    /// borrowed for `'list`.
+    pub(crate) unsafe fn new(start: MaybePointer<T>, pred: F) -> Self {
+        Self {
+            next_start: start,
+            finished: false,
+            pred,
+            _bound_to_list: PhantomData,
+        }
+    }
+}
+
+impl<'list, T: 'list, F: FnMut(&T) -> bool> Iterator for Split<'list, T, F> {
+    type Item = Iter<'list, T>;
+
+    fn next(&mut self) -> Option<Iter<'list, T>> {
+        if self.finished {
+            return None;
+        }
+
+        let first = self.next_start;
+        let mut last = None;
+        let mut len = 0;
+        let mut current = first;
+        while let Some(node) = current {
+            // SAFETY: The list is borrowed for `'list`, so all its nodes stay valid.
+            let node_ref = unsafe { &*node.as_ptr() };
+            if (self.pred)(&node_ref.data) {
+                self.next_start = node_ref.next;
+                // SAFETY: `first` up to `last` are `len` nodes of the borrowed list.
+                return Some(unsafe { Iter::new(first.filter(|_| len > 0), last, len) });
+            }
+            last = current;
+            len += 1;
+            current = node_ref.next;
+        }
+
+        self.finished = true;
+        // SAFETY: Same as above.
+        Some(unsafe { Iter::new(first.filter(|_| len > 0), last, len) })
+    }
+}
+
+impl<'list, T: 'list, F: FnMut(&T) -> bool> FusedIterator for Split<'list, T, F> {}
+
+/// Iterator moving the runs of elements separated by elements matching a predicate out of a
+/// list, created by [`ReversibleList::into_split`]. Separators are dropped.
+pub struct IntoSplit<T, A: Allocator, F> {
+    list: ReversibleList<T, A>,
+    finished: bool,
+    pred: F,
+}
+
+impl<T, A: Allocator, F> IntoSplit<T, A, F> {
+    pub(crate) fn new(list: ReversibleList<T, A>, pred: F) -> Self {
+        Self {
+            list,
+            finished: false,
+            pred,
+        }
+    }
+}
+
+impl<T, A: Allocator + Clone, F: FnMut(&T) -> bool> Iterator for IntoSplit<T, A, F> {
+    type Item = ReversibleList<T, A>;
+
+    fn next(&mut self) -> Option<ReversibleList<T, A>> {
+        if self.finished {
+            return None;
+        }
+
+        let mut len = 0;
+        let mut current = self.list.start;
+        while let Some(node) = current {
+            // SAFETY: All nodes reachable from the start are owned by the list.
+            let node_ref = unsafe { &*node.as_ptr() };
+            if (self.pred)(&node_ref.data) {
+                let run = match node_ref.prev {
+                    // SAFETY: The run consists of the `len` nodes before the separator.
+                    Some(last) => unsafe {
+                        self.list
+                            .detach_range(self.list.start.unwrap_unchecked(), last, len)
+                    },
+                    None => self.list.empty_like(),
+                };
+                self.list.pop_front();
+                return Some(run);
+            }
+            len += 1;
+            current = node_ref.next;
+        }
+
+        self.finished = true;
+        let empty = self.list.empty_like();
+        Some(mem::replace(&mut self.list, empty))
+    }
+}
+
+impl<T, A: Allocator + Clone, F: FnMut(&T) -> bool> FusedIterator for IntoSplit<T, A, F> {}
+
+/// Iterator through the maximal runs of consecutive elements belonging together, created by
+/// [`ReversibleList::chunk_by`]. Each run is yielded as an [`Iter`] through it.
+pub struct ChunkBy<'list, T: 'list, F> {
+    next_start: MaybePointer<T>,
+    pred: F,
+    _bound_to_list: PhantomData<&'list ()>,
+}
+
+// SAFETY: Only shared references to the elements are handed out, like with `Iter`.
+unsafe impl<T: Sync, F: Send> Send for ChunkBy<'_, T, F> {}
+unsafe impl<T: Sync, F: Sync> Sync for ChunkBy<'_, T, F> {}
+
+impl<'list, T: 'list, F: FnMut(&T, &T) -> bool> ChunkBy<'list, T, F> {
+    /// # Safety
+    ///
+    /// The caller must ensure that `start` is the start of a valid linked list, which is
+    /// borrowed for `'list`.
+    pub(crate) unsafe fn new(start: MaybePointer<T>, pred: F) -> Self {
+        Self {
+            next_start: start,
+            pred,
+            _bound_to_list: PhantomData,
+        }
+    }
+}
+
+impl<'list, T: 'list, F: FnMut(&T, &T) -> bool> Iterator for ChunkBy<'list, T, F> {
+    type Item = Iter<'list, T>;
+
+    fn next(&mut self) -> Option<Iter<'list, T>> {
+        let first = self.next_start?;
+        // SAFETY: The list is borrowed for `'list`, so all its nodes stay valid.
+        let (last, len) = unsafe { run_from(first, &mut self.pred) };
+        // SAFETY: Same as above.
+        self.next_start = unsafe { (*last.as_ptr()).next };
+        // SAFETY: `first` up to `last` are `len` nodes of the borrowed list.
+        Some(unsafe { Iter::new(Some(first), Some(last), len) })
+    }
+}
+
+impl<'list, T: 'list, F: FnMut(&T, &T) -> bool> FusedIterator for ChunkBy<'list, T, F> {}
+
+/// Iterator moving the maximal runs of consecutive elements belonging together out of a list,
+/// created by [`ReversibleList::into_chunk_by`].
+pub struct IntoChunkBy<T, A: Allocator, F> {
+    list: ReversibleList<T, A>,
+    pred: F,
+}
+
+impl<T, A: Allocator, F> IntoChunkBy<T, A, F> {
+    pub(crate) fn new(list: ReversibleList<T, A>, pred: F) -> Self {
+        Self { list, pred }
+    }
+}
+
+impl<T, A: Allocator + Clone, F: FnMut(&T, &T) -> bool> Iterator for IntoChunkBy<T, A, F> {
+    type Item = ReversibleList<T, A>;
+
+    fn next(&mut self) -> Option<ReversibleList<T, A>> {
+        let first = self.list.start?;
+        // SAFETY: All nodes reachable from the start are owned by the list.
+        let (last, len) = unsafe { run_from(first, &mut self.pred) };
+        if len == self.list.len {
+            let empty = self.list.empty_like();
+            return Some(mem::replace(&mut self.list, empty));
+        }
+        // SAFETY: The run consists of the `len` nodes from the start up to `last`.
+        Some(unsafe { self.list.detach_range(first, last, len) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(self.list.len > 0), Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator + Clone, F: FnMut(&T, &T) -> bool> FusedIterator for IntoChunkBy<T, A, F> {}
+
+/// Walks from `first` as long as `pred` returns `true` for each pair of neighbors, returning
+/// the last node of that run and how many nodes it has.
+///
+/// # Safety
+///
+/// `first` must be a valid pointer to a node of a valid linked list, which stays valid until
+/// this returns.
+unsafe fn run_from<T>(
+    first: Pointer<T>,
+    pred: &mut impl FnMut(&T, &T) -> bool,
+) -> (Pointer<T>, usize) {
+    let mut last = first;
+    let mut len = 1;
+    // SAFETY: Delegated to the caller.
+    unsafe {
+        while let Some(next) = (*last.as_ptr()).next {
+            if !pred(&(*last.as_ptr()).data, &(*next.as_ptr()).data) {
+                break;
+            }
+            last = next;
+            len += 1;
+        }
     }
+    (last, len)
 }
+
+/// Iterator looping through a list over and over, created by [`ReversibleList::cycle`] and
+/// [`ReversibleList::cycle_n`].
+pub struct Cycle<'list, T: 'list> {
+    start: MaybePointer<T>,
+    next: MaybePointer<T>,
+    len: usize,
+    /// Index of `next`, to tell how much of the current pass is left.
+    index: usize,
+    /// How many full passes follow the current one, or `None` if it goes on forever.
+    passes_left: Option<usize>,
+    _bound_to_list: PhantomData<&'list ()>,
+}
+
+// SAFETY: Only shared references to the elements are handed out, like with `Iter`.
+unsafe impl<T: Sync> Send for Cycle<'_, T> {}
+unsafe impl<T: Sync> Sync for Cycle<'_, T> {}
+
+impl<'list, T: 'list> Cycle<'list, T> {
+    /// # Safety
+    ///
+    /// The caller must ensure that `start` is the start of a valid linked list with `len`
+    /// nodes, which is borrowed for `'list`.
+    pub(crate) unsafe fn new(start: MaybePointer<T>, len: usize, passes: Option<usize>) -> Self {
+        Self {
+            start,
+            next: start.filter(|_| passes != Some(0)),
+            len,
+            index: 0,
+            passes_left: passes.map(|passes| passes.saturating_sub(1)),
+            _bound_to_list: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for Cycle<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            start: self.start,
+            next: self.next,
+            len: self.len,
+            index: self.index,
+            passes_left: self.passes_left,
+            _bound_to_list: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Cycle<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cycle")
+            .field("len", &self.len)
+            .field("index", &self.index)
+            .field("passes_left", &self.passes_left)
+            .finish()
+    }
+}
+
+impl<'list, T: 'list> Iterator for Cycle<'list, T> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        let node = self.next?;
+        // SAFETY: The list is borrowed for `'list`, so all its nodes stay valid.
+        let node_ref = unsafe { &*node.as_ptr() };
+        self.next = node_ref.next;
+        self.index += 1;
+        if self.next.is_none() {
+            // wrap around to the start, unless this was the last pass
+            self.index = 0;
+            self.next = match &mut self.passes_left {
+                Some(0) => None,
+                Some(passes) => {
+                    *passes -= 1;
+                    self.start
+                }
+                None => self.start,
+            };
+        }
+        Some(&node_ref.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_none() {
+            return (0, Some(0));
+        }
+        let remaining = self.passes_left.and_then(|passes| {
+            passes
+                .checked_mul(self.len)?
+                .checked_add(self.len - self.index)
+        });
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+impl<'list, T: 'list> FusedIterator for Cycle<'list, T> {}
+
+/// Iterator through consecutive groups of `size` elements, created by
+/// [`ReversibleList::chunks`]. Each group is yielded as an [`Iter`] through it, the last one
+/// may be shorter.
+pub struct Chunks<'list, T: 'list> {
+    rest: Iter<'list, T>,
+    size: usize,
+}
+
+impl<'list, T: 'list> Chunks<'list, T> {
+    pub(crate) fn new(iter: Iter<'list, T>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self { rest: iter, size }
+    }
+}
+
+impl<T> Clone for Chunks<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Chunks<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chunks")
+            .field("size", &self.size)
+            .field("rest", &self.rest)
+            .finish()
+    }
+}
+
+impl<'list, T: 'list> Iterator for Chunks<'list, T> {
+    type Item = Iter<'list, T>;
+
+    fn next(&mut self) -> Option<Iter<'list, T>> {
+        let len = self.size.min(self.rest.links.remaining);
+        if len == 0 {
+            return None;
+        }
+
+        let first = self.rest.links.forward_node;
+        let mut last = None;
+        for _ in 0..len {
+            last = self.rest.links.next_in_dir(Direction::Forward);
+        }
+        // SAFETY: `first` up to `last` are the `len` nodes just skipped in the borrowed list.
+        Some(unsafe { Iter::new(first, last, len) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.rest.links.remaining.div_ceil(self.size);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<'list, T: 'list> ExactSizeIterator for Chunks<'list, T> {}
+
+impl<'list, T: 'list> FusedIterator for Chunks<'list, T> {}
+
+/// Iterator through all overlapping groups of `size` consecutive elements, created by
+/// [`ReversibleList::windows`]. Each group is yielded as an [`Iter`] through it.
+pub struct Windows<'list, T: 'list> {
+    first: MaybePointer<T>,
+    /// Last node of the next window, found on the first call to `next`, and afterwards moved
+    /// along with `first`, so each step only takes _O_(1).
+    last: MaybePointer<T>,
+    /// Number of nodes from `first` up to the end of the list.
+    remaining: usize,
+    size: usize,
+    _bound_to_list: PhantomData<&'list ()>,
+}
+
+// SAFETY: Only shared references to the elements are handed out, like with `Iter`.
+unsafe impl<T: Sync> Send for Windows<'_, T> {}
+unsafe impl<T: Sync> Sync for Windows<'_, T> {}
+
+impl<'list, T: 'list> Windows<'list, T> {
+    /// # Safety
+    ///
+    /// The caller must ensure that `start` is the start of a valid linked list with `len`
+    /// nodes, which is borrowed for `'list`.
+    pub(crate) unsafe fn new(start: MaybePointer<T>, len: usize, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Self {
+            first: start,
+            last: None,
+            remaining: len,
+            size,
+            _bound_to_list: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for Windows<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            first: self.first,
+            last: self.last,
+            remaining: self.remaining,
+            size: self.size,
+            _bound_to_list: PhantomData,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Windows<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Windows")
+            .field("size", &self.size)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<'list, T: 'list> Iterator for Windows<'list, T> {
+    type Item = Iter<'list, T>;
+
+    fn next(&mut self) -> Option<Iter<'list, T>> {
+        if self.remaining < self.size {
+            return None;
+        }
+
+        // SAFETY: All nodes walked over are part of the borrowed list, since there are at
+        //         least `size` nodes remaining from `first` on.
+        unsafe {
+            let first = self.first?;
+            let last = match self.last {
+                Some(last) => last,
+                None => {
+                    let mut last = first;
+                    for _ in 1..self.size {
+                        last = (*last.as_ptr()).next?;
+                    }
+                    last
+                }
+            };
+
+            self.first = (*first.as_ptr()).next;
+            self.last = (*last.as_ptr()).next;
+            self.remaining -= 1;
+            Some(Iter::new(Some(first), Some(last), self.size))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let windows = (self.remaining + 1).saturating_sub(self.size);
+        (windows, Some(windows))
+    }
+}
+
+impl<'list, T: 'list> ExactSizeIterator for Windows<'list, T> {}
+
+impl<'list, T: 'list> FusedIterator for Windows<'list, T> {}
+
+/// Iterator yielding a [`Cursor`] at every element, created by [`ReversibleList::cursors`].
+pub struct Cursors<'list, T: 'list, A: Allocator = Global> {
+    links: Links<T>,
+    /// Index of the node `links.forward_node` points at.
+    front_index: usize,
+    list: &'list ReversibleList<T, A>,
+}
+
+// SAFETY: Only hands out `Cursor`s, which are `Send` and `Sync` under the same bounds.
+unsafe impl<T: Sync, A: Allocator + Sync> Send for Cursors<'_, T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Cursors<'_, T, A> {}
+
+impl<'list, T: 'list, A: Allocator> Cursors<'list, T, A> {
+    pub(crate) fn new(list: &'list ReversibleList<T, A>) -> Self {
+        Self {
+            // SAFETY: Those are the start and end of the borrowed list.
+            links: unsafe { Links::new(list.start, list.end, list.len) },
+            front_index: 0,
+            list,
+        }
+    }
+
+    fn cursor(&self, node: Pointer<T>, index: usize) -> Cursor<'list, T, A> {
+        // SAFETY: `node` is part of the borrowed list at `index`, since the running pointers
+        //         are only moved in lockstep with `front_index` and `remaining`.
+        unsafe { Cursor::new_at(self.list, node, index) }
+    }
+}
+
+impl<T, A: Allocator> Clone for Cursors<'_, T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            links: self.links,
+            front_index: self.front_index,
+            list: self.list,
+        }
+    }
+}
+
+impl<T, A: Allocator> fmt::Debug for Cursors<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cursors")
+            .field("front_index", &self.front_index)
+            .field("remaining", &self.links.remaining)
+            .finish()
+    }
+}
+
+impl<'list, T: 'list, A: Allocator> Iterator for Cursors<'list, T, A> {
+    type Item = Cursor<'list, T, A>;
+
+    fn next(&mut self) -> Option<Cursor<'list, T, A>> {
+        let node = self.links.next_in_dir(Direction::Forward)?;
+        self.front_index += 1;
+        Some(self.cursor(node, self.front_index - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.links.size_hint()
+    }
+}
+
+impl<'list, T: 'list, A: Allocator> DoubleEndedIterator for Cursors<'list, T, A> {
+    fn next_back(&mut self) -> Option<Cursor<'list, T, A>> {
+        let node = self.links.next_in_dir(Direction::Backward)?;
+        Some(self.cursor(node, self.front_index + self.links.remaining))
+    }
+}
+
+impl<'list, T: 'list, A: Allocator> ExactSizeIterator for Cursors<'list, T, A> {}
+
+impl<'list, T: 'list, A: Allocator> FusedIterator for Cursors<'list, T, A> {}
@@ -0,0 +1,297 @@
+//! A list which records its structural edits, so they can be undone and redone.
+//!
+//! A [`JournaledList`] keeps two stacks of edits, like the undo history of an editor. Nodes which
+//! are removed aren't dropped, but kept around detached in the history, so undoing the removal
+//! just links the very same nodes back in, without cloning or even moving any element. Likewise,
+//! undoing an insertion detaches the inserted nodes again, ready to be redone.
+//!
+//! Every edit is relinked at the node it was originally next to. Undoing and redoing always
+//! restores the exact structure the list had at that point in the history, so that node is
+//! guaranteed to be there again.
+//!
+//! Only the structure is journaled: changes to elements through [`JournaledList::get_mut`] or
+//! [`JournaledList::iter_mut`] are not recorded.
+
+use std::{
+    fmt,
+    ops::{Bound, Deref, RangeBounds},
+};
+
+use crate::{handle, iter, MaybePointer, Pointer, ReversibleList};
+
+enum Edit<T> {
+    /// The range from `first` to `last` is linked into the list, right after `after`.
+    Linked {
+        first: Pointer<T>,
+        last: Pointer<T>,
+        len: usize,
+        after: MaybePointer<T>,
+    },
+    /// `nodes` have been unlinked from right after `after`.
+    Unlinked {
+        nodes: ReversibleList<T>,
+        after: MaybePointer<T>,
+    },
+    /// The whole list has been reversed.
+    Reversed,
+}
+
+/// A [`ReversibleList`] with undo and redo for structural edits.
+///
+/// See the module docs for details.
+pub struct JournaledList<T> {
+    list: ReversibleList<T>,
+    undo: Vec<Edit<T>>,
+    redo: Vec<Edit<T>>,
+}
+
+// SAFETY: The history only holds pointers to nodes owned by the list or the history itself, so
+//         it's just as safe to send or share as the list.
+unsafe impl<T: Send> Send for JournaledList<T> {}
+unsafe impl<T: Sync> Sync for JournaledList<T> {}
+
+impl<T> JournaledList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from(ReversibleList::new())
+    }
+
+    /// Returns if there's an edit to undo.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns if there's an undone edit to redo.
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Reverts the last edit, in _O_(1) except for reversals. Returns `false` if there is none.
+    pub fn undo(&mut self) -> bool {
+        let Some(edit) = self.undo.pop() else {
+            return false;
+        };
+        let inverse = self.revert(edit);
+        self.redo.push(inverse);
+        true
+    }
+
+    /// Applies the last undone edit again, in _O_(1) except for reversals. Returns `false` if
+    /// there is none.
+    pub fn redo(&mut self) -> bool {
+        let Some(edit) = self.redo.pop() else {
+            return false;
+        };
+        let inverse = self.revert(edit);
+        self.undo.push(inverse);
+        true
+    }
+
+    /// Forgets all edits, dropping the elements which are only kept around for undoing.
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Returns the list, dropping the history.
+    pub fn into_inner(self) -> ReversibleList<T> {
+        self.list
+    }
+
+    /// Returns the element at the given index mutably. This isn't journaled.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.list.get_mut(idx)
+    }
+
+    /// Returns an iterator through this list yielding mutable references. Changes made through
+    /// it aren't journaled.
+    pub fn iter_mut(&mut self) -> iter::IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+
+    /// Inserts the given item before the first element of the list.
+    pub fn push_front(&mut self, item: T) -> handle::NodeHandle<T> {
+        self.insert(0, item)
+    }
+
+    /// Appends the given item to the end of the list.
+    pub fn push_back(&mut self, item: T) -> handle::NodeHandle<T> {
+        self.insert(self.list.len(), item)
+    }
+
+    /// Inserts the given item at the given index, see [`ReversibleList::insert`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    pub fn insert(&mut self, idx: usize, item: T) -> handle::NodeHandle<T> {
+        let handle = self.list.insert(idx, item);
+        // SAFETY: The node was just linked into the list.
+        let after = unsafe { handle.node.as_ref().prev };
+        self.record(Edit::Linked {
+            first: handle.node,
+            last: handle.node,
+            len: 1,
+            after,
+        });
+        handle
+    }
+
+    /// Moves all elements of `other` into this list at the given index, leaving `other` empty.
+    /// Nodes are only relinked, not reallocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    pub fn splice(&mut self, idx: usize, other: &mut ReversibleList<T>) {
+        assert!(
+            idx <= self.list.len(),
+            "tried to insert at index {idx} but the len is {}",
+            self.list.len()
+        );
+        let (Some(first), Some(last)) = (other.start, other.end) else {
+            return;
+        };
+        let len = other.len();
+        let after = idx.checked_sub(1).map(|idx| self.list.node_at(idx));
+        // SAFETY: `after` is owned by this list.
+        unsafe { self.list.splice_after(after, other) };
+        self.record(Edit::Linked {
+            first,
+            last,
+            len,
+            after,
+        });
+    }
+
+    /// Removes the element at the given index. It's only dropped once the removal can't be
+    /// undone anymore.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn remove(&mut self, idx: usize) {
+        self.remove_range(idx..=idx);
+    }
+
+    /// Removes all elements in the given range. They're only dropped once the removal can't be
+    /// undone anymore.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.list.len(),
+        };
+        assert!(
+            start <= end && end <= self.list.len(),
+            "tried to remove {start}..{end} but the len is {}",
+            self.list.len()
+        );
+        if start == end {
+            return;
+        }
+
+        let first = self.list.node_at(start);
+        let last = self.list.node_at(end - 1);
+        // SAFETY: Both are owned by this list, and `last` comes `end - start - 1` nodes after
+        //         `first`.
+        let (after, nodes) = unsafe {
+            let after = first.as_ref().prev;
+            (after, self.list.detach_range(first, last, end - start))
+        };
+        self.record(Edit::Unlinked { nodes, after });
+    }
+
+    /// Reverses the order of all elements, in _O_(_n_).
+    pub fn reverse(&mut self) {
+        self.list.reverse_links();
+        self.record(Edit::Reversed);
+    }
+
+    /// Records a new edit, which makes all undone ones impossible to redo.
+    fn record(&mut self, edit: Edit<T>) {
+        self.undo.push(edit);
+        self.redo.clear();
+    }
+
+    /// Reverts the given edit and returns the edit which reverts that again.
+    fn revert(&mut self, edit: Edit<T>) -> Edit<T> {
+        match edit {
+            Edit::Linked {
+                first,
+                last,
+                len,
+                after,
+            } => {
+                // SAFETY: The list has exactly the structure it had after linking the range.
+                let nodes = unsafe { self.list.detach_range(first, last, len) };
+                Edit::Unlinked { nodes, after }
+            }
+            Edit::Unlinked { mut nodes, after } => {
+                let (first, last) = (nodes.start.unwrap(), nodes.end.unwrap());
+                let len = nodes.len();
+                // SAFETY: The list has exactly the structure it had before unlinking the
+                //         range, so `after` is part of it again.
+                unsafe { self.list.splice_after(after, &mut nodes) };
+                Edit::Linked {
+                    first,
+                    last,
+                    len,
+                    after,
+                }
+            }
+            Edit::Reversed => {
+                self.list.reverse_links();
+                Edit::Reversed
+            }
+        }
+    }
+}
+
+impl<T> Deref for JournaledList<T> {
+    type Target = ReversibleList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for JournaledList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+impl<T> Default for JournaledList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<ReversibleList<T>> for JournaledList<T> {
+    /// Starts journaling with an empty history.
+    fn from(value: ReversibleList<T>) -> Self {
+        Self {
+            list: value,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for JournaledList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<ReversibleList<T>>())
+    }
+}
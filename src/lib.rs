@@ -1,24 +1,176 @@
-#[cfg(test)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+#![cfg_attr(feature = "dropck_eyepatch", feature(dropck_eyepatch))]
+#![cfg_attr(feature = "unsize", feature(unsize))]
+
+#[cfg(all(test, loom))]
+mod loom_tests;
+#[cfg(all(test, not(loom)))]
 mod tests;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "capi")]
+pub mod capi;
+mod chunk;
+pub mod circular;
+pub mod cow;
 pub mod cursor;
+pub mod deque;
+mod dot;
+mod finger;
+pub mod fixed;
+pub mod handle;
+pub mod indexed;
+pub mod intrusive;
 pub mod iter;
+pub mod journal;
+pub mod mpsc;
+pub mod observed;
+pub mod ordered;
+pub mod persistent;
+mod pool;
+mod primitives;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+pub mod reversed;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+pub mod scratch;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "slab")]
+pub mod slab;
+pub mod slots;
+pub mod small;
+pub mod sorted;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(feature = "futures-core")]
+pub mod stream;
+pub mod sync;
+pub mod text;
+pub mod unrolled;
+pub mod xor;
 
 use std::{
-    cmp, fmt,
+    array, cmp,
+    collections::{LinkedList, VecDeque},
+    fmt,
     hash::{Hash, Hasher},
-    ptr::NonNull,
+    marker::PhantomData,
+    mem,
+    ptr::{self, NonNull},
 };
 
+use allocator_api2::alloc::{Allocator, Global};
+
+/// Creates a [`ReversibleList`] containing the given elements, just like `vec!`.
+///
+/// `reversible_list![x; n]` clones `x` for all but the last of the `n` elements.
+#[macro_export]
+macro_rules! reversible_list {
+    () => {
+        $crate::ReversibleList::new()
+    };
+    ($item:expr; $n:expr) => {
+        ::std::iter::repeat_n($item, $n).collect::<$crate::ReversibleList<_>>()
+    };
+    ($($item:expr),+ $(,)?) => {
+        $crate::ReversibleList::from([$($item),+])
+    };
+}
+
 type Pointer<T> = NonNull<Node<T>>;
 type MaybePointer<T> = Option<Pointer<T>>;
 
-pub struct ReversibleList<T> {
+/// A doubly linked list.
+///
+/// All nodes are allocated using `A`, which is the global allocator by default. On stable, `A`
+/// implements the `Allocator` polyfill of the `allocator-api2` crate, with the `allocator_api`
+/// feature it's the unstable `Allocator` trait of `std` instead.
+///
+/// Comparing, ordering and hashing lists only ever looks at the elements in iteration order.
+/// Where the nodes live, how they got linked and whether they were moved around with cursors
+/// doesn't matter, so equal lists always hash equally. Any future way of reversing parts of the
+/// list will have to keep it that way.
+pub struct ReversibleList<T, A: Allocator = Global> {
     start: MaybePointer<T>,
     end: MaybePointer<T>,
     len: usize,
+    alloc: A,
+    pool: pool::NodePool<T>,
+    #[cfg(feature = "checked-handles")]
+    registry: handle::Registry,
+    /// The node last looked up by index, see [`Self::get`].
+    finger: finger::Finger<T>,
+    /// Bumped on every structural change, see [`Self::version`].
+    version: u64,
+    /// Tells the drop checker that dropping the list drops values of type `T`.
+    _owns: PhantomData<T>,
+}
+
+// SAFETY: The list owns all its nodes exclusively, just like a `Vec` owns its elements. Hence
+//         sending or sharing it is fine as long as doing so with the elements and the
+//         allocator is fine.
+unsafe impl<T: Send, A: Allocator + Send> Send for ReversibleList<T, A> {}
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for ReversibleList<T, A> {}
+
+/// Memory taken up by a list, as reported by [`ReversibleList::memory_usage`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MemoryUsage {
+    /// Number of nodes currently holding an element, equal to the length of the list.
+    pub live_nodes: usize,
+    /// Number of allocated nodes kept around for reuse.
+    pub pooled_nodes: usize,
+    /// Bytes allocated for live and pooled nodes together.
+    pub heap_bytes: usize,
+}
+
+/// Error returned by the `try_` insertion methods, like [`ReversibleList::try_push_back`], if
+/// there's no memory left for another node, either since the allocator failed or since a
+/// [`StaticReversibleList`](fixed::StaticReversibleList) is full. Hands back the item which
+/// couldn't be inserted.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TryInsertError<T>(pub T);
+
+impl<T> TryInsertError<T> {
+    /// Returns the item which couldn't be inserted.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Debug for TryInsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TryInsertError(..)")
+    }
 }
 
+impl<T> fmt::Display for TryInsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no memory left for another list node")
+    }
+}
+
+impl<T> std::error::Error for TryInsertError<T> {}
+
+/// One allocation of the list.
+///
+/// # Aliasing model
+///
+/// All node pointers are derived from the pointer returned by the allocator, never from a
+/// reference, and never roundtrip through integers (only `handle::Registry` looks at their
+/// addresses, without ever turning them back into pointers). This keeps the list clean under
+/// Miri with `-Zmiri-strict-provenance`, with both Stacked and Tree Borrows.
+///
+/// References into a node are only created for as long as the corresponding borrow of the list
+/// lives, so `&ReversibleList` hands out `&Node`/`&T`, and `&mut ReversibleList` hands out
+/// `&mut T`. Relinking writes to `prev`/`next` through the raw pointer directly, without
+/// creating a reference to the whole node, so it can't invalidate references to `data` that
+/// other code (say, a cursor about to move on) might derive from the same pointer. Keep it
+/// that way when adding new cursor operations.
 struct Node<T> {
     data: T,
     prev: MaybePointer<T>,
@@ -28,18 +180,343 @@ struct Node<T> {
 impl<T> ReversibleList<T> {
     #[must_use]
     pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates an empty list with at least `capacity` nodes pre-allocated, so that the first
+    /// `capacity` insertions don't need to allocate. See [`Self::reserve`] for details.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+
+    /// Takes the list apart into pointers to its first and last node and its length, without
+    /// dropping any element. See [`Self::into_raw_parts_with_alloc`] for details.
+    #[must_use = "losing the pointers leaks the list"]
+    pub fn into_raw_parts(self) -> (*mut (), *mut (), usize) {
+        let (start, end, len, Global) = self.into_raw_parts_with_alloc();
+        (start, end, len)
+    }
+
+    /// Reassembles a list taken apart by [`Self::into_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::from_raw_parts_in`].
+    pub unsafe fn from_raw_parts(start: *mut (), end: *mut (), len: usize) -> Self {
+        // SAFETY: Delegated to the caller.
+        unsafe { Self::from_raw_parts_in(start, end, len, Global) }
+    }
+
+    /// Moves all elements of `other` to the end of this list in _O_(1), leaving `other` empty.
+    ///
+    /// Nodes are only relinked, not reallocated, and their handles now belong to this list.
+    /// Like [`Self::interleave`], this is only available for the global allocator.
+    pub fn append(&mut self, other: &mut Self) {
+        // SAFETY: `self.end` is either `None` or the last node of this list.
+        unsafe { self.splice_after(self.end, other) };
+    }
+
+    /// Moves all nodes of `other` right after `anchor`, or to the front if `anchor` is `None`,
+    /// leaving `other` empty. Relinking takes _O_(1), only moving the handles along takes
+    /// longer.
+    ///
+    /// # Safety
+    ///
+    /// If `anchor` is `Some`, it must be a valid, well-aligned pointer to a list element owned
+    /// by this list.
+    unsafe fn splice_after(&mut self, anchor: MaybePointer<T>, other: &mut Self) {
+        let (Some(first), Some(last)) = (other.start, other.end) else {
+            return;
+        };
+
+        // SAFETY: `first` and `last` are the ends of `other`, `anchor` is delegated to the
+        //         caller, and both lists use the global allocator.
+        unsafe {
+            let after = match anchor {
+                Some(anchor) => anchor.as_ref().next,
+                None => self.start,
+            };
+            (*first.as_ptr()).prev = anchor;
+            (*last.as_ptr()).next = after;
+            match anchor {
+                Some(anchor) => (*anchor.as_ptr()).next = Some(first),
+                None => self.start = Some(first),
+            }
+            match after {
+                Some(after) => (*after.as_ptr()).prev = Some(last),
+                None => self.end = Some(last),
+            }
+        }
+        self.len += other.len;
+
+        // the nodes belong to this list now, so their handles need to follow them
+        #[cfg(feature = "checked-handles")]
+        {
+            let mut current = Some(first);
+            while let Some(node) = current {
+                let generation = other
+                    .registry
+                    .unregister(node)
+                    .expect("node should be registered in its own list");
+                self.registry.adopt(node, generation);
+                if node == last {
+                    break;
+                }
+                // SAFETY: The node is still valid, just owned by this list now.
+                current = unsafe { node.as_ref().next };
+            }
+        }
+
+        other.start = None;
+        other.end = None;
+        other.len = 0;
+        self.structure_changed();
+        other.structure_changed();
+    }
+
+    /// Concatenates all given lists into one, preserving their order, by relinking their nodes
+    /// in _O_(number of lists).
+    pub fn concat<I: IntoIterator<Item = Self>>(lists: I) -> Self {
+        let mut flat = Self::new();
+        for mut list in lists {
+            flat.append(&mut list);
+        }
+        flat
+    }
+
+    /// Moves all elements of `other` into this list, alternating between this list's and
+    /// `other`'s elements, starting with this list. Once one of the lists runs out, the rest of
+    /// the other one is appended. Afterwards, `other` is empty.
+    ///
+    /// Nodes are only relinked, not reallocated, and their handles now belong to this list.
+    /// This is only available for the global allocator, since relinking nodes between two
+    /// lists requires that either list can deallocate the other's nodes.
+    pub fn interleave(&mut self, other: &mut Self) {
+        let mut current = self.start;
+        while let (Some(node), Some(other_node)) = (current, other.start) {
+            // SAFETY: `node` is owned by this list and `other_node` by `other`, and both use
+            //         the global allocator.
+            unsafe {
+                current = node.as_ref().next;
+                other.move_node(other_node, self, Some(node), Direction::After);
+            }
+        }
+
+        while let Some(other_node) = other.start {
+            // SAFETY: Same as above.
+            unsafe { other.move_node_to_back(other_node, self) };
+        }
+    }
+}
+
+impl<T> ReversibleList<ReversibleList<T>> {
+    /// Flattens this list of lists into one list, preserving the order. See
+    /// [`ReversibleList::concat`] for details.
+    pub fn flatten(self) -> ReversibleList<T> {
+        ReversibleList::concat(self)
+    }
+}
+
+/// Trait objects and other unsized values are stored boxed, as in `ReversibleList<Box<dyn
+/// Trait>>`. A concrete `Box` is unsized right where it's passed in, so
+/// `list.push_back(Box::new(value))` just works. Only when collecting boxes of different
+/// types, the first one needs a cast like `Box::new(value) as Box<dyn Trait>`.
+impl<T: ?Sized, A: Allocator> ReversibleList<Box<T>, A> {
+    /// Returns an iterator through the boxed values, yielding `&T` instead of `&Box<T>`.
+    pub fn iter_unboxed(&self) -> iter::Unboxed<'_, T> {
+        self.iter().map(AsRef::as_ref)
+    }
+
+    /// Returns an iterator through the boxed values, yielding `&mut T` instead of
+    /// `&mut Box<T>`.
+    pub fn iter_mut_unboxed(&mut self) -> iter::UnboxedMut<'_, T> {
+        self.iter_mut().map(AsMut::as_mut)
+    }
+
+    /// Boxes the given value and inserts it before the first element, unsizing it to `T` on
+    /// the way.
+    #[cfg(feature = "unsize")]
+    pub fn push_front_unsized<U: std::marker::Unsize<T>>(
+        &mut self,
+        item: U,
+    ) -> handle::NodeHandle<Box<T>> {
+        let item: Box<U> = Box::new(item);
+        self.push_front(item)
+    }
+
+    /// Boxes the given value and appends it after the last element, unsizing it to `T` on the
+    /// way.
+    #[cfg(feature = "unsize")]
+    pub fn push_back_unsized<U: std::marker::Unsize<T>>(
+        &mut self,
+        item: U,
+    ) -> handle::NodeHandle<Box<T>> {
+        let item: Box<U> = Box::new(item);
+        self.push_back(item)
+    }
+}
+
+impl<T, A: Allocator> ReversibleList<T, A> {
+    /// Creates an empty list which allocates its nodes using the given allocator.
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
         Self {
             start: None,
             end: None,
             len: 0,
+            alloc,
+            pool: pool::NodePool::new(pool::NodePool::<T>::DEFAULT_LIMIT),
+            #[cfg(feature = "checked-handles")]
+            registry: handle::Registry::default(),
+            finger: finger::Finger::new(),
+            version: 0,
+            _owns: PhantomData,
         }
     }
 
+    /// Creates an empty list with at least `capacity` nodes pre-allocated using the given
+    /// allocator. See [`Self::reserve`] for details.
+    #[must_use]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        let mut list = Self::new_in(alloc);
+        list.reserve(capacity);
+        list
+    }
+
+    /// Returns a reference to the allocator used by this list.
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Takes the list apart into pointers to its first and last node, its length and its
+    /// allocator, without dropping any element, like [`Vec::into_raw_parts`]. The pointers are
+    /// null for an empty list.
+    ///
+    /// The pointers are opaque and must not be dereferenced. They're only good for stashing
+    /// the list away somewhere a [`ReversibleList`] can't live, like in memory owned by C
+    /// code, and reassembling it later with [`Self::from_raw_parts_in`]. Until then, the nodes
+    /// are leaked.
+    ///
+    /// Pooled nodes are deallocated, and with the `checked-handles` feature, handles into the
+    /// list won't be recognized by the reassembled one.
+    #[must_use = "losing the pointers leaks the list"]
+    pub fn into_raw_parts_with_alloc(self) -> (*mut (), *mut (), usize, A) {
+        let mut list = mem::ManuallyDrop::new(self);
+        list.set_pool_limit(0);
+        let erase =
+            |node: MaybePointer<T>| node.map_or(ptr::null_mut(), |node| node.as_ptr().cast());
+        let (start, end, len) = (erase(list.start), erase(list.end), list.len);
+        // SAFETY: The list is never touched again, so the allocator is moved out exactly once
+        //         and the registry dropped exactly once. All other fields don't need dropping.
+        unsafe {
+            #[cfg(feature = "checked-handles")]
+            ptr::drop_in_place(&mut list.registry);
+            (start, end, len, ptr::read(&list.alloc))
+        }
+    }
+
+    /// Reassembles a list taken apart by [`Self::into_raw_parts_with_alloc`].
+    ///
+    /// # Safety
+    ///
+    /// `start`, `end` and `len` must have been returned together by
+    /// [`Self::into_raw_parts_with_alloc`] or [`ReversibleList::into_raw_parts`] for a list
+    /// of the same `T`, and `alloc` must be able to deallocate the nodes, so it has to be the
+    /// allocator returned along with them or an equivalent one. Each set of parts may only be
+    /// reassembled once.
+    pub unsafe fn from_raw_parts_in(start: *mut (), end: *mut (), len: usize, alloc: A) -> Self {
+        let mut list = Self::new_in(alloc);
+        list.start = NonNull::new(start.cast());
+        list.end = NonNull::new(end.cast());
+        list.len = len;
+
+        #[cfg(feature = "checked-handles")]
+        {
+            let mut current = list.start;
+            while let Some(node) = current {
+                list.registry.register(node);
+                // SAFETY: The nodes belong to this list again, as guaranteed by the caller.
+                current = unsafe { node.as_ref().next };
+            }
+        }
+        list
+    }
+
+    /// Returns how many unused nodes this list keeps around at most for reuse.
+    ///
+    /// Removed nodes are put into a pool instead of being deallocated right away, so subsequent
+    /// insertions can reuse them without asking the allocator again. By default, up to 16 nodes
+    /// are pooled.
+    pub fn pool_limit(&self) -> usize {
+        self.pool.limit()
+    }
+
+    /// Returns how many elements this list can hold without allocating, in effect its length
+    /// plus the number of pooled nodes.
+    pub fn capacity(&self) -> usize {
+        self.len + self.pool.len()
+    }
+
+    /// Reports how much memory this list's nodes take up. Heap data owned by the elements
+    /// themselves isn't included, neither is any rounding up the allocator might do.
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage {
+            live_nodes: self.len,
+            pooled_nodes: self.pool.len(),
+            heap_bytes: self.capacity() * std::mem::size_of::<Node<T>>(),
+        }
+    }
+
+    /// Pre-allocates nodes so that at least `additional` more elements can be inserted without
+    /// allocating. The nodes are put into the pool, whose limit is raised to the resulting
+    /// capacity if necessary, so it's kept even after removing all elements again.
+    pub fn reserve(&mut self, additional: usize) {
+        let pooled = cmp::max(self.pool.len(), additional);
+        self.pool
+            .reserve(additional, self.len + pooled, &self.alloc);
+    }
+
+    /// Deallocates all pooled nodes, so that the capacity is equal to the length afterwards.
+    /// The pool limit stays untouched though, so subsequently removed nodes are pooled again.
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_to(0);
+    }
+
+    /// Deallocates pooled nodes so that the capacity is at least `min_capacity` afterwards,
+    /// but no more than needed for that. If the capacity is less than `min_capacity` already,
+    /// this does nothing. Like [`Self::shrink_to_fit`], the pool limit stays untouched.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        let pooled = min_capacity.saturating_sub(self.len);
+        // SAFETY: The pool only ever receives nodes allocated by `self.alloc`.
+        unsafe { self.pool.shrink_to(pooled, &self.alloc) };
+    }
+
+    /// Changes how many unused nodes this list keeps around at most for reuse, deallocating
+    /// currently pooled nodes exceeding the new limit. A limit of 0 disables pooling entirely.
+    pub fn set_pool_limit(&mut self, limit: usize) {
+        // SAFETY: The pool only ever receives nodes allocated by `self.alloc`.
+        unsafe { self.pool.set_limit(limit, &self.alloc) };
+    }
+
     #[must_use]
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns the structural version of this list, which changes whenever nodes are linked
+    /// into or unlinked from it, or their order changes. Modifying elements in place doesn't
+    /// change it.
+    ///
+    /// Comparing it before and after some code ran tells if that code changed the structure,
+    /// which is handy for debugging unsafe code holding on to nodes, like saved
+    /// [`cursor::Position`]s. The `checked-versions` feature uses it to only verify restored
+    /// positions if the structure changed since they were saved.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
     #[must_use]
     pub fn is_empty(&self) -> bool {
         self.len == 0
@@ -51,60 +528,955 @@ impl<T> ReversibleList<T> {
         //         and `Iter` is bound by it --- will not ever be leaked
         //         pointers are only mutated through `Self::insert_in_dir` and
         //         `Self::pop`, which both preserve a valid linked list
-        unsafe { iter::Iter::new(self.start, self.end) }
+        unsafe { iter::Iter::new(self.start, self.end, self.len) }
+    }
+
+    /// Returns a view presenting this list back to front, without changing it. See the
+    /// [module docs](reversed) for details.
+    pub fn reversed(&self) -> reversed::Reversed<'_, T, A> {
+        reversed::Reversed::new(self)
+    }
+
+    /// Returns an iterator through this list, yielding each element along with its index.
+    /// The indices are the same [`Self::get`] and [`Self::cursor_at`] use, also when
+    /// iterating from the back.
+    pub fn indexed_iter(&self) -> iter::Indexed<'_, T> {
+        self.iter().enumerate()
+    }
+
+    /// Returns an iterator through the runs of elements separated by elements for which
+    /// `pred` returns `true`, just like [`slice::split`]. Each run is yielded as an iterator
+    /// through it.
+    pub fn split<F>(&self, pred: F) -> iter::Split<'_, T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        // SAFETY: Same as `Self::iter`.
+        unsafe { iter::Split::new(self.start, pred) }
+    }
+
+    /// Returns an iterator through the maximal runs of consecutive elements for which `pred`
+    /// returns `true` on each pair of neighbors, just like [`slice::chunk_by`]. Each run is
+    /// yielded as an iterator through it, so nothing is copied.
+    pub fn chunk_by<F>(&self, pred: F) -> iter::ChunkBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        // SAFETY: Same as `Self::iter`.
+        unsafe { iter::ChunkBy::new(self.start, pred) }
+    }
+
+    /// Returns an iterator looping through this list forever, starting over at the front
+    /// after reaching the back, like a round-robin. Yields nothing if the list is empty.
+    pub fn cycle(&self) -> iter::Cycle<'_, T> {
+        // SAFETY: Same as `Self::iter`.
+        unsafe { iter::Cycle::new(self.start, self.len, None) }
+    }
+
+    /// Like [`Self::cycle`], but stops after `passes` full passes through the list.
+    pub fn cycle_n(&self, passes: usize) -> iter::Cycle<'_, T> {
+        // SAFETY: Same as `Self::iter`.
+        unsafe { iter::Cycle::new(self.start, self.len, Some(passes)) }
+    }
+
+    /// Returns an iterator through consecutive groups of `size` elements, the last one possibly
+    /// being shorter. Each group is yielded as an iterator through it, so nothing is copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn chunks(&self, size: usize) -> iter::Chunks<'_, T> {
+        iter::Chunks::new(self.iter(), size)
+    }
+
+    /// Returns an iterator through all overlapping groups of `size` consecutive elements,
+    /// moving one element further each time. Each group is yielded as an iterator through it,
+    /// so nothing is copied. If the list is shorter than `size`, there are no groups at all.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn windows(&self, size: usize) -> iter::Windows<'_, T> {
+        // SAFETY: Same as `Self::iter`.
+        unsafe { iter::Windows::new(self.start, self.len, size) }
+    }
+
+    /// Returns an iterator through this list which allows modifying each element.
+    pub fn iter_mut(&mut self) -> iter::IterMut<'_, T> {
+        // SAFETY: Same as `Self::iter`, but with a mutable borrow.
+        unsafe { iter::IterMut::new(self.start, self.end, self.len) }
+    }
+
+    /// Moves all elements into a `Vec`, freeing each node right after its element has been
+    /// moved out.
+    pub fn into_vec(self) -> Vec<T> {
+        self.into_iter().collect()
+    }
+
+    /// Clones all elements into a `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.len);
+        vec.extend(self.iter().cloned());
+        vec
+    }
+
+    /// Walks the whole list forward and backward, panicking if any internal invariant is
+    /// violated, that is, if `prev`/`next` aren't symmetric, if the walks don't end at the
+    /// start and end of the list, or if the length doesn't match.
+    ///
+    /// This takes _O_(_n_) and is only useful for debugging code that relinks nodes, it can't
+    /// fail using only the safe API.
+    #[track_caller]
+    pub fn assert_invariants(&self) {
+        let mut count = 0;
+        let mut prev = None;
+        let mut current = self.start;
+        while let Some(node) = current {
+            assert!(
+                count < self.len,
+                "more nodes reachable than len {}",
+                self.len
+            );
+            // SAFETY: If the links are intact, all reachable nodes are owned by this list. If
+            //         they're not, the length check above at least prevents running off forever.
+            let node_ref = unsafe { node.as_ref() };
+            assert_eq!(node_ref.prev, prev, "prev of node {count} doesn't match");
+            #[cfg(feature = "checked-handles")]
+            assert!(
+                self.registry.generation_of(node).is_some(),
+                "node {count} isn't registered",
+            );
+
+            prev = current;
+            current = node_ref.next;
+            count += 1;
+        }
+        assert_eq!(count, self.len, "forward walk doesn't match len");
+        assert_eq!(prev, self.end, "forward walk doesn't end at end");
+
+        let mut count = 0;
+        let mut next = None;
+        let mut current = self.end;
+        while let Some(node) = current {
+            assert!(
+                count < self.len,
+                "more nodes reachable than len {}",
+                self.len
+            );
+            // SAFETY: Same as above.
+            let node_ref = unsafe { node.as_ref() };
+            assert_eq!(
+                node_ref.next, next,
+                "next of node {count} from the end doesn't match"
+            );
+
+            next = current;
+            current = node_ref.prev;
+            count += 1;
+        }
+        assert_eq!(count, self.len, "backward walk doesn't match len");
+        assert_eq!(next, self.start, "backward walk doesn't end at start");
+
+        assert!(
+            self.pool.len() <= self.pool.limit(),
+            "pool exceeds its limit"
+        );
+        #[cfg(feature = "checked-handles")]
+        assert_eq!(
+            self.registry.len(),
+            self.len,
+            "registry size doesn't match len"
+        );
     }
 
     /// Creates a cursor pointing at the **first** node in the list.
-    pub fn cursor_front(&self) -> cursor::Cursor<'_, T> {
+    pub fn cursor_front(&self) -> cursor::Cursor<'_, T, A> {
         // SAFETY: Same as `Self::iter`.
         unsafe { cursor::Cursor::new_front(self) }
     }
 
-    /// Creates a cursor pointing at the **last** node in the list.
-    pub fn cursor_back(&self) -> cursor::Cursor<'_, T> {
-        // SAFETY: Same as `Self::iter`.
-        unsafe { cursor::Cursor::new_back(self) }
+    /// Creates a cursor pointing at the **last** node in the list.
+    pub fn cursor_back(&self) -> cursor::Cursor<'_, T, A> {
+        // SAFETY: Same as `Self::iter`.
+        unsafe { cursor::Cursor::new_back(self) }
+    }
+
+    /// Creates a cursor pointing at node with the given index in the list.
+    pub fn cursor_at(&self, idx: usize) -> cursor::Cursor<'_, T, A> {
+        let node = self.node_at(idx);
+        // SAFETY: `node_at` returns the node at exactly that index.
+        unsafe { cursor::Cursor::new_at(self, node, idx) }
+    }
+
+    /// Returns an iterator yielding a cursor at every element, from the front to the back.
+    /// Unlike calling [`Self::cursor_at`] for each index, this walks the list only once.
+    pub fn cursors(&self) -> iter::Cursors<'_, T, A> {
+        iter::Cursors::new(self)
+    }
+
+    /// Creates up to `count` cursors splitting the list into that many contiguous parts,
+    /// which differ in length by at most one element. Each cursor points at the first element
+    /// of its part, so the first one is always at the front.
+    ///
+    /// Useful for handing parts of the list to parallel readers. Takes a single walk through
+    /// the list. If it has fewer than `count` elements, there's one cursor per element, so
+    /// there are none for an empty list.
+    pub fn spaced_cursors(&self, count: usize) -> Vec<cursor::Cursor<'_, T, A>> {
+        let count = count.min(self.len);
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let (step, extra) = (self.len / count, self.len % count);
+        let mut all = self.cursors();
+        (0..count)
+            .map(|part| {
+                let cursor = all.next().expect("parts should add up to the length");
+                let skip = step + usize::from(part < extra) - 1;
+                if skip > 0 {
+                    all.nth(skip - 1);
+                }
+                cursor
+            })
+            .collect()
+    }
+
+    /// Returns the element at the given index, or `None` if it's out of bounds.
+    ///
+    /// This walks from whichever is closest of the start, the end, or the node last looked up
+    /// by index through [`Self::get`], [`Self::get_mut`], [`Self::cursor_at`] or
+    /// [`Self::insert`]. So while a single lookup takes _O_(_n_), looking up indices close to
+    /// each other one after another, like when iterating by index, only takes _O_(1) each.
+    /// Linking or unlinking any node forgets the last looked up one though.
+    #[must_use]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if self.len <= idx {
+            return None;
+        }
+        // SAFETY: The node is owned by this list, which is borrowed for as long as the reference
+        //         lives.
+        Some(unsafe { &(*self.node_at(idx).as_ptr()).data })
+    }
+
+    /// Returns the element at the given index mutably, or `None` if it's out of bounds. Uses
+    /// the same shortcut as [`Self::get`].
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        if self.len <= idx {
+            return None;
+        }
+        // SAFETY: The node is owned by this list, which is borrowed mutably for as long as the
+        //         reference lives.
+        Some(unsafe { &mut (*self.node_at(idx).as_ptr()).data })
+    }
+
+    /// Returns the node at the given index, walking from the closest known node, and remembers
+    /// it for the next lookup.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn node_at(&self, idx: usize) -> Pointer<T> {
+        let (Some(start), Some(end)) = (self.start, self.end) else {
+            panic!("tried to move to index {idx} but the len is {}", self.len);
+        };
+        assert!(
+            idx < self.len,
+            "tried to move to index {idx} but the len is {}",
+            self.len
+        );
+
+        let mut from: (Pointer<T>, usize) = (start, 0);
+        for candidate in [Some((end, self.len - 1)), self.finger.get()]
+            .into_iter()
+            .flatten()
+        {
+            if candidate.1.abs_diff(idx) < from.1.abs_diff(idx) {
+                from = candidate;
+            }
+        }
+
+        let (mut node, mut current) = from;
+        // SAFETY: All nodes between two valid indices are owned by this list, and the finger
+        //         is cleared whenever nodes are linked or unlinked, so it's still accurate.
+        unsafe {
+            while current < idx {
+                node = node.as_ref().next.expect("index is in bounds");
+                current += 1;
+            }
+            while idx < current {
+                node = node.as_ref().prev.expect("index is in bounds");
+                current -= 1;
+            }
+        }
+
+        self.finger.set(node, idx);
+        node
+    }
+
+    /// Searches this list, which must be sorted according to `f`, for an element for which
+    /// `f` returns [`Ordering::Equal`](cmp::Ordering::Equal), just like
+    /// [`slice::binary_search_by`].
+    ///
+    /// On success, returns a cursor pointing at a matching element. Otherwise, returns the index
+    /// at which a matching element could be inserted while keeping the order.
+    ///
+    /// While this needs only _O_(log _n_) comparisons, the cursor still has to walk _O_(_n_)
+    /// nodes in total, since there's no random access. This is still worth it for expensive
+    /// comparisons.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<cursor::Cursor<'_, T, A>, usize>
+    where
+        F: FnMut(&T) -> cmp::Ordering,
+    {
+        let mut cursor = self.cursor_front();
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            // the cursor walks only the distance between the previous and the next midpoint
+            cursor.move_to(mid);
+            let current = cursor.current().expect("mid is in bounds");
+            match f(current) {
+                cmp::Ordering::Less => low = mid + 1,
+                cmp::Ordering::Greater => high = mid,
+                cmp::Ordering::Equal => return Ok(cursor),
+            }
+        }
+        Err(low)
+    }
+
+    /// Returns if the elements are sorted in ascending order, in one pass.
+    #[must_use]
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.iter().is_sorted()
+    }
+
+    /// Returns if `compare` returns `true` for all pairs of neighbouring elements, just like
+    /// [`slice::is_sorted_by`].
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.iter().is_sorted_by(|a, b| compare(a, b))
+    }
+
+    /// Returns if the keys extracted by `f` are sorted in ascending order.
+    pub fn is_sorted_by_key<K: PartialOrd, F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> K,
+    {
+        self.iter().is_sorted_by_key(f)
+    }
+
+    /// Like [`Self::binary_search_by`], but compares elements with `target`.
+    pub fn binary_search(&self, target: &T) -> Result<cursor::Cursor<'_, T, A>, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|item| item.cmp(target))
+    }
+
+    /// Like [`Self::binary_search_by`], but compares the keys extracted by `f` with `key`.
+    pub fn binary_search_by_key<K: Ord, F>(
+        &self,
+        key: &K,
+        mut f: F,
+    ) -> Result<cursor::Cursor<'_, T, A>, usize>
+    where
+        F: FnMut(&T) -> K,
+    {
+        self.binary_search_by(|item| f(item).cmp(key))
+    }
+
+    /// Returns the index of the first element for which `pred` returns `true`, walking from
+    /// the front.
+    pub fn position<F>(&self, pred: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().position(pred)
+    }
+
+    /// Returns the index of the last element for which `pred` returns `true`, walking from the
+    /// back, so elements after the match aren't looked at.
+    pub fn rposition<F>(&self, pred: F) -> Option<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().rposition(pred)
+    }
+
+    pub fn cursor_mut_front(&mut self) -> cursor::CursorMut<'_, T, A> {
+        // SAFETY: Same as `Self::iter`.
+        unsafe { cursor::CursorMut::new_front(self) }
+    }
+
+    pub fn cursor_mut_back(&mut self) -> cursor::CursorMut<'_, T, A> {
+        // SAFETY: Same as `Self::iter`.
+        unsafe { cursor::CursorMut::new_back(self) }
+    }
+
+    /// Creates a cursor pointing at the minimum element, in one pass. If there are several,
+    /// the cursor points at the first one, just like [`Iterator::min`]. Points "nowhere" if the
+    /// list is empty.
+    pub fn cursor_to_min(&self) -> cursor::Cursor<'_, T, A>
+    where
+        T: Ord,
+    {
+        self.cursor_to_extreme(self.extreme_by_key(|item| item, cmp::Ordering::is_lt))
+    }
+
+    /// Creates a cursor pointing at the maximum element, in one pass. If there are several,
+    /// the cursor points at the last one, just like [`Iterator::max`]. Points "nowhere" if the
+    /// list is empty.
+    pub fn cursor_to_max(&self) -> cursor::Cursor<'_, T, A>
+    where
+        T: Ord,
+    {
+        self.cursor_to_extreme(self.extreme_by_key(|item| item, cmp::Ordering::is_ge))
+    }
+
+    /// Like [`Self::cursor_to_min`], but compares the keys extracted by `f`.
+    pub fn cursor_to_min_by_key<K: Ord, F>(&self, f: F) -> cursor::Cursor<'_, T, A>
+    where
+        F: FnMut(&T) -> K,
+    {
+        self.cursor_to_extreme(self.extreme_by_key(f, cmp::Ordering::is_lt))
+    }
+
+    /// Like [`Self::cursor_to_max`], but compares the keys extracted by `f`.
+    pub fn cursor_to_max_by_key<K: Ord, F>(&self, f: F) -> cursor::Cursor<'_, T, A>
+    where
+        F: FnMut(&T) -> K,
+    {
+        self.cursor_to_extreme(self.extreme_by_key(f, cmp::Ordering::is_ge))
+    }
+
+    /// Like [`Self::cursor_to_min`], but creates a mutable cursor.
+    pub fn cursor_mut_to_min(&mut self) -> cursor::CursorMut<'_, T, A>
+    where
+        T: Ord,
+    {
+        let extreme = self.extreme_by_key(|item| item, cmp::Ordering::is_lt);
+        self.cursor_mut_to_extreme(extreme)
+    }
+
+    /// Like [`Self::cursor_to_max`], but creates a mutable cursor.
+    pub fn cursor_mut_to_max(&mut self) -> cursor::CursorMut<'_, T, A>
+    where
+        T: Ord,
+    {
+        let extreme = self.extreme_by_key(|item| item, cmp::Ordering::is_ge);
+        self.cursor_mut_to_extreme(extreme)
+    }
+
+    /// Like [`Self::cursor_to_min_by_key`], but creates a mutable cursor.
+    pub fn cursor_mut_to_min_by_key<K: Ord, F>(&mut self, f: F) -> cursor::CursorMut<'_, T, A>
+    where
+        F: FnMut(&T) -> K,
+    {
+        let extreme = self.extreme_by_key(f, cmp::Ordering::is_lt);
+        self.cursor_mut_to_extreme(extreme)
+    }
+
+    /// Like [`Self::cursor_to_max_by_key`], but creates a mutable cursor.
+    pub fn cursor_mut_to_max_by_key<K: Ord, F>(&mut self, f: F) -> cursor::CursorMut<'_, T, A>
+    where
+        F: FnMut(&T) -> K,
+    {
+        let extreme = self.extreme_by_key(f, cmp::Ordering::is_ge);
+        self.cursor_mut_to_extreme(extreme)
+    }
+
+    /// Walks through the list once and returns the node and index of the element whose key
+    /// compares to the key of the best one so far such that `replaces` returns `true`, starting
+    /// with the first element. Returns `None` if the list is empty.
+    fn extreme_by_key<'list, K: Ord>(
+        &'list self,
+        mut f: impl FnMut(&'list T) -> K,
+        replaces: fn(cmp::Ordering) -> bool,
+    ) -> Option<(Pointer<T>, usize)> {
+        let mut best: Option<(Pointer<T>, usize, K)> = None;
+        let mut current = self.start;
+        let mut index = 0;
+        while let Some(node) = current {
+            // SAFETY: All nodes are owned by this list, which is borrowed for `'list`.
+            let node_ref = unsafe { &*node.as_ptr() };
+            let key = f(&node_ref.data);
+            if best
+                .as_ref()
+                .is_none_or(|(_, _, best_key)| replaces(key.cmp(best_key)))
+            {
+                best = Some((node, index, key));
+            }
+            current = node_ref.next;
+            index += 1;
+        }
+        best.map(|(node, index, _)| (node, index))
+    }
+
+    fn cursor_to_extreme(&self, extreme: Option<(Pointer<T>, usize)>) -> cursor::Cursor<'_, T, A> {
+        match extreme {
+            // SAFETY: The node and index have been found in this list by `extreme_by_key`.
+            Some((node, index)) => unsafe { cursor::Cursor::new_at(self, node, index) },
+            None => self.cursor_front(),
+        }
+    }
+
+    fn cursor_mut_to_extreme(
+        &mut self,
+        extreme: Option<(Pointer<T>, usize)>,
+    ) -> cursor::CursorMut<'_, T, A> {
+        match extreme {
+            // SAFETY: Same as in `cursor_to_extreme`.
+            Some((node, index)) => unsafe { cursor::CursorMut::new_at(self, node, index) },
+            None => self.cursor_mut_front(),
+        }
+    }
+
+    /// Returns mutable references to the data of multiple nodes at once, identified by their
+    /// indices. Walks through the list only once, so this takes _O_(_n_ + _N_ log _N_).
+    ///
+    /// Returns `None` if any index is out of range, or if any index is given more than once,
+    /// since that would create aliasing mutable references.
+    pub fn get_disjoint_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        // visit the requested nodes in list order, but remember where each one belongs to
+        let mut order: [usize; N] = array::from_fn(|slot| slot);
+        order.sort_unstable_by_key(|&slot| indices[slot]);
+
+        let overlapping = order
+            .windows(2)
+            .any(|pair| indices[pair[0]] == indices[pair[1]]);
+        let out_of_range = order.last().is_some_and(|&slot| self.len <= indices[slot]);
+        if overlapping || out_of_range {
+            return None;
+        }
+
+        let mut found: [MaybePointer<T>; N] = [None; N];
+        let mut current = self.start;
+        let mut current_idx = 0;
+        for slot in order {
+            while current_idx < indices[slot] {
+                // SAFETY: All indices are in range, so there's always a next node.
+                current = unsafe { current?.as_ref().next };
+                current_idx += 1;
+            }
+            found[slot] = current;
+        }
+
+        // SAFETY: All pointers are distinct and valid nodes of this list, which is borrowed
+        //         mutably for as long as the references live.
+        Some(found.map(|node| unsafe { &mut (*node.unwrap().as_ptr()).data }))
+    }
+
+    /// Inserts the given item before the first element of the list, should complete in _O_(1).
+    /// Returns a [`handle::NodeHandle`] to the new node.
+    pub fn push_front(&mut self, item: T) -> handle::NodeHandle<T> {
+        // SAFETY: `self.start` is only mutated by `Self::insert_in_dir` or `Self::pop`,
+        // which both preserve the validity of it.
+        let node = unsafe { self.insert_in_dir(self.start, Direction::Before, item) };
+        self.handle_for(node)
+    }
+
+    /// Appends the given item to the end of the list, should complete in _O_(1). Returns a
+    /// [`handle::NodeHandle`] to the new node.
+    pub fn push_back(&mut self, item: T) -> handle::NodeHandle<T> {
+        // SAFETY: `self.end` is only mutated by `Self::insert_in_dir` or `Self::pop`,
+        // which both preserve the validity of it.
+        let node = unsafe { self.insert_in_dir(self.end, Direction::After, item) };
+        self.handle_for(node)
+    }
+
+    /// Like [`Self::push_front`], but returns an error holding `item` instead of aborting if
+    /// the allocator fails.
+    pub fn try_push_front(&mut self, item: T) -> Result<handle::NodeHandle<T>, TryInsertError<T>> {
+        // SAFETY: Same as `Self::push_front`.
+        let node = unsafe { self.try_insert_in_dir(self.start, Direction::Before, item) }?;
+        Ok(self.handle_for(node))
+    }
+
+    /// Like [`Self::push_back`], but returns an error holding `item` instead of aborting if
+    /// the allocator fails.
+    pub fn try_push_back(&mut self, item: T) -> Result<handle::NodeHandle<T>, TryInsertError<T>> {
+        // SAFETY: Same as `Self::push_back`.
+        let node = unsafe { self.try_insert_in_dir(self.end, Direction::After, item) }?;
+        Ok(self.handle_for(node))
+    }
+
+    /// Inserts the given item at the given index, shifting all elements after it one position
+    /// back. Finds the index the same way as [`Self::get`], so inserting at or close to the
+    /// previous index only takes _O_(1). Returns a [`handle::NodeHandle`] to the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    pub fn insert(&mut self, idx: usize, item: T) -> handle::NodeHandle<T> {
+        let (anchor, direction) = self.insertion_point(idx);
+        // SAFETY: The insertion point consists of nodes owned by this list.
+        let node = unsafe { self.insert_in_dir(anchor, direction, item) };
+        self.finger.set(node, idx);
+        self.handle_for(node)
+    }
+
+    /// Like [`Self::insert`], but returns an error holding `item` instead of aborting if the
+    /// allocator fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    pub fn try_insert(
+        &mut self,
+        idx: usize,
+        item: T,
+    ) -> Result<handle::NodeHandle<T>, TryInsertError<T>> {
+        let (anchor, direction) = self.insertion_point(idx);
+        // SAFETY: The insertion point consists of nodes owned by this list.
+        let node = unsafe { self.try_insert_in_dir(anchor, direction, item) }?;
+        self.finger.set(node, idx);
+        Ok(self.handle_for(node))
+    }
+
+    /// Returns the anchor and direction to insert a new node at so it ends up at `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    fn insertion_point(&mut self, idx: usize) -> (MaybePointer<T>, Direction) {
+        assert!(
+            idx <= self.len,
+            "tried to insert at index {idx} but the len is {}",
+            self.len
+        );
+        if idx == self.len {
+            (self.end, Direction::After)
+        } else {
+            (Some(self.node_at(idx)), Direction::Before)
+        }
+    }
+
+    /// Inserts the given item into this list, which must be sorted according to `compare`,
+    /// so that it stays sorted. The item is placed after all elements comparing equal to it,
+    /// so inserting keeps the order of equal elements stable. Returns a
+    /// [`handle::NodeHandle`] to the new node.
+    ///
+    /// This walks from the front until finding the insertion point, so it takes _O_(_n_).
+    pub fn insert_sorted_by<F>(&mut self, item: T, mut compare: F) -> handle::NodeHandle<T>
+    where
+        F: FnMut(&T, &T) -> cmp::Ordering,
+    {
+        let mut current = self.start;
+        while let Some(node) = current {
+            // SAFETY: All nodes reachable from the start are owned by this list.
+            let node_ref = unsafe { node.as_ref() };
+            if compare(&node_ref.data, &item) == cmp::Ordering::Greater {
+                break;
+            }
+            current = node_ref.next;
+        }
+
+        let node = match current {
+            // SAFETY: `node` is owned by this list, see above.
+            Some(node) => unsafe { self.insert_in_dir(Some(node), Direction::Before, item) },
+            None => return self.push_back(item),
+        };
+        self.handle_for(node)
+    }
+
+    /// Like [`Self::insert_sorted_by`], but for lists sorted by `T`'s own order.
+    pub fn insert_sorted(&mut self, item: T) -> handle::NodeHandle<T>
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(item, T::cmp)
+    }
+
+    /// Like [`Self::insert_sorted_by`], but for lists sorted by the keys extracted by `f`.
+    pub fn insert_sorted_by_key<K: Ord, F>(&mut self, item: T, mut f: F) -> handle::NodeHandle<T>
+    where
+        F: FnMut(&T) -> K,
+    {
+        self.insert_sorted_by(item, |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Looks up an element in this list, which must be sorted consistently with `compare`,
+    /// inserting one created by `make` at the right place if there's none yet. Either way,
+    /// returns a cursor pointing at the element, all in a single walk from the front.
+    ///
+    /// Like [`slice::binary_search_by`], `compare` says how each element is ordered relative
+    /// to the one looked for. Should multiple elements compare equal, the cursor points at the
+    /// first one.
+    pub fn find_or_insert_with<F, G>(
+        &mut self,
+        mut compare: F,
+        make: G,
+    ) -> cursor::CursorMut<'_, T, A>
+    where
+        F: FnMut(&T) -> cmp::Ordering,
+        G: FnOnce() -> T,
+    {
+        let mut current = self.start;
+        let mut idx = 0;
+        while let Some(node) = current {
+            // SAFETY: All nodes reachable from the start are owned by this list.
+            let node_ref = unsafe { node.as_ref() };
+            match compare(&node_ref.data) {
+                cmp::Ordering::Less => {}
+                // SAFETY: `node` is owned by this list and `idx` steps were taken to it.
+                cmp::Ordering::Equal => {
+                    return unsafe { cursor::CursorMut::new_at(self, node, idx) }
+                }
+                cmp::Ordering::Greater => break,
+            }
+            current = node_ref.next;
+            idx += 1;
+        }
+
+        let (anchor, direction) = match current {
+            Some(node) => (Some(node), Direction::Before),
+            None => (self.end, Direction::After),
+        };
+        // SAFETY: `anchor` is owned by this list, or `None` if it's empty, and the new node
+        //         ends up exactly at `idx`.
+        unsafe {
+            let node = self.insert_in_dir(anchor, direction, make());
+            cursor::CursorMut::new_at(self, node, idx)
+        }
+    }
+
+    /// Returns the data stored on the node the given handle points at, in _O_(1).
+    ///
+    /// # Safety
+    ///
+    /// `handle` must point at a node which is still part of this list.
+    pub unsafe fn get_by_handle(&self, handle: handle::NodeHandle<T>) -> &T {
+        #[cfg(feature = "checked-handles")]
+        debug_assert!(self.contains_handle(handle), "handle is dangling");
+
+        // SAFETY: Delegated to the caller.
+        unsafe { &(*handle.node.as_ptr()).data }
+    }
+
+    /// Returns a mutable reference to the data stored on the node the given handle points at,
+    /// in _O_(1).
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::get_by_handle`].
+    pub unsafe fn get_by_handle_mut(&mut self, handle: handle::NodeHandle<T>) -> &mut T {
+        #[cfg(feature = "checked-handles")]
+        debug_assert!(self.contains_handle(handle), "handle is dangling");
+
+        // SAFETY: Delegated to the caller.
+        unsafe { &mut (*handle.node.as_ptr()).data }
+    }
+
+    /// Removes the node the given handle points at and returns its data, in _O_(1). Afterwards,
+    /// the handle (and all copies of it) are dangling and must not be used anymore.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::get_by_handle`].
+    pub unsafe fn remove_by_handle(&mut self, handle: handle::NodeHandle<T>) -> T {
+        #[cfg(feature = "checked-handles")]
+        debug_assert!(self.contains_handle(handle), "handle is dangling");
+
+        // SAFETY: Delegated to the caller.
+        unsafe { self.remove(handle.node) }
+    }
+
+    /// Relinks the node the given handle points at to the **front** of the list, in _O_(1) and
+    /// without reallocating it, so the handle stays valid.
+    ///
+    /// Together with [`Self::pop_lru`], this makes the list usable as the recency list of an
+    /// LRU cache: touch entries whenever they're used, and evict from the back.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::get_by_handle`].
+    pub unsafe fn touch(&mut self, handle: handle::NodeHandle<T>) {
+        #[cfg(feature = "checked-handles")]
+        debug_assert!(self.contains_handle(handle), "handle is dangling");
+
+        // SAFETY: Delegated to the caller, the node is only moved around.
+        unsafe {
+            self.unlink(handle.node);
+            self.link_in_dir(self.start, Direction::Before, handle.node);
+        }
+    }
+
+    /// Like [`Self::touch`], but relinks the node to the **back** of the list instead.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::get_by_handle`].
+    pub unsafe fn touch_back(&mut self, handle: handle::NodeHandle<T>) {
+        #[cfg(feature = "checked-handles")]
+        debug_assert!(self.contains_handle(handle), "handle is dangling");
+
+        // SAFETY: Same as in `touch`.
+        unsafe {
+            self.unlink(handle.node);
+            self.link_in_dir(self.end, Direction::After, handle.node);
+        }
+    }
+
+    /// Removes the least recently [touched](Self::touch) element, which is the one at the
+    /// back of the list. Same as [`Self::pop_back`], but reads better in LRU code.
+    pub fn pop_lru(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    /// Creates a cursor pointing at the node the given handle points at.
+    ///
+    /// Note that this needs to walk to the start of the list to find out the index of the node,
+    /// so it takes _O_(_n_).
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::get_by_handle`].
+    pub unsafe fn cursor_at_handle(
+        &self,
+        handle: handle::NodeHandle<T>,
+    ) -> cursor::Cursor<'_, T, A> {
+        #[cfg(feature = "checked-handles")]
+        debug_assert!(self.contains_handle(handle), "handle is dangling");
+
+        // SAFETY: Delegated to the caller.
+        unsafe {
+            let index = self.index_of(handle.node);
+            cursor::Cursor::new_at(self, handle.node, index)
+        }
+    }
+
+    /// Creates a mutable cursor pointing at the node the given handle points at.
+    ///
+    /// Note that this needs to walk to the start of the list to find out the index of the node,
+    /// so it takes _O_(_n_).
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::get_by_handle`].
+    pub unsafe fn cursor_mut_at_handle(
+        &mut self,
+        handle: handle::NodeHandle<T>,
+    ) -> cursor::CursorMut<'_, T, A> {
+        #[cfg(feature = "checked-handles")]
+        debug_assert!(self.contains_handle(handle), "handle is dangling");
+
+        // SAFETY: Delegated to the caller.
+        unsafe {
+            let index = self.index_of(handle.node);
+            cursor::CursorMut::new_at(self, handle.node, index)
+        }
+    }
+
+    /// Returns if the given handle points at a node which is still part of this list.
+    #[cfg(feature = "checked-handles")]
+    pub fn contains_handle(&self, handle: handle::NodeHandle<T>) -> bool {
+        self.registry.contains(handle)
+    }
+
+    /// Returns the data stored on the node the given handle points at, or `None` if the handle
+    /// is dangling or belongs to another list.
+    #[cfg(feature = "checked-handles")]
+    pub fn try_get_by_handle(&self, handle: handle::NodeHandle<T>) -> Option<&T> {
+        // SAFETY: Just checked that the node is still alive in this list.
+        self.contains_handle(handle)
+            .then(|| unsafe { self.get_by_handle(handle) })
+    }
+
+    /// Returns a mutable reference to the data stored on the node the given handle points at,
+    /// or `None` if the handle is dangling or belongs to another list.
+    #[cfg(feature = "checked-handles")]
+    pub fn try_get_by_handle_mut(&mut self, handle: handle::NodeHandle<T>) -> Option<&mut T> {
+        // SAFETY: Same as `Self::try_get_by_handle`.
+        self.contains_handle(handle)
+            .then(|| unsafe { self.get_by_handle_mut(handle) })
+    }
+
+    /// Removes the node the given handle points at and returns its data, or `None` if the
+    /// handle is dangling or belongs to another list.
+    #[cfg(feature = "checked-handles")]
+    pub fn try_remove_by_handle(&mut self, handle: handle::NodeHandle<T>) -> Option<T> {
+        // SAFETY: Same as `Self::try_get_by_handle`.
+        self.contains_handle(handle)
+            .then(|| unsafe { self.remove_by_handle(handle) })
     }
 
-    /// Creates a cursor pointing at node with the given index in the list.
-    pub fn cursor_at(&self, idx: usize) -> cursor::Cursor<'_, T> {
-        // SAFETY: Same as `Self::iter`.
-        let mut cursor = unsafe { cursor::Cursor::new_back(self) };
-        cursor.move_to(idx);
-        cursor
+    /// Relinks the node the given handle points at to the front of the list like
+    /// [`Self::touch`]. Returns `false` and does nothing if the handle is dangling or belongs
+    /// to another list.
+    #[cfg(feature = "checked-handles")]
+    pub fn try_touch(&mut self, handle: handle::NodeHandle<T>) -> bool {
+        let valid = self.contains_handle(handle);
+        if valid {
+            // SAFETY: Same as `Self::try_get_by_handle`.
+            unsafe { self.touch(handle) };
+        }
+        valid
     }
 
-    pub fn cursor_mut_front(&mut self) -> cursor::CursorMut<'_, T> {
-        // SAFETY: Same as `Self::iter`.
-        unsafe { cursor::CursorMut::new_front(self) }
+    /// Creates a cursor pointing at the node the given handle points at, or `None` if the
+    /// handle is dangling or belongs to another list. Takes _O_(_n_), see
+    /// [`Self::cursor_at_handle`].
+    #[cfg(feature = "checked-handles")]
+    pub fn try_cursor_at_handle(
+        &self,
+        handle: handle::NodeHandle<T>,
+    ) -> Option<cursor::Cursor<'_, T, A>> {
+        // SAFETY: Same as `Self::try_get_by_handle`.
+        self.contains_handle(handle)
+            .then(|| unsafe { self.cursor_at_handle(handle) })
     }
 
-    pub fn cursor_mut_back(&mut self) -> cursor::CursorMut<'_, T> {
-        // SAFETY: Same as `Self::iter`.
-        unsafe { cursor::CursorMut::new_back(self) }
+    /// Creates a mutable cursor pointing at the node the given handle points at, or `None` if
+    /// the handle is dangling or belongs to another list. Takes _O_(_n_), see
+    /// [`Self::cursor_at_handle`].
+    #[cfg(feature = "checked-handles")]
+    pub fn try_cursor_mut_at_handle(
+        &mut self,
+        handle: handle::NodeHandle<T>,
+    ) -> Option<cursor::CursorMut<'_, T, A>> {
+        // SAFETY: Same as `Self::try_get_by_handle`.
+        self.contains_handle(handle)
+            .then(|| unsafe { self.cursor_mut_at_handle(handle) })
     }
 
-    /// Appends the given item to the end of the list, should complete in _O_(1).
-    pub fn push_front(&mut self, item: T) {
-        // SAFETY: `self.start` is only mutated by `Self::insert_in_dir` or `Self::pop`,
-        // which both preserve the validity of it.
-        unsafe {
-            self.insert_in_dir(self.start, Direction::Before, item);
+    /// Creates a handle for the given node.
+    pub(crate) fn handle_for(&self, node: Pointer<T>) -> handle::NodeHandle<T> {
+        handle::NodeHandle {
+            node,
+            #[cfg(feature = "checked-handles")]
+            generation: self
+                .registry
+                .generation_of(node)
+                .expect("node should be registered in its own list"),
         }
     }
 
-    /// Inserts the given item before the first element of the list, should complete in _O_(1).
-    pub fn push_back(&mut self, item: T) {
-        // SAFETY: `self.end` is only mutated by `Self::insert_in_dir` or `Self::pop`,
-        // which both preserve the validity of it.
-        unsafe {
-            self.insert_in_dir(self.end, Direction::After, item);
+    /// Counts how many nodes are before the given one, in effect its index.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a valid, well-aligned pointer to a list element owned by this list.
+    unsafe fn index_of(&self, node: Pointer<T>) -> usize {
+        let mut index = 0;
+        let mut current = node.as_ref().prev;
+        while let Some(prev) = current {
+            index += 1;
+            current = prev.as_ref().prev;
         }
+        index
     }
 
     /// Inserts the given element in the given direction of the anchor element, or as the
-    /// sole element of this list, if `anchor` is `None`. Ensures that `self.start` and
-    /// `self.end` stay updated accordingly, if there is no node in `direction`.
+    /// sole element of this list, if `anchor` is `None`, and returns the new node. Ensures
+    /// that `self.start` and `self.end` stay updated accordingly, if there is no node in
+    /// `direction`.
     ///
     /// # Safety
     ///
@@ -114,31 +1486,172 @@ impl<T> ReversibleList<T> {
     ///
     /// Panics if `anchor` is the sentinel tail or head element, and `direction` points
     /// away from the rest of the list.
-    unsafe fn insert_in_dir(&mut self, anchor: MaybePointer<T>, direction: Direction, item: T) {
+    unsafe fn insert_in_dir(
+        &mut self,
+        anchor: MaybePointer<T>,
+        direction: Direction,
+        item: T,
+    ) -> Pointer<T> {
+        let new_node = self.allocate(Node {
+            data: item,
+            prev: None,
+            next: None,
+        });
+
+        // SAFETY: Delegated to the caller, `new_node` was just allocated and is detached.
+        unsafe {
+            self.link_in_dir(anchor, direction, new_node);
+        }
+        #[cfg(feature = "checked-handles")]
+        self.registry.register(new_node);
+
+        new_node
+    }
+
+    /// Like [`Self::insert_in_dir`], but hands `item` back in an error instead of aborting if
+    /// the allocator fails.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::insert_in_dir`].
+    unsafe fn try_insert_in_dir(
+        &mut self,
+        anchor: MaybePointer<T>,
+        direction: Direction,
+        item: T,
+    ) -> Result<Pointer<T>, TryInsertError<T>> {
+        let new_node = self.try_allocate(Node {
+            data: item,
+            prev: None,
+            next: None,
+        })?;
+
+        // SAFETY: Same as in `Self::insert_in_dir`.
+        unsafe {
+            self.link_in_dir(anchor, direction, new_node);
+        }
+        #[cfg(feature = "checked-handles")]
+        self.registry.register(new_node);
+
+        Ok(new_node)
+    }
+
+    /// Inserts all items as one chain in the given direction of the anchor element, or as the
+    /// sole elements of this list, if `anchor` is `None`. The new nodes are linked to each
+    /// other while the iterator runs, and into the list only once at the end. If the iterator
+    /// panics, the items yielded until then are still linked in.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::insert_in_dir`].
+    unsafe fn insert_all_in_dir(
+        &mut self,
+        anchor: MaybePointer<T>,
+        direction: Direction,
+        items: impl IntoIterator<Item = T>,
+    ) {
+        /// Links the chain into the list on drop, so also while unwinding.
+        struct Chain<'list, T, A: Allocator> {
+            list: &'list mut ReversibleList<T, A>,
+            anchor: MaybePointer<T>,
+            direction: Direction,
+            ends: Option<(Pointer<T>, Pointer<T>)>,
+            len: usize,
+        }
+
+        impl<T, A: Allocator> Drop for Chain<'_, T, A> {
+            fn drop(&mut self) {
+                let Some((first, last)) = self.ends else {
+                    return;
+                };
+                // SAFETY: `anchor` is delegated to the caller of `insert_all_in_dir`, and the
+                //         chain consists of complete nodes owned by the list.
+                unsafe {
+                    let (before, after) = match self.anchor {
+                        Some(anchor) => {
+                            retrieve_paired_elements(anchor, Pair::AnchorAnd(self.direction))
+                        }
+                        None => (None, None),
+                    };
+                    (*first.as_ptr()).prev = before;
+                    (*last.as_ptr()).next = after;
+                    match before {
+                        Some(before) => (*before.as_ptr()).next = Some(first),
+                        None => self.list.start = Some(first),
+                    }
+                    match after {
+                        Some(after) => (*after.as_ptr()).prev = Some(last),
+                        None => self.list.end = Some(last),
+                    }
+                }
+                self.list.len += self.len;
+                self.list.structure_changed();
+            }
+        }
+
+        let mut chain = Chain {
+            list: self,
+            anchor,
+            direction,
+            ends: None,
+            len: 0,
+        };
+        for item in items {
+            let node = chain.list.allocate(Node {
+                data: item,
+                prev: chain.ends.map(|(_, last)| last),
+                next: None,
+            });
+            chain.ends = match chain.ends {
+                Some((first, last)) => {
+                    // SAFETY: `last` was allocated by this loop and isn't linked anywhere else.
+                    unsafe { (*last.as_ptr()).next = Some(node) };
+                    Some((first, node))
+                }
+                None => Some((node, node)),
+            };
+            chain.len += 1;
+            #[cfg(feature = "checked-handles")]
+            chain.list.registry.register(node);
+        }
+    }
+
+    /// Links the given detached node in the given direction of the anchor element, or as the
+    /// sole element of this list, if `anchor` is `None`. Like [`Self::insert_in_dir`], but
+    /// without allocating.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::insert_in_dir`]. Additionally, `node` must be a valid, well-aligned
+    /// pointer to a node which is not linked into any list.
+    unsafe fn link_in_dir(
+        &mut self,
+        anchor: MaybePointer<T>,
+        direction: Direction,
+        node: Pointer<T>,
+    ) {
         let (before_new, after_new) = match anchor {
             Some(anchor) => retrieve_paired_elements(anchor, Pair::AnchorAnd(direction)),
             None => (None, None),
         };
 
-        let new_node = allocate(Node {
-            data: item,
-            prev: before_new,
-            next: after_new,
-        });
-
         // SAFETY: Delegated to the caller.
         unsafe {
+            (*node.as_ptr()).prev = before_new;
+            (*node.as_ptr()).next = after_new;
+
             match before_new {
-                Some(before_new) => (*before_new.as_ptr()).next = Some(new_node),
-                None => self.start = Some(new_node),
+                Some(before_new) => (*before_new.as_ptr()).next = Some(node),
+                None => self.start = Some(node),
             }
             match after_new {
-                Some(after_new) => (*after_new.as_ptr()).prev = Some(new_node),
-                None => self.end = Some(new_node),
+                Some(after_new) => (*after_new.as_ptr()).prev = Some(node),
+                None => self.end = Some(node),
             }
         }
 
         self.len += 1;
+        self.structure_changed();
     }
 
     /// Removes the element at the beginning of the list, should complete in _O_(1).
@@ -155,12 +1668,171 @@ impl<T> ReversibleList<T> {
         unsafe { Some(self.remove(last)) }
     }
 
-    /// Removes the given element by first deallocating the node, then unlinking it.
+    /// Removes the first element for which `pred` returns `true` and returns it, walking from
+    /// the front only once. Returns `None` if no element matches.
+    pub fn remove_first<F>(&mut self, mut pred: F) -> Option<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut current = self.start;
+        while let Some(node) = current {
+            // SAFETY: `node` is owned by this list, and no other reference to it exists.
+            let node_ref = unsafe { node.as_ref() };
+            if pred(&node_ref.data) {
+                // SAFETY: See above.
+                return Some(unsafe { self.remove(node) });
+            }
+            current = node_ref.next;
+        }
+        None
+    }
+
+    /// Removes the first element which equals `item` and returns it. Returns `None` if no
+    /// element does.
+    pub fn remove_item(&mut self, item: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        self.remove_first(|candidate| candidate == item)
+    }
+
+    /// Removes all elements for which `pred` returns `true` in one pass, and returns how many
+    /// have been removed. The removed elements are dropped right away.
+    ///
+    /// If `pred` or dropping an element panics, the elements looked at so far are removed, and
+    /// the rest stays in the list.
+    pub fn remove_all<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut removed = 0;
+        let mut current = self.start;
+        while let Some(node) = current {
+            // SAFETY: `node` is owned by this list, and no other reference to it exists.
+            let node_ref = unsafe { node.as_ref() };
+            current = node_ref.next;
+            if pred(&node_ref.data) {
+                removed += 1;
+                // SAFETY: See above, the next node has been read already.
+                drop(unsafe { self.remove(node) });
+            }
+        }
+        removed
+    }
+
+    /// Removes all elements after the first `len` ones, dropping them from the back. Does
+    /// nothing if the list isn't longer than `len`.
+    ///
+    /// If dropping an element panics, the remaining ones are still removed and dropped before
+    /// the panic continues.
+    pub fn truncate(&mut self, len: usize) {
+        self.pop_down_to(len, Self::pop_back);
+    }
+
+    /// Removes all elements, dropping them from the front. Pooled nodes stay pooled, and
+    /// freed ones are pooled up to the pool limit.
+    ///
+    /// If dropping an element panics, the remaining ones are still removed and dropped before
+    /// the panic continues.
+    pub fn clear(&mut self) {
+        self.pop_down_to(0, Self::pop_front);
+    }
+
+    /// Drops elements taken out by `pop` until only `len` are left, carrying on if one of the
+    /// destructors panics so that no node is leaked.
+    fn pop_down_to(&mut self, len: usize, pop: fn(&mut Self) -> Option<T>) {
+        struct Guard<'a, T, A: Allocator> {
+            list: &'a mut ReversibleList<T, A>,
+            len: usize,
+            pop: fn(&mut ReversibleList<T, A>) -> Option<T>,
+        }
+
+        impl<T, A: Allocator> Drop for Guard<'_, T, A> {
+            fn drop(&mut self) {
+                // a second panicking destructor aborts, same as with std's collections
+                while self.list.len > self.len && (self.pop)(self.list).is_some() {}
+            }
+        }
+
+        while self.len > len {
+            let Some(item) = pop(self) else { break };
+            let guard = Guard {
+                list: self,
+                len,
+                pop,
+            };
+            drop(item);
+            mem::forget(guard);
+        }
+    }
+
+    /// Removes the given element by first unlinking it, then deallocating the node.
     ///
     /// # Safety
     ///
     /// `node` must be a valid, well-aligned pointer to a list element owned by this list.
     unsafe fn remove(&mut self, node: Pointer<T>) -> T {
+        self.unlink(node);
+        #[cfg(feature = "checked-handles")]
+        self.registry.unregister(node);
+
+        // SAFETY: Delegated to the caller, the node is unlinked now and won't be accessed anymore.
+        unsafe { self.deallocate(node) }
+    }
+
+    /// Allocates the given node, without linking it anywhere. Reuses a pooled node if there is
+    /// any, otherwise asks this list's allocator.
+    fn allocate(&mut self, node: Node<T>) -> Pointer<T> {
+        let ptr = self
+            .pool
+            .take()
+            .unwrap_or_else(|| pool::allocate_uninit(&self.alloc));
+
+        // SAFETY: The memory fits the layout of `Node<T>`, and pooled nodes don't hold any
+        //         initialized data which would need to be dropped.
+        unsafe { ptr.as_ptr().write(node) };
+        ptr
+    }
+
+    /// Like [`Self::allocate`], but hands the data back in an error instead of aborting if the
+    /// allocator fails.
+    fn try_allocate(&mut self, node: Node<T>) -> Result<Pointer<T>, TryInsertError<T>> {
+        let Some(ptr) = self
+            .pool
+            .take()
+            .or_else(|| pool::try_allocate_uninit(&self.alloc).ok())
+        else {
+            return Err(TryInsertError(node.data));
+        };
+
+        // SAFETY: Same as in `Self::allocate`.
+        unsafe { ptr.as_ptr().write(node) };
+        Ok(ptr)
+    }
+
+    /// Moves the data out of the given node and puts the node memory into the pool, or returns
+    /// it to the allocator if the pool is full.
+    ///
+    /// # Safety
+    ///
+    /// `node` must have been allocated by [`Self::allocate`] of this list, must be unlinked,
+    /// and must not be accessed anymore afterwards.
+    unsafe fn deallocate(&mut self, node: Pointer<T>) -> T {
+        // SAFETY: Delegated to the caller, the data is moved out exactly once.
+        unsafe {
+            let data = ptr::read(ptr::addr_of!((*node.as_ptr()).data));
+            self.pool.give(node, &self.alloc);
+            data
+        }
+    }
+
+    /// Unlinks the given element from its neighbors, without deallocating it. Afterwards, the
+    /// node is detached and can be linked again using [`Self::link_in_dir`].
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a valid, well-aligned pointer to a list element owned by this list.
+    unsafe fn unlink(&mut self, node: Pointer<T>) {
         let (before_ele, after_ele) = retrieve_paired_elements(node, Pair::Surrounding);
 
         // unlink it from the previous elements
@@ -188,11 +1860,160 @@ impl<T> ReversibleList<T> {
             }
         }
 
+        (*node.as_ptr()).prev = None;
+        (*node.as_ptr()).next = None;
         self.len -= 1;
+        self.structure_changed();
+    }
+
+    /// Moves the given node to the end of `other`, without reallocating it. Its handle stays
+    /// valid, but now belongs to `other`.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a valid, well-aligned pointer to a list element owned by this list, and
+    /// `other` must use an allocator which can deallocate the nodes of this list.
+    unsafe fn move_node_to_back(&mut self, node: Pointer<T>, other: &mut Self) {
+        self.move_node(node, other, other.end, Direction::After);
+    }
+
+    /// Moves the given node into `other`, in the given direction of `anchor` (or as its only
+    /// element, if `anchor` is `None`), without reallocating it. Its handle stays valid, but
+    /// now belongs to `other`.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Self::move_node_to_back`], and additionally `anchor` must satisfy the
+    /// requirements of [`Self::link_in_dir`] for `other`.
+    unsafe fn move_node(
+        &mut self,
+        node: Pointer<T>,
+        other: &mut Self,
+        anchor: MaybePointer<T>,
+        direction: Direction,
+    ) {
+        self.unlink(node);
+        other.link_in_dir(anchor, direction, node);
+
+        #[cfg(feature = "checked-handles")]
+        {
+            let generation = self
+                .registry
+                .unregister(node)
+                .expect("node should be registered in its own list");
+            other.registry.adopt(node, generation);
+        }
+    }
+
+    /// Forgets everything cached about the structure of this list and bumps its version. Needs
+    /// to be called whenever nodes are linked, unlinked or reordered.
+    fn structure_changed(&mut self) {
+        self.finger.clear();
+        self.version = self.version.wrapping_add(1);
+    }
+
+    /// Makes the given node the first one, moving all nodes before it to the end in the same
+    /// order. Only the ends are relinked, so this takes _O_(1).
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a valid, well-aligned pointer to a list element owned by this list.
+    unsafe fn rotate_to_node(&mut self, node: Pointer<T>) {
+        // SAFETY: `node` is owned by this list, so the list isn't empty and `start` and `end`
+        //         are valid too.
+        unsafe {
+            let Some(new_end) = node.as_ref().prev else {
+                // already the first node
+                return;
+            };
+            let (start, end) = (self.start.unwrap_unchecked(), self.end.unwrap_unchecked());
+            (*end.as_ptr()).next = Some(start);
+            (*start.as_ptr()).prev = Some(end);
+            (*new_end.as_ptr()).next = None;
+            (*node.as_ptr()).prev = None;
+            self.start = Some(node);
+            self.end = Some(new_end);
+        }
+        // every index shifted
+        self.structure_changed();
+    }
 
-        // reboxed will be dropped at the end of the scope -- and deallocate the Node
-        let reboxed = Box::from_raw(node.as_ptr());
-        reboxed.data
+    /// Reverses the order of all nodes in _O_(_n_), by swapping the links of each.
+    fn reverse_links(&mut self) {
+        let mut current = self.start;
+        while let Some(node) = current {
+            // SAFETY: All nodes are owned by this list, and only their links are touched.
+            unsafe {
+                let node = node.as_ptr();
+                current = (*node).next;
+                ptr::swap(
+                    ptr::addr_of_mut!((*node).prev),
+                    ptr::addr_of_mut!((*node).next),
+                );
+            }
+        }
+        std::mem::swap(&mut self.start, &mut self.end);
+        self.structure_changed();
+    }
+
+    /// Unlinks the contiguous range from `first` up to and including `last` and returns it as a
+    /// separate list, without reallocating any nodes.
+    ///
+    /// # Safety
+    ///
+    /// `first` and `last` must be valid, well-aligned pointers to list elements owned by this
+    /// list, `last` must be reachable from `first` by following `next`, and `len` must be the
+    /// number of nodes in that range (inclusive on both ends).
+    unsafe fn detach_range(&mut self, first: Pointer<T>, last: Pointer<T>, len: usize) -> Self
+    where
+        A: Clone,
+    {
+        let before_range = first.as_ref().prev;
+        let after_range = last.as_ref().next;
+
+        match before_range {
+            Some(before_range) => (*before_range.as_ptr()).next = after_range,
+            None => self.start = after_range,
+        }
+        match after_range {
+            Some(after_range) => (*after_range.as_ptr()).prev = before_range,
+            None => self.end = before_range,
+        }
+
+        (*first.as_ptr()).prev = None;
+        (*last.as_ptr()).next = None;
+        self.len -= len;
+        self.structure_changed();
+
+        #[allow(unused_mut)]
+        let mut detached = Self {
+            start: Some(first),
+            end: Some(last),
+            len,
+            alloc: self.alloc.clone(),
+            pool: pool::NodePool::new(self.pool.limit()),
+            #[cfg(feature = "checked-handles")]
+            registry: handle::Registry::default(),
+            finger: finger::Finger::new(),
+            version: 0,
+            _owns: PhantomData,
+        };
+
+        // the nodes are now owned by the detached list, so their handles need to follow them
+        #[cfg(feature = "checked-handles")]
+        {
+            let mut current = Some(first);
+            while let Some(node) = current {
+                let generation = self
+                    .registry
+                    .unregister(node)
+                    .expect("node should be registered in its own list");
+                detached.registry.adopt(node, generation);
+                current = node.as_ref().next;
+            }
+        }
+
+        detached
     }
 }
 
@@ -236,51 +2057,318 @@ unsafe fn retrieve_paired_elements<T>(
     }
 }
 
-fn allocate<T>(item: T) -> NonNull<T> {
-    let ptr = Box::into_raw(Box::new(item));
-    // SAFETY: `Box::into_raw` always returns a non-null pointer according to the docs
-    unsafe { NonNull::new_unchecked(ptr) }
+impl<T, A: Allocator + Clone> ReversibleList<T, A> {
+    /// Returns an empty list using a clone of this list's allocator and the same pool limit.
+    fn empty_like(&self) -> Self {
+        let mut list = Self::new_in(self.alloc.clone());
+        list.set_pool_limit(self.pool_limit());
+        list
+    }
+
+    /// Moves the runs of elements separated by elements for which `pred` returns `true` out
+    /// of this list, just like [`slice::split`], but yielding lists. The separators are
+    /// dropped, and the nodes of the runs are not reallocated.
+    pub fn into_split<F>(self, pred: F) -> iter::IntoSplit<T, A, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        iter::IntoSplit::new(self, pred)
+    }
+
+    /// Moves the maximal runs of consecutive elements for which `pred` returns `true` on each
+    /// pair of neighbors out of this list, like [`Self::chunk_by`], but yielding lists. The
+    /// nodes are not reallocated.
+    pub fn into_chunk_by<F>(self, pred: F) -> iter::IntoChunkBy<T, A, F>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        iter::IntoChunkBy::new(self, pred)
+    }
+
+    /// Pairs up the elements of this list and `other` into a new list using this list's
+    /// allocator, just like [`Iterator::zip`]. If one list is longer, its surplus elements are
+    /// dropped.
+    ///
+    /// Each node is freed right after its element has been moved out, so at most one node more
+    /// than the result needs is allocated at any time.
+    pub fn zip_into<U, B: Allocator>(
+        self,
+        other: ReversibleList<U, B>,
+    ) -> ReversibleList<(T, U), A> {
+        let mut zipped = ReversibleList::new_in(self.alloc.clone());
+        zipped.set_pool_limit(self.pool_limit());
+        for pair in self.into_iter().zip(other) {
+            zipped.push_back(pair);
+        }
+        zipped
+    }
+
+    /// Splits this list into the elements for which `f` returns `true` and those for which it
+    /// returns `false`, in that order. Both keep their relative order.
+    ///
+    /// The nodes are relinked into the two lists, so nothing is reallocated and handles stay
+    /// valid, now belonging to whichever list their node ended up in.
+    pub fn partition<F>(mut self, mut f: F) -> (Self, Self)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut rejected = self.empty_like();
+
+        let mut current = self.start;
+        while let Some(node) = current {
+            // SAFETY: All nodes reachable from the start are owned by this list, and `rejected`
+            //         uses a clone of the same allocator.
+            unsafe {
+                current = node.as_ref().next;
+                if !f(&node.as_ref().data) {
+                    self.move_node_to_back(node, &mut rejected);
+                }
+            }
+        }
+
+        (self, rejected)
+    }
 }
 
-impl<T: Clone> Clone for ReversibleList<T> {
+impl<T: Clone, A: Allocator + Clone> Clone for ReversibleList<T, A> {
     fn clone(&self) -> Self {
-        self.iter().map(Clone::clone).collect()
+        let mut list = Self::new_in(self.alloc.clone());
+        list.set_pool_limit(self.pool_limit());
+        list.append_cloned(self.iter());
+        list
+    }
+
+    /// Overwrites the existing elements using `T::clone_from`, so neither the nodes nor any
+    /// resources held by the elements need to be reallocated. Only surplus nodes are removed
+    /// (and pooled), and missing ones allocated.
+    fn clone_from(&mut self, source: &Self) {
+        let shared = cmp::min(self.len, source.len);
+        for (item, source_item) in self.iter_mut().zip(source.iter()) {
+            item.clone_from(source_item);
+        }
+
+        self.truncate(source.len);
+        self.append_cloned(source.iter().skip(shared));
+    }
+}
+
+impl<T: Clone, A: Allocator> ReversibleList<T, A> {
+    /// Returns a new list containing the elements of this one `n` times in a row.
+    #[must_use]
+    pub fn repeat(&self, n: usize) -> Self
+    where
+        A: Clone,
+    {
+        let mut list = self.empty_like();
+        for _ in 0..n {
+            list.append_cloned(self.iter());
+        }
+        list
+    }
+
+    /// Repeats the elements of this list in place, so it contains them `n` times in a row.
+    /// The existing nodes are kept, and `n = 0` empties the list.
+    pub fn repeat_into(&mut self, n: usize) {
+        if n == 0 {
+            self.truncate(0);
+            return;
+        }
+
+        let (start, end, len) = (self.start, self.end, self.len);
+        for _ in 1..n {
+            // SAFETY: The original `len` nodes stay valid and in place while appending. Only
+            //         the links of the original end are written to, never the data which the
+            //         iterator hands out, and it stops after `len` elements anyway.
+            let original = unsafe { iter::Iter::new(start, end, len) };
+            self.append_cloned(original);
+        }
+    }
+
+    /// Appends clones of the given items, see [`Self::append_iter`]. If cloning panics, all
+    /// elements cloned so far stay in the list.
+    fn append_cloned<'a>(&mut self, items: impl Iterator<Item = &'a T>)
+    where
+        T: 'a,
+    {
+        self.append_iter(items.cloned());
     }
+}
 
-    // TODO: optimized clone_from, someday...
+impl<T, A: Allocator> ReversibleList<T, A> {
+    /// Appends the given items, linking each new node directly after the current end instead
+    /// of going through a cursor. If the iterator panics, all items yielded so far stay in the
+    /// list.
+    fn append_iter(&mut self, items: impl Iterator<Item = T>) {
+        // up front rather than afterwards, so the list is already consistent if the iterator
+        // panics halfway through, every node linked by then is complete
+        self.structure_changed();
+        for item in items {
+            let node = self.allocate(Node {
+                data: item,
+                prev: self.end,
+                next: None,
+            });
+            match self.end {
+                // SAFETY: `end` is owned by this list, and `node` was just allocated.
+                Some(end) => unsafe { (*end.as_ptr()).next = Some(node) },
+                None => self.start = Some(node),
+            }
+            self.end = Some(node);
+            self.len += 1;
+            #[cfg(feature = "checked-handles")]
+            self.registry.register(node);
+        }
+    }
 }
 
-impl<T: fmt::Debug> fmt::Debug for ReversibleList<T> {
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for ReversibleList<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
 }
 
+/// Display view of a [`ReversibleList`], created by [`ReversibleList::display_with`].
+pub struct DisplayWith<'list, 'sep, T, A: Allocator = Global> {
+    list: &'list ReversibleList<T, A>,
+    separator: &'sep str,
+}
+
+impl<T, A: Allocator> ReversibleList<T, A> {
+    /// Returns a wrapper whose `Display` impl writes all elements with `separator` in between,
+    /// without allocating. Formatting options like width and precision are applied to each
+    /// element on its own.
+    pub fn display_with<'sep>(&self, separator: &'sep str) -> DisplayWith<'_, 'sep, T, A> {
+        DisplayWith {
+            list: self,
+            separator,
+        }
+    }
+}
+
+impl<T: fmt::Display, A: Allocator> fmt::Display for DisplayWith<'_, '_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, item) in self.list.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            item.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Debug view of a [`ReversibleList`] including its node structure, created by
+/// [`ReversibleList::debug_nodes`].
+pub struct DebugNodes<'list, T, A: Allocator = Global> {
+    list: &'list ReversibleList<T, A>,
+}
+
+impl<T, A: Allocator> ReversibleList<T, A> {
+    /// Returns a wrapper whose `Debug` impl shows the node addresses and their `prev`/`next`
+    /// pointers alongside the data, for low-level troubleshooting. The plain `Debug` impl only
+    /// shows the data.
+    pub fn debug_nodes(&self) -> DebugNodes<'_, T, A> {
+        DebugNodes { list: self }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for DebugNodes<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct NodeEntry<'list, T>(Pointer<T>, &'list Node<T>);
+
+        impl<T: fmt::Debug> fmt::Debug for NodeEntry<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct("Node")
+                    .field("addr", &self.0)
+                    .field("prev", &self.1.prev)
+                    .field("next", &self.1.next)
+                    .field("data", &self.1.data)
+                    .finish()
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(self.list.len);
+        let mut current = self.list.start;
+        while let Some(node) = current {
+            // SAFETY: All nodes reachable from the start are owned by the borrowed list.
+            let node_ref = unsafe { node.as_ref() };
+            nodes.push(NodeEntry(node, node_ref));
+            current = node_ref.next;
+        }
+
+        f.debug_struct("ReversibleList")
+            .field("len", &self.list.len)
+            .field("start", &self.list.start)
+            .field("end", &self.list.end)
+            .field("pooled", &self.list.pool.len())
+            .field("nodes", &nodes)
+            .finish()
+    }
+}
+
 impl<T> Default for ReversibleList<T> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<T> Drop for ReversibleList<T> {
+#[cfg(not(feature = "dropck_eyepatch"))]
+impl<T, A: Allocator> Drop for ReversibleList<T, A> {
+    fn drop(&mut self) {
+        self.drop_all();
+    }
+}
+
+// SAFETY: Dropping the list doesn't access any `T` except for dropping it.
+#[cfg(feature = "dropck_eyepatch")]
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for ReversibleList<T, A> {
     fn drop(&mut self) {
-        // just create a cursor and remove elements until it is empty
-        // the cursor advances to the next element automatically
-        let mut cursor = self.cursor_mut_front();
-        while cursor.remove_current().is_some() {}
+        self.drop_all();
+    }
+}
+
+impl<T, A: Allocator> ReversibleList<T, A> {
+    fn drop_all(&mut self) {
+        // no point in pooling nodes anymore, so this also releases all currently pooled ones
+        self.set_pool_limit(0);
+        self.clear();
     }
 }
 
-impl<T> Extend<T> for ReversibleList<T> {
+impl<T, A: Allocator> Extend<T> for ReversibleList<T, A> {
+    /// Appends the items one by one. If the iterator panics, all items yielded before stay in
+    /// the list.
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         // distortions caused by Self::reverse are only applicable on a finite range
         // so extending a ReversibleList *always* ends up at the absolute end, either way
-        let mut back_cursor = self.cursor_mut_back();
+        self.append_iter(iter.into_iter());
+    }
+}
 
-        for item in iter {
-            back_cursor.insert_after(item);
-            back_cursor.move_next();
-        }
+impl<T, A: Allocator> IntoIterator for ReversibleList<T, A> {
+    type Item = T;
+    type IntoIter = iter::IntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::IntoIter::new(self)
+    }
+}
+
+impl<'list, T, A: Allocator> IntoIterator for &'list ReversibleList<T, A> {
+    type Item = &'list T;
+    type IntoIter = iter::Iter<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'list, T, A: Allocator> IntoIterator for &'list mut ReversibleList<T, A> {
+    type Item = &'list mut T;
+    type IntoIter = iter::IterMut<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
@@ -298,13 +2386,44 @@ impl<T> From<Vec<T>> for ReversibleList<T> {
     }
 }
 
+impl<T, A: Allocator> From<ReversibleList<T, A>> for Vec<T> {
+    fn from(value: ReversibleList<T, A>) -> Self {
+        value.into_vec()
+    }
+}
+
+impl<T> From<LinkedList<T>> for ReversibleList<T> {
+    fn from(value: LinkedList<T>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T, A: Allocator> From<ReversibleList<T, A>> for LinkedList<T> {
+    fn from(value: ReversibleList<T, A>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T> From<VecDeque<T>> for ReversibleList<T> {
+    fn from(value: VecDeque<T>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<T, A: Allocator> From<ReversibleList<T, A>> for VecDeque<T> {
+    fn from(value: ReversibleList<T, A>) -> Self {
+        value.into_vec().into()
+    }
+}
+
 impl<T, const N: usize> From<[T; N]> for ReversibleList<T> {
     fn from(value: [T; N]) -> Self {
         value.into_iter().collect()
     }
 }
 
-impl<T: Hash> Hash for ReversibleList<T> {
+impl<T: Hash, A: Allocator> Hash for ReversibleList<T, A> {
+    // must stay consistent with `PartialEq`, so only the iteration order may matter here
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.len.hash(state);
         for item in self.iter() {
@@ -313,21 +2432,47 @@ impl<T: Hash> Hash for ReversibleList<T> {
     }
 }
 
-impl<T: PartialEq> PartialEq for ReversibleList<T> {
-    fn eq(&self, other: &Self) -> bool {
+impl<T: PartialEq, A: Allocator, B: Allocator> PartialEq<ReversibleList<T, B>>
+    for ReversibleList<T, A>
+{
+    fn eq(&self, other: &ReversibleList<T, B>) -> bool {
         self.iter().eq(other.iter())
     }
 }
 
-impl<T: Eq> Eq for ReversibleList<T> {}
+/// Implements `PartialEq` between the list and a sequence type in both directions, comparing
+/// element by element.
+macro_rules! impl_seq_eq {
+    ($([$($generics:tt)*] $seq:ty),* $(,)?) => {$(
+        impl<T: PartialEq<U>, U, A: Allocator, $($generics)*> PartialEq<$seq>
+            for ReversibleList<T, A>
+        {
+            fn eq(&self, other: &$seq) -> bool {
+                self.len == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+            }
+        }
+
+        impl<T, U: PartialEq<T>, A: Allocator, $($generics)*> PartialEq<ReversibleList<T, A>>
+            for $seq
+        {
+            fn eq(&self, other: &ReversibleList<T, A>) -> bool {
+                self.len() == other.len && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+            }
+        }
+    )*};
+}
+
+impl_seq_eq!([] [U], [const N: usize] [U; N], [] Vec<U>);
+
+impl<T: Eq, A: Allocator> Eq for ReversibleList<T, A> {}
 
-impl<T: PartialOrd> PartialOrd for ReversibleList<T> {
+impl<T: PartialOrd, A: Allocator> PartialOrd for ReversibleList<T, A> {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
         self.iter().partial_cmp(other.iter())
     }
 }
 
-impl<T: Ord> Ord for ReversibleList<T> {
+impl<T: Ord, A: Allocator> Ord for ReversibleList<T, A> {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         self.iter().cmp(other.iter())
     }
@@ -0,0 +1,57 @@
+//! Model-checked tests of the concurrent types, only compiled with `--cfg loom`.
+//!
+//! Run them with `RUSTFLAGS="--cfg loom" cargo test --release`. Loom explores every possible
+//! interleaving of the threads, so keep the number of operations per test tiny.
+
+use loom::thread;
+
+use crate::{mpsc, ReversibleList};
+
+#[test]
+fn two_producers_one_consumer() {
+    loom::model(|| {
+        let (producer, mut consumer) = mpsc::queue();
+        let other = producer.clone();
+        let first = thread::spawn(move || producer.push_back(1));
+        let second = thread::spawn(move || other.push_back(2));
+
+        // popping concurrently sees any prefix of the completed pushes
+        let mut seen = Vec::new();
+        seen.extend(consumer.pop_front());
+
+        first.join().unwrap();
+        second.join().unwrap();
+        seen.extend(consumer.by_ref());
+        seen.sort_unstable();
+        assert_eq!(seen, [1, 2]);
+        assert!(consumer.is_empty());
+    });
+}
+
+#[test]
+fn pushes_stay_in_order() {
+    loom::model(|| {
+        let (producer, mut consumer) = mpsc::queue();
+        let pusher = thread::spawn(move || {
+            producer.push_back("first");
+            producer.push_back("second");
+        });
+
+        let mut log = ReversibleList::new();
+        consumer.drain_into(&mut log);
+        pusher.join().unwrap();
+        consumer.drain_into(&mut log);
+        assert_eq!(log, ["first", "second"]);
+    });
+}
+
+#[test]
+fn dropped_while_pushing() {
+    loom::model(|| {
+        let (producer, consumer) = mpsc::queue();
+        let pusher = thread::spawn(move || producer.push_back(String::from("late")));
+        // whoever drops last frees the pending element
+        drop(consumer);
+        pusher.join().unwrap();
+    });
+}
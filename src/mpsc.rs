@@ -0,0 +1,197 @@
+//! A singly linked queue which many threads can append to without locking, drained by one.
+//!
+//! Created by [`queue`], which returns a [`Producer`] and a [`Consumer`] end. The producer can
+//! be cloned and shared freely, every [`Producer::push_back`] is one atomic swap plus one
+//! atomic store. The consumer is unique, so it can walk from the front without any
+//! synchronization besides reading the links.
+//!
+//! This is the classic (non-intrusive) MPSC queue by Dmitry Vyukov: the front of the queue is
+//! always a "stub" node whose data has been taken already, and pushing swaps the new node in as
+//! the tail before linking the old tail to it. In between those two steps, the queue looks shorter
+//! to the consumer than it actually is, so [`Consumer::pop_front`] might briefly return `None`
+//! even though a push has started already. The element shows up once the push completes.
+//!
+//! The nodes mirror the ones of [`ReversibleList`] minus the `prev` link, which the queue never
+//! needs. Since the `next` link has to be atomic, they can't be the very same type though.
+
+use std::{fmt, mem::MaybeUninit, ptr};
+
+use allocator_api2::alloc::Allocator;
+
+use crate::{
+    primitives::{Arc, AtomicPtr, Ordering, UnsafeCell},
+    ReversibleList,
+};
+
+/// Creates a new empty queue, returning its two ends.
+#[must_use]
+pub fn queue<T>() -> (Producer<T>, Consumer<T>) {
+    let stub = Slot::stub();
+    let shared = Arc::new(Shared {
+        head: UnsafeCell::new(stub),
+        tail: AtomicPtr::new(stub),
+    });
+    (
+        Producer {
+            shared: Arc::clone(&shared),
+        },
+        Consumer { shared },
+    )
+}
+
+struct Slot<T> {
+    next: AtomicPtr<Slot<T>>,
+    /// Uninitialized for the stub node at the front, and initialized for all others.
+    data: MaybeUninit<T>,
+}
+
+impl<T> Slot<T> {
+    fn new(data: MaybeUninit<T>) -> *mut Self {
+        Box::into_raw(Box::new(Self {
+            next: AtomicPtr::new(ptr::null_mut()),
+            data,
+        }))
+    }
+
+    fn stub() -> *mut Self {
+        Self::new(MaybeUninit::uninit())
+    }
+}
+
+struct Shared<T> {
+    /// The stub node. Only ever accessed by the consumer, or on drop.
+    head: UnsafeCell<*mut Slot<T>>,
+    /// The most recently pushed node, or the stub if there's none.
+    tail: AtomicPtr<Slot<T>>,
+}
+
+// SAFETY: Elements are only ever moved between threads, never shared, and all links crossing
+//         threads are atomic. `head` is exclusive to the unique consumer.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        // all producers and the consumer are gone, so every push has been completed already
+        // SAFETY: This is the last reference to the queue.
+        let mut current = self.head.with(|head| unsafe { *head });
+        let mut is_stub = true;
+        while !current.is_null() {
+            // SAFETY: All nodes were allocated by `Slot::new` and are exclusively owned by the
+            //         queue, and only the stub has uninitialized data.
+            let mut slot = unsafe { Box::from_raw(current) };
+            current = slot.next.load(Ordering::Relaxed);
+            if !is_stub {
+                // SAFETY: See above.
+                unsafe { slot.data.assume_init_drop() };
+            }
+            is_stub = false;
+        }
+    }
+}
+
+/// The appending end of a queue, which can be cloned and shared between threads.
+///
+/// See the module docs for details.
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Producer<T> {
+    /// Appends the given item to the end of the queue, without locking.
+    pub fn push_back(&self, item: T) {
+        let slot = Slot::new(MaybeUninit::new(item));
+        // the swap orders all pushes, and the release part publishes the node's data
+        let prev = self.shared.tail.swap(slot, Ordering::AcqRel);
+        // SAFETY: `prev` is only deallocated by the consumer after it has seen this link, so
+        //         it's still alive. No other producer writes to its `next`, since only one
+        //         swap can ever return it.
+        unsafe { (*prev).next.store(slot, Ordering::Release) };
+    }
+}
+
+impl<T> Clone for Producer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Producer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Producer").finish_non_exhaustive()
+    }
+}
+
+/// The draining end of a queue, of which there's only ever one.
+///
+/// See the module docs for details.
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Consumer<T> {
+    /// Removes the element at the front of the queue. Returns `None` if the queue is empty, or
+    /// if the push of the next element hasn't completed yet.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.shared.head.with_mut(|head_ptr| {
+            // SAFETY: This is the only consumer, so nobody else accesses `head`.
+            let head = unsafe { *head_ptr };
+            // SAFETY: The stub stays alive until the consumer deallocates it below.
+            let next = unsafe { (*head).next.load(Ordering::Acquire) };
+            if next.is_null() {
+                return None;
+            }
+
+            // SAFETY: The acquire load synchronizes with the push of `next`, so its data is
+            //         initialized and visible. Reading it out turns `next` into the new stub,
+            //         and the old stub isn't referenced by anyone anymore.
+            unsafe {
+                let data = (*next).data.assume_init_read();
+                *head_ptr = next;
+                drop(Box::from_raw(head));
+                Some(data)
+            }
+        })
+    }
+
+    /// Moves all elements whose push has completed to the end of the given list, and returns
+    /// how many have been moved.
+    pub fn drain_into<A: Allocator>(&mut self, list: &mut ReversibleList<T, A>) -> usize {
+        let mut moved = 0;
+        while let Some(item) = self.pop_front() {
+            list.push_back(item);
+            moved += 1;
+        }
+        moved
+    }
+
+    /// Returns if there's no element to pop at the moment.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        // SAFETY: Same as in `pop_front`.
+        self.shared
+            .head
+            .with(|head| unsafe { (**head).next.load(Ordering::Acquire) })
+            .is_null()
+    }
+}
+
+impl<T> Iterator for Consumer<T> {
+    type Item = T;
+
+    /// Same as [`Consumer::pop_front`]. Note that this returns `None` as soon as the queue is
+    /// empty, even if producers are still around which might push more.
+    fn next(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+}
+
+impl<T> fmt::Debug for Consumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Consumer")
+            .field("is_empty", &self.is_empty())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,209 @@
+//! A list which reports all elements entering and leaving it to an [`Observer`].
+//!
+//! Caches, secondary indexes or metrics layered on top of a list need to hear about every
+//! change to it. Instead of remembering to update them at each call site, wrap the list in an
+//! [`ObservedList`] once, and let it call the hooks of the observer from each of its mutating
+//! operations.
+//!
+//! The observer isn't stored in [`ReversibleList`] itself, since a list of `&'static str` may be
+//! used as a list of any shorter lived `&str`, and an observer expecting the former would then
+//! be handed the latter.
+
+use std::{fmt, ops::Deref};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::{handle, iter, ReversibleList};
+
+/// Hooks called by an [`ObservedList`] whenever its structure changes. All of them do nothing
+/// by default.
+pub trait Observer<T> {
+    /// Called right after the given element has been linked into the list.
+    fn on_insert(&mut self, item: &T) {
+        let _ = item;
+    }
+
+    /// Called right before the given element is unlinked from the list.
+    fn on_remove(&mut self, item: &T) {
+        let _ = item;
+    }
+
+    /// Called right after the order of all elements has been reversed.
+    fn on_reverse(&mut self) {}
+}
+
+impl<T> Observer<T> for () {}
+
+/// A [`ReversibleList`] calling the hooks of an [`Observer`] on every structural change.
+///
+/// See the module docs for details.
+pub struct ObservedList<T, O: Observer<T>, A: Allocator = Global> {
+    list: ReversibleList<T, A>,
+    observer: O,
+}
+
+impl<T, O: Observer<T>> ObservedList<T, O> {
+    /// Creates an empty list, reporting to the given observer.
+    #[must_use]
+    pub fn new(observer: O) -> Self {
+        Self::with_list(ReversibleList::new(), observer)
+    }
+
+    /// Moves all elements of `other` to the end of this list, leaving `other` empty. Reports
+    /// each of them, so unlike [`ReversibleList::append`], this takes _O_(_n_).
+    pub fn append(&mut self, other: &mut ReversibleList<T>) {
+        for item in other.iter() {
+            self.observer.on_insert(item);
+        }
+        self.list.append(other);
+    }
+}
+
+impl<T, O: Observer<T>, A: Allocator> ObservedList<T, O, A> {
+    /// Starts observing the given list. Its current elements are reported as inserted, so the
+    /// observer starts out in sync.
+    pub fn with_list(list: ReversibleList<T, A>, mut observer: O) -> Self {
+        for item in list.iter() {
+            observer.on_insert(item);
+        }
+        Self { list, observer }
+    }
+
+    /// Returns the observer.
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    /// Returns the observer mutably.
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    /// Stops observing and returns the list and the observer.
+    pub fn into_parts(self) -> (ReversibleList<T, A>, O) {
+        (self.list, self.observer)
+    }
+
+    /// Returns the element at the given index mutably. Changes made through it aren't
+    /// reported.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        self.list.get_mut(idx)
+    }
+
+    /// Returns an iterator through this list yielding mutable references. Changes made through
+    /// it aren't reported.
+    pub fn iter_mut(&mut self) -> iter::IterMut<'_, T> {
+        self.list.iter_mut()
+    }
+
+    /// Inserts the given item before the first element of the list.
+    pub fn push_front(&mut self, item: T) -> handle::NodeHandle<T> {
+        let handle = self.list.push_front(item);
+        self.report_insert(handle)
+    }
+
+    /// Appends the given item to the end of the list.
+    pub fn push_back(&mut self, item: T) -> handle::NodeHandle<T> {
+        let handle = self.list.push_back(item);
+        self.report_insert(handle)
+    }
+
+    /// Inserts the given item at the given index, see [`ReversibleList::insert`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    pub fn insert(&mut self, idx: usize, item: T) -> handle::NodeHandle<T> {
+        let handle = self.list.insert(idx, item);
+        self.report_insert(handle)
+    }
+
+    /// Removes the element at the beginning of the list.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.observer.on_remove(self.list.get(0)?);
+        self.list.pop_front()
+    }
+
+    /// Removes the element at the end of the list.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.observer
+            .on_remove(self.list.get(self.list.len().checked_sub(1)?)?);
+        self.list.pop_back()
+    }
+
+    /// Removes the element at the given index and returns it, or `None` if it's out of bounds.
+    pub fn remove(&mut self, idx: usize) -> Option<T> {
+        self.observer.on_remove(self.list.get(idx)?);
+        let node = self.list.node_at(idx);
+        // SAFETY: `node_at` only returns nodes owned by this list.
+        Some(unsafe { self.list.remove(node) })
+    }
+
+    /// Removes the node the given handle points at and returns its data.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`ReversibleList::remove_by_handle`].
+    pub unsafe fn remove_by_handle(&mut self, handle: handle::NodeHandle<T>) -> T {
+        // SAFETY: Delegated to the caller.
+        unsafe {
+            self.observer.on_remove(self.list.get_by_handle(handle));
+            self.list.remove_by_handle(handle)
+        }
+    }
+
+    /// Removes all elements for which `pred` returns `true` and returns how many there were.
+    pub fn remove_all<F>(&mut self, mut pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let observer = &mut self.observer;
+        self.list.remove_all(|item| {
+            let remove = pred(item);
+            if remove {
+                observer.on_remove(item);
+            }
+            remove
+        })
+    }
+
+    /// Removes all elements, reporting each of them.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Reverses the order of all elements, in _O_(_n_).
+    pub fn reverse(&mut self) {
+        self.list.reverse_links();
+        self.observer.on_reverse();
+    }
+
+    fn report_insert(&mut self, handle: handle::NodeHandle<T>) -> handle::NodeHandle<T> {
+        // SAFETY: The node was just linked into the list.
+        self.observer
+            .on_insert(unsafe { self.list.get_by_handle(handle) });
+        handle
+    }
+}
+
+impl<T, O: Observer<T>, A: Allocator> Deref for ObservedList<T, O, A> {
+    type Target = ReversibleList<T, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T: fmt::Debug, O: Observer<T>, A: Allocator> fmt::Debug for ObservedList<T, O, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+impl<T, O: Observer<T>, A: Allocator> Extend<T> for ObservedList<T, O, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! A hash map which remembers the order its entries were inserted in.
+//!
+//! An [`OrderedMap`] keeps its entries in a [`ReversibleList`] and indexes them by key with a
+//! hash map of [`NodeHandle`]s, so looking up, inserting and removing entries all take _O_(1),
+//! while iterating goes from the oldest entry to the newest. Since nodes never move, the index
+//! doesn't store the keys a second time, it just points at the ones in the list.
+//!
+//! Entries can be moved to the back again when they're used with
+//! [`get_refresh`](OrderedMap::get_refresh), which turns the map into an LRU cache evicting
+//! with [`pop_front`](OrderedMap::pop_front):
+//!
+//! ```
+//! use casual_linked_list::ordered::OrderedMap;
+//!
+//! let mut cache = OrderedMap::new();
+//! cache.insert("a", 1);
+//! cache.insert("b", 2);
+//! cache.insert("c", 3);
+//! *cache.get_refresh("a").unwrap() += 10;
+//! assert_eq!(cache.pop_front(), Some(("b", 2)));
+//! assert!(cache.iter().eq([(&"c", &3), (&"a", &11)]));
+//! ```
+
+use std::{
+    borrow::Borrow,
+    collections::{hash_map::RandomState, HashMap},
+    fmt,
+    hash::{BuildHasher, Hash, Hasher},
+    iter::Map,
+    mem,
+    ptr::{self, NonNull},
+};
+
+use crate::{handle::NodeHandle, iter, ReversibleList};
+
+/// Iterator through the entries of an [`OrderedMap`], from the oldest to the newest.
+pub type Iter<'map, K, V> = Map<iter::Iter<'map, (K, V)>, fn(&(K, V)) -> (&K, &V)>;
+/// Iterator through the entries of an [`OrderedMap`] with mutable access to the values.
+pub type IterMut<'map, K, V> = Map<iter::IterMut<'map, (K, V)>, fn(&mut (K, V)) -> (&K, &mut V)>;
+/// Iterator through the keys of an [`OrderedMap`], from the oldest to the newest.
+pub type Keys<'map, K, V> = Map<iter::Iter<'map, (K, V)>, fn(&(K, V)) -> &K>;
+/// Iterator through the values of an [`OrderedMap`], from the oldest to the newest.
+pub type Values<'map, K, V> = Map<iter::Iter<'map, (K, V)>, fn(&(K, V)) -> &V>;
+
+/// Hash map iterating in insertion order, see the module docs.
+pub struct OrderedMap<K, V, S = RandomState> {
+    // declared first so it's dropped before the keys it points at, even though it never
+    // looks at them while dropping
+    index: HashMap<KeyRef<K>, NodeHandle<(K, V)>, S>,
+    entries: ReversibleList<(K, V)>,
+}
+
+/// Points at the key stored in a node of the entry list, so the index can hash and compare it
+/// without keeping a copy. Only lives in the index as long as its node does.
+struct KeyRef<K>(NonNull<K>);
+
+impl<K> KeyRef<K> {
+    fn of<V>(handle: NodeHandle<(K, V)>) -> Self {
+        // SAFETY: Only computes the address of the key, without reading it or creating a
+        //         reference, which could be invalidated by later mutable access to the entry.
+        Self(unsafe { NonNull::new_unchecked(ptr::addr_of_mut!((*handle.node.as_ptr()).data.0)) })
+    }
+
+    fn key(&self) -> &K {
+        // SAFETY: The node is alive as long as this is in the index, and keys are never handed
+        //         out mutably.
+        unsafe { self.0.as_ref() }
+    }
+}
+
+impl<K: Hash> Hash for KeyRef<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl<K: PartialEq> PartialEq for KeyRef<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<K: Eq> Eq for KeyRef<K> {}
+
+// SAFETY: A `KeyRef` is only used like a `&K` within the map owning the key.
+unsafe impl<K: Sync> Send for KeyRef<K> {}
+unsafe impl<K: Sync> Sync for KeyRef<K> {}
+
+/// A borrowed key to look up entries with, hashing and comparing just like the `KeyRef`
+/// pointing at an equal key thanks to the contract of [`Borrow`].
+#[repr(transparent)]
+struct Lookup<Q: ?Sized>(Q);
+
+impl<Q: ?Sized> Lookup<Q> {
+    fn new(key: &Q) -> &Self {
+        // SAFETY: `Lookup` is a transparent wrapper.
+        unsafe { &*(key as *const Q as *const Self) }
+    }
+}
+
+impl<K: Borrow<Q>, Q: ?Sized> Borrow<Lookup<Q>> for KeyRef<K> {
+    fn borrow(&self) -> &Lookup<Q> {
+        Lookup::new(self.key().borrow())
+    }
+}
+
+impl<Q: Hash + ?Sized> Hash for Lookup<Q> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<Q: PartialEq + ?Sized> PartialEq for Lookup<Q> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<Q: Eq + ?Sized> Eq for Lookup<Q> {}
+
+impl<K, V> OrderedMap<K, V> {
+    /// Creates an empty map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K, V, S> OrderedMap<K, V, S> {
+    /// Creates an empty map which hashes keys with the given hasher.
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            index: HashMap::with_hasher(hasher),
+            entries: ReversibleList::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the oldest entry, which is the next one evicted by [`Self::pop_front`].
+    #[must_use]
+    pub fn front(&self) -> Option<(&K, &V)> {
+        self.iter().next()
+    }
+
+    /// Returns the newest entry.
+    #[must_use]
+    pub fn back(&self) -> Option<(&K, &V)> {
+        self.iter().next_back()
+    }
+
+    /// Returns an iterator through all entries, from the oldest to the newest.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Returns an iterator through all entries which allows modifying the values.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        self.entries.iter_mut().map(|(key, value)| (&*key, value))
+    }
+
+    /// Returns an iterator through all keys, from the oldest to the newest.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator through all values, from the oldest to the newest.
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Removes all entries, keeping the hasher.
+    pub fn clear(&mut self) {
+        // the index first, so it never points at removed keys
+        self.index.clear();
+        self.entries.clear();
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> OrderedMap<K, V, S> {
+    /// Inserts a new entry at the back. If there's one with an equal key already, only its
+    /// value is replaced, keeping its position, and the old value is returned.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(mem::replace(old, value));
+        }
+
+        let handle = self.entries.push_back((key, value));
+        self.index.insert(KeyRef::of(handle), handle);
+        None
+    }
+
+    #[must_use]
+    pub fn contains_key<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.index.contains_key(Lookup::new(key))
+    }
+
+    /// Returns the value for the given key, without changing its position.
+    #[must_use]
+    pub fn get<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = *self.index.get(Lookup::new(key))?;
+        // SAFETY: The index only contains handles of live entries.
+        Some(unsafe { &self.entries.get_by_handle(handle).1 })
+    }
+
+    /// Returns a mutable reference to the value for the given key, without changing its
+    /// position.
+    pub fn get_mut<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = *self.index.get(Lookup::new(key))?;
+        // SAFETY: Same as in `get`.
+        Some(unsafe { &mut self.entries.get_by_handle_mut(handle).1 })
+    }
+
+    /// Like [`Self::get_mut`], but also moves the entry to the back, as if it had just been
+    /// inserted.
+    pub fn get_refresh<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = *self.index.get(Lookup::new(key))?;
+        // SAFETY: Same as in `get`.
+        unsafe {
+            self.entries.touch_back(handle);
+            Some(&mut self.entries.get_by_handle_mut(handle).1)
+        }
+    }
+
+    /// Removes the entry for the given key and returns its value.
+    pub fn remove<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes the entry for the given key and returns it.
+    pub fn remove_entry<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let handle = self.index.remove(Lookup::new(key))?;
+        // SAFETY: Same as in `get`, and it's not in the index anymore.
+        Some(unsafe { self.entries.remove_by_handle(handle) })
+    }
+
+    /// Removes the oldest entry and returns it.
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let (key, _) = self.entries.iter().next()?;
+        self.index.remove(Lookup::new(key));
+        self.entries.pop_front()
+    }
+
+    /// Removes the newest entry and returns it.
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        let (key, _) = self.entries.iter().next_back()?;
+        self.index.remove(Lookup::new(key));
+        self.entries.pop_back()
+    }
+}
+
+impl<K, V, S: Default> Default for OrderedMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, S: BuildHasher + Clone> Clone for OrderedMap<K, V, S> {
+    fn clone(&self) -> Self {
+        let mut map = Self::with_hasher(self.index.hasher().clone());
+        map.extend(self.iter().map(|(key, value)| (key.clone(), value.clone())));
+        map
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for OrderedMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for OrderedMap<K, V, S> {
+    /// Inserts the entries one by one, see [`OrderedMap::insert`].
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for OrderedMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K, V, S> IntoIterator for OrderedMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = iter::IntoIter<(K, V)>;
+
+    /// Returns an iterator taking all entries out, from the oldest to the newest.
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'map, K, V, S> IntoIterator for &'map OrderedMap<K, V, S> {
+    type Item = (&'map K, &'map V);
+    type IntoIter = Iter<'map, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'map, K, V, S> IntoIterator for &'map mut OrderedMap<K, V, S> {
+    type Item = (&'map K, &'map mut V);
+    type IntoIter = IterMut<'map, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
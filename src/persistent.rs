@@ -0,0 +1,257 @@
+//! An immutable list sharing its structure between clones, for snapshot semantics.
+//!
+//! A [`PersistentList`] is a chain of reference-counted cons cells, singly linked from front to
+//! back. Cloning it only bumps the count of the first cell, and pushing to the front creates one
+//! new cell pointing at the old front, so any number of snapshots can share their common tail.
+//! In exchange, only the front can be changed, and there's no way to walk backwards.
+//!
+//! Cells are never mutated once created, so snapshots can also be sent to and read from other
+//! threads freely.
+
+use std::{fmt, iter::FusedIterator, sync::Arc};
+
+use allocator_api2::alloc::Allocator;
+
+use crate::ReversibleList;
+
+/// An immutable singly linked list whose clones share structure.
+///
+/// See the module docs for details.
+pub struct PersistentList<T> {
+    head: Option<Arc<Cell<T>>>,
+    len: usize,
+}
+
+struct Cell<T> {
+    data: T,
+    next: Option<Arc<Cell<T>>>,
+}
+
+impl<T> PersistentList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the first element, if any.
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|cell| &cell.data)
+    }
+
+    /// Returns an iterator through this list, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+            remaining: self.len,
+        }
+    }
+
+    /// Inserts the given item before the first element, in _O_(1). Clones of this list taken
+    /// before are not affected.
+    pub fn push_front(&mut self, item: T) {
+        self.head = Some(Arc::new(Cell {
+            data: item,
+            next: self.head.take(),
+        }));
+        self.len += 1;
+    }
+
+    /// Returns a new list with the given item in front of all elements of this one, sharing
+    /// them with this list.
+    #[must_use]
+    pub fn prepend(&self, item: T) -> Self {
+        let mut list = self.clone();
+        list.push_front(item);
+        list
+    }
+
+    /// Returns the list without its first element, sharing all the others with this list.
+    /// Returns an empty list if this one is empty.
+    #[must_use]
+    pub fn tail(&self) -> Self {
+        match &self.head {
+            Some(cell) => Self {
+                head: cell.next.clone(),
+                len: self.len - 1,
+            },
+            None => Self::new(),
+        }
+    }
+
+    /// Removes the first element and returns it. It's moved out if no other list shares it,
+    /// otherwise it's cloned.
+    pub fn pop_front(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let cell = self.head.take()?;
+        self.len -= 1;
+        match Arc::try_unwrap(cell) {
+            Ok(cell) => {
+                self.head = cell.next;
+                Some(cell.data)
+            }
+            Err(shared) => {
+                self.head = shared.next.clone();
+                Some(shared.data.clone())
+            }
+        }
+    }
+
+    /// Returns if both lists share the very same cells, in which case they're equal without
+    /// having to compare any elements.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Copies all elements into a new [`ReversibleList`], front to back.
+    pub fn to_list(&self) -> ReversibleList<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> Clone for PersistentList<T> {
+    /// Clones the list in _O_(1), by sharing all cells.
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        // dropping the cells recursively could overflow the stack for long lists, so unwind the
+        // chain by hand until reaching a cell which is still shared with another list. Unlike
+        // `Arc::try_unwrap`, `Arc::into_inner` guarantees that when two lists sharing a tail are
+        // dropped on different threads at once, one of them carries on unwinding it.
+        let mut current = self.head.take();
+        while let Some(mut cell) = current.and_then(Arc::into_inner) {
+            current = cell.next.take();
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && (self.ptr_eq(other) || self.iter().eq(other.iter()))
+    }
+}
+
+impl<T: Eq> Eq for PersistentList<T> {}
+
+impl<T> FromIterator<T> for PersistentList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        // cells can only be prepended, so the order needs to be flipped first
+        let items: Vec<T> = iter.into_iter().collect();
+        let mut list = Self::new();
+        for item in items.into_iter().rev() {
+            list.push_front(item);
+        }
+        list
+    }
+}
+
+impl<T, A: Allocator> From<ReversibleList<T, A>> for PersistentList<T> {
+    fn from(mut value: ReversibleList<T, A>) -> Self {
+        let mut list = Self::new();
+        while let Some(item) = value.pop_back() {
+            list.push_front(item);
+        }
+        list
+    }
+}
+
+impl<T: Clone> From<PersistentList<T>> for ReversibleList<T> {
+    /// Moves out all elements which aren't shared with other lists, and clones the rest.
+    fn from(mut value: PersistentList<T>) -> Self {
+        let mut list = ReversibleList::new();
+        while let Some(item) = value.pop_front() {
+            list.push_back(item);
+        }
+        list
+    }
+}
+
+impl<'list, T> IntoIterator for &'list PersistentList<T> {
+    type Item = &'list T;
+    type IntoIter = Iter<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator through a [`PersistentList`].
+pub struct Iter<'list, T> {
+    next: Option<&'list Cell<T>>,
+    remaining: usize,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            next: self.next,
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&crate::iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'list, T> Iterator for Iter<'list, T> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        let cell = self.next?;
+        self.next = cell.next.as_deref();
+        self.remaining -= 1;
+        Some(&cell.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
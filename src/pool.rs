@@ -0,0 +1,136 @@
+//! Pool of unused node allocations, so that pop-then-push workloads don't hit the allocator on
+//! every single operation.
+//!
+//! Pooled nodes are chained through their `next` pointer. Their `data` is uninitialized, since
+//! it has been moved out already, so it must never be read or dropped.
+
+use std::ptr;
+
+use allocator_api2::alloc::{handle_alloc_error, AllocError, Allocator, Layout};
+
+use crate::{MaybePointer, Node, Pointer};
+
+pub(crate) struct NodePool<T> {
+    free: MaybePointer<T>,
+    len: usize,
+    limit: usize,
+}
+
+impl<T> NodePool<T> {
+    /// How many nodes are kept around at most if not configured otherwise.
+    pub(crate) const DEFAULT_LIMIT: usize = 16;
+
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            free: None,
+            len: 0,
+            limit,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Changes the limit, deallocating pooled nodes which exceed it.
+    ///
+    /// # Safety
+    ///
+    /// All pooled nodes must have been allocated by `alloc`.
+    pub(crate) unsafe fn set_limit(&mut self, limit: usize, alloc: &impl Allocator) {
+        self.limit = limit;
+        // SAFETY: Delegated to the caller.
+        unsafe { self.shrink_to(limit, alloc) };
+    }
+
+    /// Deallocates pooled nodes until at most `len` are left, without touching the limit.
+    ///
+    /// # Safety
+    ///
+    /// All pooled nodes must have been allocated by `alloc`.
+    pub(crate) unsafe fn shrink_to(&mut self, len: usize, alloc: &impl Allocator) {
+        while len < self.len {
+            // SAFETY: Delegated to the caller.
+            unsafe { release(self.take_raw(), alloc) };
+        }
+    }
+
+    /// Allocates new nodes until at least `len` nodes are pooled, and raises the limit to at
+    /// least `limit`, which must not be less than `len`.
+    pub(crate) fn reserve(&mut self, len: usize, limit: usize, alloc: &impl Allocator) {
+        self.limit = self.limit.max(limit);
+        while self.len < len {
+            let node = allocate_uninit(alloc);
+            // SAFETY: The node was just allocated by `alloc` and there's no data in it.
+            unsafe { self.give(node, alloc) };
+        }
+    }
+
+    /// Takes one unused node out of the pool, if there is any. Its `data` is uninitialized.
+    pub(crate) fn take(&mut self) -> MaybePointer<T> {
+        if self.len == 0 {
+            return None;
+        }
+        Some(self.take_raw())
+    }
+
+    /// Puts the given node into the pool, or deallocates it if the pool is full.
+    ///
+    /// # Safety
+    ///
+    /// `node` must have been allocated by `alloc` with the layout of `Node<T>`, must not be
+    /// linked into any list, and its `data` must have been moved out already.
+    pub(crate) unsafe fn give(&mut self, node: Pointer<T>, alloc: &impl Allocator) {
+        if self.limit <= self.len {
+            // SAFETY: Delegated to the caller.
+            unsafe { release(node, alloc) };
+            return;
+        }
+
+        // SAFETY: Delegated to the caller. Only the `next` field is written, without creating
+        //         a reference to the partially uninitialized node.
+        unsafe { ptr::addr_of_mut!((*node.as_ptr()).next).write(self.free) };
+        self.free = Some(node);
+        self.len += 1;
+    }
+
+    /// Like [`Self::take`], but assumes that the pool is not empty.
+    fn take_raw(&mut self) -> Pointer<T> {
+        let node = self
+            .free
+            .expect("pool length should be in sync with the free chain");
+        // SAFETY: The `next` field of pooled nodes is always initialized by `Self::give`.
+        self.free = unsafe { ptr::addr_of!((*node.as_ptr()).next).read() };
+        self.len -= 1;
+        node
+    }
+}
+
+/// Allocates memory for one node using the given allocator, without initializing it.
+pub(crate) fn allocate_uninit<T>(alloc: &impl Allocator) -> Pointer<T> {
+    let Ok(ptr) = try_allocate_uninit(alloc) else {
+        handle_alloc_error(Layout::new::<Node<T>>());
+    };
+    ptr
+}
+
+/// Like [`allocate_uninit`], but returns an error instead of aborting if the allocator fails.
+pub(crate) fn try_allocate_uninit<T>(alloc: &impl Allocator) -> Result<Pointer<T>, AllocError> {
+    alloc
+        .allocate(Layout::new::<Node<T>>())
+        .map(|ptr| ptr.cast())
+}
+
+/// Returns the memory of the given node to the allocator, without dropping its data.
+///
+/// # Safety
+///
+/// Same as [`NodePool::give`].
+unsafe fn release<T>(node: Pointer<T>, alloc: &impl Allocator) {
+    // SAFETY: Delegated to the caller.
+    unsafe { alloc.deallocate(node.cast(), Layout::new::<Node<T>>()) };
+}
@@ -0,0 +1,37 @@
+//! Synchronization primitives used by the concurrent types, swapped out for the model-checked
+//! ones of [`loom`](https://docs.rs/loom) when compiling with `--cfg loom`.
+//!
+//! [`UnsafeCell`] follows the closure-based API of loom, since that's the only way loom can
+//! track accesses to it. Outside of loom, it's a thin wrapper around the one of [`std`].
+
+#[cfg(loom)]
+pub(crate) use loom::{
+    cell::UnsafeCell,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        Arc,
+    },
+};
+#[cfg(not(loom))]
+pub(crate) use std::sync::{
+    atomic::{AtomicPtr, Ordering},
+    Arc,
+};
+
+#[cfg(not(loom))]
+pub(crate) struct UnsafeCell<T>(std::cell::UnsafeCell<T>);
+
+#[cfg(not(loom))]
+impl<T> UnsafeCell<T> {
+    pub(crate) fn new(data: T) -> Self {
+        Self(std::cell::UnsafeCell::new(data))
+    }
+
+    pub(crate) fn with<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+        f(self.0.get())
+    }
+
+    pub(crate) fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+        f(self.0.get())
+    }
+}
@@ -0,0 +1,73 @@
+//! Random reordering and sampling, behind the `rand` feature.
+
+use ::rand::{Rng, RngExt};
+use allocator_api2::alloc::Allocator;
+
+use crate::{cursor, Pointer, ReversibleList};
+
+impl<T, A: Allocator> ReversibleList<T, A> {
+    /// Puts all elements into a uniformly random order, drawing from `rng`.
+    ///
+    /// The nodes are shuffled through a temporary buffer of pointers and then relinked, so
+    /// the elements themselves are never moved and all handles stay valid. Takes _O_(_n_)
+    /// time and one pointer of extra memory per element.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mut nodes: Vec<Pointer<T>> = Vec::with_capacity(self.len);
+        let mut current = self.start;
+        while let Some(node) = current {
+            nodes.push(node);
+            // SAFETY: All nodes reachable from the start are owned by this list.
+            current = unsafe { (*node.as_ptr()).next };
+        }
+
+        // Fisher-Yates, from the back
+        for i in (1..nodes.len()).rev() {
+            nodes.swap(i, rng.random_range(0..=i));
+        }
+
+        let mut prev = None;
+        for &node in &nodes {
+            // SAFETY: Every node is owned by this list, and all of them are relinked here, so
+            //         the chain is complete again afterwards.
+            unsafe {
+                (*node.as_ptr()).prev = prev;
+                match prev {
+                    Some(prev) => (*prev.as_ptr()).next = Some(node),
+                    None => self.start = Some(node),
+                }
+            }
+            prev = Some(node);
+        }
+        if let Some(end) = prev {
+            // SAFETY: Same as above.
+            unsafe { (*end.as_ptr()).next = None };
+            self.end = Some(end);
+        }
+        self.structure_changed();
+    }
+
+    /// Picks a uniformly random element, drawing from `rng`, and returns a cursor pointing at
+    /// it. Returns `None` if the list is empty.
+    ///
+    /// Walks from whichever end is closer, just like [`Self::cursor_at`], so this takes
+    /// _O_(_n_) in the worst case.
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<cursor::Cursor<'_, T, A>> {
+        let idx = self.random_index(rng)?;
+        Some(self.cursor_at(idx))
+    }
+
+    /// Like [`Self::choose`], but returns a mutable cursor.
+    pub fn choose_mut<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+    ) -> Option<cursor::CursorMut<'_, T, A>> {
+        let idx = self.random_index(rng)?;
+        let node = self.node_at(idx);
+        // SAFETY: `node_at` returns the node at exactly that index.
+        Some(unsafe { cursor::CursorMut::new_at(self, node, idx) })
+    }
+
+    fn random_index<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<usize> {
+        (self.len > 0).then(|| rng.random_range(0..self.len))
+    }
+}
@@ -0,0 +1,195 @@
+//! Parallel iteration using [`rayon`](::rayon), behind the `rayon` feature.
+//!
+//! Splitting a linked list in half requires walking to its middle, so each split costs
+//! _O_(_n_) in the length of the split part. This is still dwarfed by the actual per-element
+//! work in most cases where parallelizing is worth it, and there's no need to collect into a
+//! `Vec` first.
+
+use std::{fmt, marker::PhantomData};
+
+use ::rayon::iter::{
+    plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer},
+    IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
+};
+use allocator_api2::alloc::Allocator;
+
+use crate::{iter, MaybePointer, ReversibleList};
+
+impl<T: Sync, A: Allocator> ReversibleList<T, A> {
+    /// Returns a parallel iterator through this list.
+    pub fn par_iter(&self) -> ParIter<'_, T> {
+        ParIter {
+            range: Range {
+                first: self.start,
+                last: self.end,
+                len: self.len,
+                _bound_to_list: PhantomData,
+            },
+        }
+    }
+}
+
+impl<'list, T: Sync + 'list, A: Allocator> IntoParallelIterator for &'list ReversibleList<T, A> {
+    type Iter = ParIter<'list, T>;
+    type Item = &'list T;
+
+    fn into_par_iter(self) -> ParIter<'list, T> {
+        self.par_iter()
+    }
+}
+
+/// Parallel iterator through a [`ReversibleList`].
+pub struct ParIter<'list, T> {
+    range: Range<'list, T>,
+}
+
+impl<T> Clone for ParIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { range: self.range }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ParIter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ParIter")
+            .field(&iter::Remaining(self.range))
+            .finish()
+    }
+}
+
+impl<'list, T: Sync + 'list> ParallelIterator for ParIter<'list, T> {
+    type Item = &'list T;
+
+    fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.range.len)
+    }
+}
+
+impl<'list, T: Sync + 'list> IndexedParallelIterator for ParIter<'list, T> {
+    fn len(&self) -> usize {
+        self.range.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(self.range)
+    }
+}
+
+/// A contiguous range of `len` nodes from `first` up to and including `last`, borrowed from a
+/// list for `'list`. Acts both as rayon producer and as its sequential iterator.
+struct Range<'list, T> {
+    first: MaybePointer<T>,
+    last: MaybePointer<T>,
+    len: usize,
+    _bound_to_list: PhantomData<&'list T>,
+}
+
+// SAFETY: The range only hands out shared references to the data, so it can be sent to other
+//         threads as long as these references can be shared, in effect `T: Sync`.
+unsafe impl<T: Sync> Send for Range<'_, T> {}
+
+impl<'list, T: Sync + 'list> Producer for Range<'list, T> {
+    type Item = &'list T;
+    type IntoIter = Self;
+
+    fn into_iter(self) -> Self {
+        self
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        if index == 0 {
+            return (Self { len: 0, ..self }, self);
+        }
+        if index == self.len {
+            return (self, Self { len: 0, ..self });
+        }
+
+        // walk from whichever end is closer to the split point
+        // SAFETY: The range is borrowed from a valid list and `0 < index < len`, so all nodes
+        //         walked over are part of the range.
+        let left_last = unsafe {
+            if index <= self.len / 2 {
+                let mut node = self.first.unwrap_unchecked();
+                for _ in 1..index {
+                    node = node.as_ref().next.unwrap_unchecked();
+                }
+                node
+            } else {
+                let mut node = self.last.unwrap_unchecked();
+                for _ in index..self.len {
+                    node = node.as_ref().prev.unwrap_unchecked();
+                }
+                node
+            }
+        };
+        // SAFETY: Same as above, `left_last` is not the last node of the range.
+        let right_first = unsafe { left_last.as_ref().next };
+
+        (
+            Self {
+                first: self.first,
+                last: Some(left_last),
+                len: index,
+                _bound_to_list: PhantomData,
+            },
+            Self {
+                first: right_first,
+                last: self.last,
+                len: self.len - index,
+                _bound_to_list: PhantomData,
+            },
+        )
+    }
+}
+
+impl<T> Clone for Range<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Range<'_, T> {}
+
+impl<'list, T: 'list> Iterator for Range<'list, T> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: There are `len` valid nodes left between `first` and `last`.
+        let node = unsafe { self.first?.as_ref() };
+        self.first = node.next;
+        self.len -= 1;
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'list, T: 'list> DoubleEndedIterator for Range<'list, T> {
+    fn next_back(&mut self) -> Option<&'list T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: Same as in `next`.
+        let node = unsafe { self.last?.as_ref() };
+        self.last = node.prev;
+        self.len -= 1;
+        Some(&node.data)
+    }
+}
+
+impl<'list, T: 'list> ExactSizeIterator for Range<'list, T> {}
@@ -0,0 +1,133 @@
+//! A borrowed view presenting a list back to front, without touching it.
+//!
+//! [`ReversibleList::reversed`] hands out a [`Reversed`], which is `Copy` and just as cheap as
+//! a reference. Everything on it mirrors the list: index 0 is its last element, iterating
+//! starts at the back, and a [`RevCursor`] moving "next" walks towards the front of the list.
+//! Call sites which don't care keep seeing the list in its usual order.
+
+use std::{fmt, iter::Rev};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::{cursor::Cursor, iter, ReversibleList};
+
+/// Read-only view of a list in opposite order, see the module docs.
+pub struct Reversed<'list, T, A: Allocator = Global> {
+    list: &'list ReversibleList<T, A>,
+}
+
+impl<T, A: Allocator> Clone for Reversed<'_, T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, A: Allocator> Copy for Reversed<'_, T, A> {}
+
+impl<'list, T, A: Allocator> Reversed<'list, T, A> {
+    pub(crate) fn new(list: &'list ReversibleList<T, A>) -> Self {
+        Self { list }
+    }
+
+    /// Returns the list in its usual order again.
+    #[must_use]
+    pub fn rev(self) -> &'list ReversibleList<T, A> {
+        self.list
+    }
+
+    #[must_use]
+    pub fn len(self) -> usize {
+        self.list.len
+    }
+
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self.list.len == 0
+    }
+
+    /// Returns an iterator from the last element of the list to the first.
+    pub fn iter(self) -> Rev<iter::Iter<'list, T>> {
+        self.list.iter().rev()
+    }
+
+    /// Returns the element at the given index counted from the back of the list, or `None` if
+    /// it's out of bounds. Walks just like [`ReversibleList::get`].
+    #[must_use]
+    pub fn get(self, idx: usize) -> Option<&'list T> {
+        let idx = self.list.len.checked_sub(idx)?.checked_sub(1)?;
+        self.list.get(idx)
+    }
+
+    /// Creates a cursor pointing at the first element of this view, which is the last one of
+    /// the list.
+    pub fn cursor_front(self) -> RevCursor<'list, T, A> {
+        RevCursor(self.list.cursor_back())
+    }
+
+    /// Creates a cursor pointing at the last element of this view, which is the first one of
+    /// the list.
+    pub fn cursor_back(self) -> RevCursor<'list, T, A> {
+        RevCursor(self.list.cursor_front())
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Reversed<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'list, T, A: Allocator> IntoIterator for Reversed<'list, T, A> {
+    type Item = &'list T;
+    type IntoIter = Rev<iter::Iter<'list, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Cursor into a [`Reversed`] view, with directions and indices flipped compared to a
+/// [`Cursor`] on the list itself. Still wraps around at the ends.
+pub struct RevCursor<'list, T, A: Allocator = Global>(Cursor<'list, T, A>);
+
+impl<T, A: Allocator> Clone for RevCursor<'_, T, A> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'list, T, A: Allocator> RevCursor<'list, T, A> {
+    /// Returns the data stored on the current node, or `None` if the list is empty.
+    pub fn current(&self) -> Option<&T> {
+        self.0.current()
+    }
+
+    /// Returns the index of the current node within the view, or `None` if the list is empty.
+    pub fn index(&self) -> Option<usize> {
+        self.0.index().map(|_| self.0.remaining_len())
+    }
+
+    /// Moves towards the back of the view, so the front of the list.
+    pub fn move_next(&mut self) {
+        self.0.move_prev();
+    }
+
+    /// Moves towards the front of the view, so the back of the list.
+    pub fn move_prev(&mut self) {
+        self.0.move_next();
+    }
+
+    /// Returns the underlying cursor, with the list's own directions and indices.
+    pub fn into_inner(self) -> Cursor<'list, T, A> {
+        self.0
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for RevCursor<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RevCursor")
+            .field("index", &self.index())
+            .field("current", &self.current())
+            .finish()
+    }
+}
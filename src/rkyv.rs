@@ -0,0 +1,51 @@
+//! [`rkyv`](::rkyv) support, behind the `rkyv` feature.
+//!
+//! Lists are archived as a flat [`ArchivedVec`] in iteration order, so the archived form can be
+//! viewed directly without deserializing anything, e.g. using `rkyv::access`. Deserializing
+//! rebuilds the list node by node.
+
+use ::rkyv::{
+    rancor::Fallible,
+    ser::{Allocator as SerAllocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, Place, Serialize,
+};
+use allocator_api2::alloc::Allocator;
+
+use crate::ReversibleList;
+
+impl<T: Archive, A: Allocator> Archive for ReversibleList<T, A> {
+    type Archived = ArchivedVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_len(self.len, resolver, out);
+    }
+}
+
+impl<T, A, S> Serialize<S> for ReversibleList<T, A>
+where
+    T: Serialize<S>,
+    A: Allocator,
+    S: Fallible + SerAllocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<T::Archived>::serialize_from_iter::<T, _, _>(self.iter(), serializer)
+    }
+}
+
+impl<T, A, D> Deserialize<ReversibleList<T, A>, D> for ArchivedVec<T::Archived>
+where
+    T: Archive,
+    T::Archived: Deserialize<T, D>,
+    A: Allocator + Default,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<ReversibleList<T, A>, D::Error> {
+        let mut list = ReversibleList::new_in(A::default());
+        for item in self.iter() {
+            list.push_back(item.deserialize(deserializer)?);
+        }
+        Ok(list)
+    }
+}
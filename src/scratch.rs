@@ -0,0 +1,115 @@
+//! Short-lived lists whose nodes live in caller-provided storage.
+//!
+//! Inside hot loops, a list which is built up and thrown away again on every iteration would
+//! hit the allocator over and over. Instead, [`ReversibleList::new_scratch`] builds a list over
+//! a borrowed slice of [`ScratchSlot`]s, which usually lives on the stack:
+//!
+//! ```
+//! use casual_linked_list::{scratch::ScratchSlot, ReversibleList};
+//!
+//! let mut storage = [ScratchSlot::UNINIT; 8];
+//! let mut scratch = ReversibleList::new_scratch(&mut storage);
+//! scratch.try_push_back(1).unwrap();
+//! scratch.try_push_front(0).unwrap();
+//! assert_eq!(scratch, [0, 1]);
+//! ```
+//!
+//! Each slot holds exactly one node, and slots of removed nodes are reused. Once all of them
+//! are taken, the `try_` insertion methods like [`ReversibleList::try_push_back`] return an
+//! error, while the infallible ones abort just like on any other allocation failure. Apart from
+//! that, a scratch list is a normal [`ReversibleList`] with a [`ScratchAlloc`] as its allocator.
+
+use std::{
+    cell::Cell,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ptr::{self, NonNull},
+};
+
+use allocator_api2::alloc::{AllocError, Allocator, Layout};
+
+use crate::{Node, ReversibleList};
+
+/// Storage for one node of a scratch list, see the module docs.
+pub struct ScratchSlot<T>(MaybeUninit<Node<T>>);
+
+impl<T> ScratchSlot<T> {
+    /// An unused slot, for filling arrays like `[ScratchSlot::UNINIT; 8]`.
+    pub const UNINIT: Self = Self(MaybeUninit::uninit());
+}
+
+/// Allocator handing out the slots of a borrowed slice of [`ScratchSlot`]s, one node each.
+/// Created by [`ReversibleList::new_scratch`].
+pub struct ScratchAlloc<'storage, T> {
+    slots: NonNull<ScratchSlot<T>>,
+    len: usize,
+    /// How many slots have been handed out at least once. All slots after them are untouched.
+    used: Cell<usize>,
+    /// Chain of deallocated slots, linked through their first bytes.
+    free: Cell<Option<NonNull<ScratchSlot<T>>>>,
+    _storage: PhantomData<&'storage mut [ScratchSlot<T>]>,
+}
+
+impl<'storage, T> ScratchAlloc<'storage, T> {
+    fn new(storage: &'storage mut [ScratchSlot<T>]) -> Self {
+        Self {
+            len: storage.len(),
+            // SAFETY: Slices never start at null.
+            slots: unsafe { NonNull::new_unchecked(storage.as_mut_ptr()) },
+            used: Cell::new(0),
+            free: Cell::new(None),
+            _storage: PhantomData,
+        }
+    }
+
+    /// Returns how many slots the storage has, so how many nodes can be allocated at once.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.len
+    }
+}
+
+// SAFETY: Every slot is handed out at most once until it's deallocated again, and the storage
+//         stays borrowed for as long as the allocator lives, which can't be cloned.
+unsafe impl<T> Allocator for ScratchAlloc<'_, T> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout != Layout::new::<Node<T>>() {
+            return Err(AllocError);
+        }
+
+        let slot = match self.free.get() {
+            Some(slot) => {
+                // SAFETY: Deallocated slots have the next free one written to their start,
+                //         which fits since a node holds two pointers.
+                self.free
+                    .set(unsafe { slot.cast::<Option<NonNull<ScratchSlot<T>>>>().read() });
+                slot
+            }
+            None if self.used.get() < self.len => {
+                let used = self.used.get();
+                self.used.set(used + 1);
+                // SAFETY: `used` is within the storage.
+                unsafe { self.slots.add(used) }
+            }
+            None => return Err(AllocError),
+        };
+        Ok(NonNull::slice_from_raw_parts(slot.cast(), layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, _layout: Layout) {
+        let slot = ptr.cast::<ScratchSlot<T>>();
+        // SAFETY: The caller guarantees that `ptr` was handed out by this allocator, so it's a
+        //         slot of the storage which nobody uses anymore.
+        unsafe { ptr::write(slot.cast().as_ptr(), self.free.get()) };
+        self.free.set(Some(slot));
+    }
+}
+
+impl<'storage, T> ReversibleList<T, ScratchAlloc<'storage, T>> {
+    /// Creates an empty list which keeps its nodes in the given storage instead of allocating
+    /// them, see the [module docs](crate::scratch).
+    #[must_use]
+    pub fn new_scratch(storage: &'storage mut [ScratchSlot<T>]) -> Self {
+        Self::new_in(ScratchAlloc::new(storage))
+    }
+}
@@ -0,0 +1,49 @@
+//! [`Serialize`] and [`Deserialize`] implementations, behind the `serde` feature.
+//!
+//! Lists are serialized as plain sequences in iteration order, and deserialized by pushing
+//! each element to the back as soon as it arrives, without collecting into a `Vec` first.
+
+use std::{fmt, marker::PhantomData};
+
+use ::serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use allocator_api2::alloc::Allocator;
+
+use crate::ReversibleList;
+
+impl<T: Serialize, A: Allocator> Serialize for ReversibleList<T, A> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for item in self.iter() {
+            seq.serialize_element(item)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, A: Allocator + Default> Deserialize<'de> for ReversibleList<T, A> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+struct ListVisitor<T, A>(PhantomData<(T, A)>);
+
+impl<'de, T: Deserialize<'de>, A: Allocator + Default> Visitor<'de> for ListVisitor<T, A> {
+    type Value = ReversibleList<T, A>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence")
+    }
+
+    fn visit_seq<S: SeqAccess<'de>>(self, mut seq: S) -> Result<Self::Value, S::Error> {
+        let mut list = ReversibleList::new_in(A::default());
+        while let Some(item) = seq.next_element()? {
+            list.push_back(item);
+        }
+        Ok(list)
+    }
+}
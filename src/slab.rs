@@ -0,0 +1,86 @@
+//! A doubly linked list keeping all nodes in one `Vec`, linked by `u32` indices.
+//!
+//! A [`SlabList`] offers the everyday API of [`ReversibleList`](crate::ReversibleList), but its
+//! nodes sit next to each other in one growable slab instead of being allocated one by one.
+//! Walking the list touches far fewer cache lines, and links take half the space of pointers on
+//! 64-bit targets. It's the same [`SlotList`] as a
+//! [`StaticReversibleList`](crate::fixed::StaticReversibleList), just with slots which grow
+//! as needed.
+//!
+//! It's a separate type rather than a storage mode of `ReversibleList`, and only covers the
+//! everyday subset of its API: there's no appending, splitting, sorting or retaining, and no
+//! cursor can link in nodes taken from another list. Use it where that subset is enough.
+//!
+//! Handles are checked in _O_(1) by a generation counter on each slot, so unlike
+//! [`NodeHandle`](crate::handle::NodeHandle)s, they can't dangle. In exchange, nodes can't
+//! be moved between lists without copying, and there's no custom allocator.
+//!
+//! A slab list holds at most `u32::MAX` elements.
+
+use crate::slots::{sealed::Slots, Link, Slot, SlotList, NONE};
+
+pub use crate::slots::{Iter, IterMut, SlotHandle as SlabHandle};
+
+/// A doubly linked list storing its nodes in one slab.
+///
+/// See the module docs for details.
+pub type SlabList<T> = SlotList<T, Vec<Slot<T>>>;
+
+/// Owning iterator through a [`SlabList`].
+pub type IntoIter<T> = crate::slots::IntoIter<T, Vec<Slot<T>>>;
+
+/// Immutable cursor into a [`SlabList`], like [`crate::cursor::Cursor`].
+pub type Cursor<'list, T> = crate::slots::Cursor<'list, T, Vec<Slot<T>>>;
+
+/// Mutable cursor into a [`SlabList`], like [`crate::cursor::CursorMut`].
+pub type CursorMut<'list, T> = crate::slots::CursorMut<'list, T, Vec<Slot<T>>>;
+
+impl<T> Slots<T> for Vec<Slot<T>> {
+    fn slots(&self) -> &[Slot<T>] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [Slot<T>] {
+        self
+    }
+
+    fn grow(&mut self) -> Option<Link> {
+        let node = Link::try_from(self.len())
+            .ok()
+            .filter(|&node| node != NONE)?;
+        self.push(Slot::FRESH);
+        Some(node)
+    }
+
+    fn max_len(&self) -> usize {
+        NONE as usize
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional);
+    }
+}
+
+impl<T> SlotList<T, Vec<Slot<T>>> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty list with room for at least `capacity` elements before reallocating.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_storage(Vec::with_capacity(capacity))
+    }
+
+    /// Returns how many elements the list can hold without reallocating.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Reserves room for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        self.reserve_slots(additional);
+    }
+}
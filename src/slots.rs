@@ -0,0 +1,848 @@
+//! Doubly linked lists keeping all nodes in the slots of one storage, linked by `u32` indices.
+//!
+//! A [`SlotList`] is the implementation shared by [`StaticReversibleList`], whose slots are an
+//! array inside the list itself, and the `SlabList` of the `slab` feature, whose slots are one
+//! growable `Vec`. Either way, nodes sit next to each other instead of being allocated one by
+//! one, links take half the space of pointers on 64-bit targets, and removed nodes are reused
+//! by later insertions.
+//!
+//! Handles are checked in _O_(1) by a generation counter on each slot, so unlike
+//! [`NodeHandle`](crate::handle::NodeHandle)s, they can't dangle. In exchange, nodes can't
+//! be moved between lists without copying.
+//!
+//! [`StaticReversibleList`]: crate::fixed::StaticReversibleList
+
+use std::{fmt, iter::FusedIterator, marker::PhantomData};
+
+use crate::TryInsertError;
+
+/// Index of a node in the slots.
+pub(crate) type Link = u32;
+
+pub(crate) const NONE: Link = Link::MAX;
+
+/// One node of a [`SlotList`], or a free spot for one.
+pub struct Slot<T> {
+    /// `None` exactly for free slots.
+    data: Option<T>,
+    prev: Link,
+    /// The next node for live slots, and the next free slot for free ones.
+    next: Link,
+    /// Bumped whenever the slot is freed, so handles to the previous node stop matching.
+    generation: u32,
+}
+
+impl<T> Slot<T> {
+    pub(crate) const FRESH: Self = Self {
+        data: None,
+        prev: NONE,
+        next: NONE,
+        generation: 0,
+    };
+}
+
+pub(crate) mod sealed {
+    use super::{Link, Slot};
+
+    pub trait Slots<T> {
+        /// Returns all slots which have ever been used.
+        fn slots(&self) -> &[Slot<T>];
+
+        fn slots_mut(&mut self) -> &mut [Slot<T>];
+
+        /// Appends a fresh slot and returns its index, or `None` if there's no room left.
+        fn grow(&mut self) -> Option<Link>;
+
+        /// Returns how many slots there can be at most.
+        fn max_len(&self) -> usize;
+
+        /// Makes room for at least `additional` more fresh slots, if the storage can grow.
+        fn reserve(&mut self, _additional: usize) {}
+    }
+}
+
+/// Where a [`SlotList`] keeps its slots. Implemented by `Vec<Slot<T>>` for a `SlabList`, and
+/// by [`ArraySlots`](crate::fixed::ArraySlots) for a
+/// [`StaticReversibleList`](crate::fixed::StaticReversibleList).
+///
+/// This trait is sealed.
+pub trait Storage<T>: sealed::Slots<T> {}
+
+impl<T, S: sealed::Slots<T>> Storage<T> for S {}
+
+/// A reference to an element of a [`SlotList`], which stays valid until exactly that element
+/// is removed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotHandle {
+    index: Link,
+    generation: u32,
+}
+
+/// A doubly linked list storing its nodes in the slots of `S`.
+///
+/// See the module docs for details.
+pub struct SlotList<T, S> {
+    pub(crate) slots: S,
+    start: Link,
+    end: Link,
+    free: Link,
+    len: usize,
+    _items: PhantomData<T>,
+}
+
+impl<T, S> SlotList<T, S> {
+    pub(crate) const fn with_storage(slots: S) -> Self {
+        Self {
+            slots,
+            start: NONE,
+            end: NONE,
+            free: NONE,
+            len: 0,
+            _items: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T, S: Storage<T>> SlotList<T, S> {
+    /// Removes all elements, keeping the slots around for reuse.
+    pub fn clear(&mut self) {
+        // going through `unlink` bumps every generation, so no old handle matches a new node
+        while self.pop_front().is_some() {}
+    }
+
+    /// Returns an iterator through this list.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            slots: self.slots.slots(),
+            forward: self.start,
+            backward: self.end,
+            remaining: self.len,
+        }
+    }
+
+    /// Returns an iterator through this list, yielding mutable references.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            slots: self.slots.slots_mut().as_mut_ptr(),
+            forward: self.start,
+            backward: self.end,
+            remaining: self.len,
+            _list: PhantomData,
+        }
+    }
+
+    /// Returns the element at the given index, or `None` if it's out of bounds. Walks from
+    /// the closer end, so this takes _O_(_n_).
+    #[must_use]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        (idx < self.len).then(|| self.data(self.node_at(idx)))
+    }
+
+    /// Returns the element at the given index mutably, or `None` if it's out of bounds.
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        if self.len <= idx {
+            return None;
+        }
+        let node = self.node_at(idx);
+        self.slot_mut(node).data.as_mut()
+    }
+
+    /// Inserts the given item before the first element of the list, in _O_(1). Returns a
+    /// [`SlotHandle`] to the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    pub fn push_front(&mut self, item: T) -> SlotHandle {
+        self.try_push_front(item)
+            .unwrap_or_else(|error| self.full(error))
+    }
+
+    /// Like [`Self::push_front`], but returns an error holding `item` if the list is full.
+    pub fn try_push_front(&mut self, item: T) -> Result<SlotHandle, TryInsertError<T>> {
+        let node = self.link_between(NONE, self.start, item)?;
+        Ok(self.handle_for(node))
+    }
+
+    /// Appends the given item to the end of the list, in _O_(1). Returns a [`SlotHandle`] to
+    /// the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    pub fn push_back(&mut self, item: T) -> SlotHandle {
+        self.try_push_back(item)
+            .unwrap_or_else(|error| self.full(error))
+    }
+
+    /// Like [`Self::push_back`], but returns an error holding `item` if the list is full.
+    pub fn try_push_back(&mut self, item: T) -> Result<SlotHandle, TryInsertError<T>> {
+        let node = self.link_between(self.end, NONE, item)?;
+        Ok(self.handle_for(node))
+    }
+
+    /// Inserts the given item at the given index, shifting all elements after it one position
+    /// back. Returns a [`SlotHandle`] to the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list, or if the list is full.
+    pub fn insert(&mut self, idx: usize, item: T) -> SlotHandle {
+        self.try_insert(idx, item)
+            .unwrap_or_else(|error| self.full(error))
+    }
+
+    /// Like [`Self::insert`], but returns an error holding `item` if the list is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    pub fn try_insert(&mut self, idx: usize, item: T) -> Result<SlotHandle, TryInsertError<T>> {
+        assert!(
+            idx <= self.len,
+            "tried to insert at index {idx} but the len is {}",
+            self.len
+        );
+        let node = if idx == self.len {
+            self.link_between(self.end, NONE, item)?
+        } else {
+            let next = self.node_at(idx);
+            self.link_between(self.slot(next).prev, next, item)?
+        };
+        Ok(self.handle_for(node))
+    }
+
+    /// Removes the element at the beginning of the list, in _O_(1).
+    pub fn pop_front(&mut self) -> Option<T> {
+        (self.start != NONE).then(|| self.unlink(self.start))
+    }
+
+    /// Removes the element at the end of the list, in _O_(1).
+    pub fn pop_back(&mut self) -> Option<T> {
+        (self.end != NONE).then(|| self.unlink(self.end))
+    }
+
+    /// Returns if the given handle points at an element which is still in this list.
+    #[must_use]
+    pub fn contains_handle(&self, handle: SlotHandle) -> bool {
+        self.slots
+            .slots()
+            .get(handle.index as usize)
+            .is_some_and(|slot| slot.generation == handle.generation && slot.data.is_some())
+    }
+
+    /// Returns the element the given handle points at, or `None` if it has been removed.
+    #[must_use]
+    pub fn try_get_by_handle(&self, handle: SlotHandle) -> Option<&T> {
+        self.contains_handle(handle)
+            .then(|| self.data(handle.index))
+    }
+
+    /// Returns the element the given handle points at mutably, or `None` if it has been
+    /// removed.
+    pub fn try_get_by_handle_mut(&mut self, handle: SlotHandle) -> Option<&mut T> {
+        if !self.contains_handle(handle) {
+            return None;
+        }
+        self.slot_mut(handle.index).data.as_mut()
+    }
+
+    /// Removes the element the given handle points at in _O_(1) and returns it, or `None` if
+    /// it has been removed already.
+    pub fn try_remove_by_handle(&mut self, handle: SlotHandle) -> Option<T> {
+        self.contains_handle(handle)
+            .then(|| self.unlink(handle.index))
+    }
+
+    pub fn cursor_front(&self) -> Cursor<'_, T, S> {
+        Cursor {
+            node: self.start,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_back(&self) -> Cursor<'_, T, S> {
+        Cursor {
+            node: self.end,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    /// Creates a cursor pointing at the element with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn cursor_at(&self, idx: usize) -> Cursor<'_, T, S> {
+        Cursor {
+            node: self.node_at(idx),
+            index: idx,
+            list: self,
+        }
+    }
+
+    pub fn cursor_mut_front(&mut self) -> CursorMut<'_, T, S> {
+        CursorMut {
+            node: self.start,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_mut_back(&mut self) -> CursorMut<'_, T, S> {
+        CursorMut {
+            node: self.end,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    /// Makes room for at least `additional` more elements, on top of the free slots which
+    /// are reused anyway.
+    pub(crate) fn reserve_slots(&mut self, additional: usize) {
+        let reusable = self.slots.slots().len() - self.len;
+        self.slots.reserve(additional.saturating_sub(reusable));
+    }
+
+    fn full<R>(&self, _: TryInsertError<T>) -> R {
+        panic!(
+            "tried to insert into a full list, which holds at most {} elements",
+            self.slots.max_len()
+        );
+    }
+
+    fn slot(&self, node: Link) -> &Slot<T> {
+        &self.slots.slots()[node as usize]
+    }
+
+    fn slot_mut(&mut self, node: Link) -> &mut Slot<T> {
+        &mut self.slots.slots_mut()[node as usize]
+    }
+
+    fn data(&self, node: Link) -> &T {
+        self.slot(node)
+            .data
+            .as_ref()
+            .expect("linked slots are live")
+    }
+
+    fn handle_for(&self, node: Link) -> SlotHandle {
+        SlotHandle {
+            index: node,
+            generation: self.slot(node).generation,
+        }
+    }
+
+    /// Returns the node at the given index, walking from the closer end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds.
+    fn node_at(&self, idx: usize) -> Link {
+        assert!(
+            idx < self.len,
+            "tried to move to index {idx} but the len is {}",
+            self.len
+        );
+        if idx < self.len / 2 {
+            (0..idx).fold(self.start, |node, _| self.slot(node).next)
+        } else {
+            (idx..self.len - 1).fold(self.end, |node, _| self.slot(node).prev)
+        }
+    }
+
+    /// Puts the given item into a free slot between the given neighbours, which must be
+    /// adjacent, and returns the new node. Hands the item back if there's no free slot.
+    fn link_between(&mut self, prev: Link, next: Link, item: T) -> Result<Link, TryInsertError<T>> {
+        let node = match self.free {
+            NONE => match self.slots.grow() {
+                Some(node) => node,
+                None => return Err(TryInsertError(item)),
+            },
+            free => {
+                self.free = self.slot(free).next;
+                free
+            }
+        };
+
+        let slot = self.slot_mut(node);
+        slot.data = Some(item);
+        slot.prev = prev;
+        slot.next = next;
+
+        match prev {
+            NONE => self.start = node,
+            prev => self.slot_mut(prev).next = node,
+        }
+        match next {
+            NONE => self.end = node,
+            next => self.slot_mut(next).prev = node,
+        }
+        self.len += 1;
+        Ok(node)
+    }
+
+    /// Unlinks the given live node, frees its slot and returns its data.
+    fn unlink(&mut self, node: Link) -> T {
+        let free = self.free;
+        let slot = self.slot_mut(node);
+        let (prev, next) = (slot.prev, slot.next);
+        let data = slot.data.take().expect("linked slots are live");
+        slot.generation = slot.generation.wrapping_add(1);
+        slot.next = free;
+        self.free = node;
+
+        match prev {
+            NONE => self.start = next,
+            prev => self.slot_mut(prev).next = next,
+        }
+        match next {
+            NONE => self.end = prev,
+            next => self.slot_mut(next).prev = prev,
+        }
+        self.len -= 1;
+        data
+    }
+}
+
+impl<T: Clone, S: Storage<T> + Default> Clone for SlotList<T, S> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, S: Storage<T>> fmt::Debug for SlotList<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, S: Default> Default for SlotList<T, S> {
+    fn default() -> Self {
+        Self::with_storage(S::default())
+    }
+}
+
+impl<T, S: Storage<T>> Extend<T> for SlotList<T, S> {
+    /// Appends the items one by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list runs full.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve_slots(iter.size_hint().0);
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T, S: Storage<T> + Default> FromIterator<T> for SlotList<T, S> {
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more items than the list can hold.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::default();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, S: Storage<T>> IntoIterator for SlotList<T, S> {
+    type Item = T;
+    type IntoIter = IntoIter<T, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+impl<'list, T, S: Storage<T>> IntoIterator for &'list SlotList<T, S> {
+    type Item = &'list T;
+    type IntoIter = Iter<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'list, T, S: Storage<T>> IntoIterator for &'list mut SlotList<T, S> {
+    type Item = &'list mut T;
+    type IntoIter = IterMut<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: PartialEq, S: Storage<T>> PartialEq for SlotList<T, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, S: Storage<T>> Eq for SlotList<T, S> {}
+
+/// Iterator through a [`SlotList`].
+pub struct Iter<'list, T> {
+    slots: &'list [Slot<T>],
+    forward: Link,
+    backward: Link,
+    remaining: usize,
+}
+
+impl<'list, T> Iter<'list, T> {
+    /// Returns the data of the given live node, and the next node in the given direction.
+    fn visit(&self, node: Link) -> (&'list T, Link, Link) {
+        let slot = &self.slots[node as usize];
+        let data = slot.data.as_ref().expect("linked slots are live");
+        (data, slot.prev, slot.next)
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots,
+            forward: self.forward,
+            backward: self.backward,
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&crate::iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'list, T> Iterator for Iter<'list, T> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let (data, _, next) = self.visit(self.forward);
+        self.forward = next;
+        Some(data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'list, T> DoubleEndedIterator for Iter<'list, T> {
+    fn next_back(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let (data, prev, _) = self.visit(self.backward);
+        self.backward = prev;
+        Some(data)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// Iterator through a [`SlotList`], yielding mutable references.
+///
+/// Handing out mutable references in list order can't go through the slots themselves without
+/// allocating a lookup table, so this walks them through a raw pointer instead.
+pub struct IterMut<'list, T> {
+    slots: *mut Slot<T>,
+    forward: Link,
+    backward: Link,
+    remaining: usize,
+    _list: PhantomData<&'list mut T>,
+}
+
+// SAFETY: The iterator only hands out mutable references, just like `&mut [T]`'s does.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'list, T> IterMut<'list, T> {
+    /// Returns the data of the given node, and the next node in the given direction.
+    ///
+    /// # Safety
+    ///
+    /// `node` must be a live node of the list, which hasn't been yielded yet.
+    unsafe fn visit(&mut self, node: Link) -> (&'list mut T, Link, Link) {
+        // SAFETY: The node is within the slots and live, and since every node is yielded at
+        //         most once, no other reference to its data exists. The links are only read.
+        let slot = unsafe { &mut *self.slots.add(node as usize) };
+        let data = slot.data.as_mut().expect("linked slots are live");
+        (data, slot.prev, slot.next)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IterMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterMut")
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'list, T> Iterator for IterMut<'list, T> {
+    type Item = &'list mut T;
+
+    fn next(&mut self) -> Option<&'list mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: `remaining` hasn't run out, so the node is live and hasn't been yielded from
+        //         either end.
+        let (data, _, next) = unsafe { self.visit(self.forward) };
+        self.forward = next;
+        Some(data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'list, T> DoubleEndedIterator for IterMut<'list, T> {
+    fn next_back(&mut self) -> Option<&'list mut T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: Same as in `next`.
+        let (data, prev, _) = unsafe { self.visit(self.backward) };
+        self.backward = prev;
+        Some(data)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// Owning iterator through a [`SlotList`].
+pub struct IntoIter<T, S: Storage<T>> {
+    list: SlotList<T, S>,
+}
+
+impl<T: fmt::Debug, S: Storage<T>> fmt::Debug for IntoIter<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list).finish()
+    }
+}
+
+impl<T, S: Storage<T>> Iterator for IntoIter<T, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, S: Storage<T>> DoubleEndedIterator for IntoIter<T, S> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, S: Storage<T>> ExactSizeIterator for IntoIter<T, S> {}
+
+impl<T, S: Storage<T>> FusedIterator for IntoIter<T, S> {}
+
+macro_rules! impl_common_cursor {
+    ($name:ident) => {
+        impl<'list, T, S: Storage<T>> $name<'list, T, S> {
+            /// Returns the data stored on the current node, or `None` if the list is empty.
+            pub fn current(&self) -> Option<&T> {
+                (self.node != NONE).then(|| self.list.data(self.node))
+            }
+
+            /// Returns the index of the current node, or `None` if the list is empty.
+            pub fn index(&self) -> Option<usize> {
+                (self.node != NONE).then_some(self.index)
+            }
+
+            /// Returns a handle to the current node, or `None` if the list is empty.
+            pub fn handle(&self) -> Option<SlotHandle> {
+                (self.node != NONE).then(|| self.list.handle_for(self.node))
+            }
+
+            /// Makes this cursor look at the **previous** node in the list. If there is none, the
+            /// cursor will point at the **end** of the list. Does nothing if the list is empty.
+            pub fn move_prev(&mut self) {
+                if self.node == NONE {
+                    return;
+                }
+
+                match self.list.slot(self.node).prev {
+                    NONE => {
+                        self.node = self.list.end;
+                        self.index = self.list.len - 1;
+                    }
+                    prev => {
+                        self.node = prev;
+                        self.index -= 1;
+                    }
+                }
+            }
+
+            /// Makes this cursor look at the **next** node in the list. If there is none, the
+            /// cursor will point at the **beginning** of the list. Does nothing if the list is
+            /// empty.
+            pub fn move_next(&mut self) {
+                if self.node == NONE {
+                    return;
+                }
+
+                match self.list.slot(self.node).next {
+                    NONE => {
+                        self.node = self.list.start;
+                        self.index = 0;
+                    }
+                    next => {
+                        self.node = next;
+                        self.index += 1;
+                    }
+                }
+            }
+
+            /// Moves this cursor to the given index, walking from the closer end.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `target_idx` is out of bounds.
+            pub fn move_to(&mut self, target_idx: usize) {
+                self.node = self.list.node_at(target_idx);
+                self.index = target_idx;
+            }
+        }
+    };
+}
+
+/// Immutable cursor into a [`SlotList`], like [`crate::cursor::Cursor`].
+pub struct Cursor<'list, T, S: Storage<T>> {
+    node: Link,
+    index: usize,
+    list: &'list SlotList<T, S>,
+}
+
+impl_common_cursor!(Cursor);
+
+/// Mutable cursor into a [`SlotList`], like [`crate::cursor::CursorMut`].
+pub struct CursorMut<'list, T, S: Storage<T>> {
+    node: Link,
+    index: usize,
+    list: &'list mut SlotList<T, S>,
+}
+
+impl_common_cursor!(CursorMut);
+
+impl<T, S: Storage<T>> CursorMut<'_, T, S> {
+    /// Returns a mutable reference to the data stored on the current node, or `None` if the
+    /// list is empty.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        if self.node == NONE {
+            return None;
+        }
+        self.list.slot_mut(self.node).data.as_mut()
+    }
+
+    /// Inserts the given item **after** the current node, in _O_(1). Returns a
+    /// [`SlotHandle`] to the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    pub fn insert_after(&mut self, item: T) -> SlotHandle {
+        self.try_insert_after(item)
+            .unwrap_or_else(|error| self.list.full(error))
+    }
+
+    /// Like [`Self::insert_after`], but returns an error holding `item` if the list is full.
+    pub fn try_insert_after(&mut self, item: T) -> Result<SlotHandle, TryInsertError<T>> {
+        let node = if self.node == NONE {
+            self.node = self.list.link_between(NONE, NONE, item)?;
+            self.node
+        } else {
+            let next = self.list.slot(self.node).next;
+            self.list.link_between(self.node, next, item)?
+        };
+        Ok(self.list.handle_for(node))
+    }
+
+    /// Inserts the given item **before** the current node, in _O_(1). Returns a
+    /// [`SlotHandle`] to the new node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list is full.
+    pub fn insert_before(&mut self, item: T) -> SlotHandle {
+        self.try_insert_before(item)
+            .unwrap_or_else(|error| self.list.full(error))
+    }
+
+    /// Like [`Self::insert_before`], but returns an error holding `item` if the list is full.
+    pub fn try_insert_before(&mut self, item: T) -> Result<SlotHandle, TryInsertError<T>> {
+        let node = if self.node == NONE {
+            self.node = self.list.link_between(NONE, NONE, item)?;
+            self.node
+        } else {
+            let prev = self.list.slot(self.node).prev;
+            let node = self.list.link_between(prev, self.node, item)?;
+            self.index += 1;
+            node
+        };
+        Ok(self.list.handle_for(node))
+    }
+
+    /// Removes the current node and returns its data, in _O_(1). Returns `None` if the list is
+    /// empty.
+    ///
+    /// Afterwards, the cursor points at the node after the removed one, or the one before it if
+    /// there is none, just like [`crate::cursor::CursorMut::remove_current`].
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.node == NONE {
+            return None;
+        }
+
+        let Slot { prev, next, .. } = *self.list.slot(self.node);
+        let data = self.list.unlink(self.node);
+        if next != NONE {
+            self.node = next;
+        } else {
+            self.node = prev;
+            self.index = self.index.saturating_sub(1);
+        }
+        Some(data)
+    }
+}
@@ -0,0 +1,263 @@
+//! A list storing its first few elements inline, without any heap allocation.
+//!
+//! Many lists only ever hold a handful of elements, for which allocating one node per element
+//! is quite wasteful. A [`SmallList`] stores up to `N` elements inline in the list itself, and
+//! only spills over into a full [`ReversibleList`] once more elements are pushed. After having
+//! spilled, it stays a [`ReversibleList`] even if elements are removed again, until
+//! [`SmallList::shrink_to_fit`] moves them back inline.
+//!
+//! Since inline elements move along with the list, there's no cursor or handle API here, as
+//! those rely on nodes staying put. Use [`SmallList::into_list`] if you need them.
+
+use std::{fmt, iter::FusedIterator, slice};
+
+use crate::{chunk::Chunk, iter, ReversibleList};
+
+/// A list storing up to `N` elements inline before allocating.
+///
+/// See the module docs for details.
+pub struct SmallList<T, const N: usize = 2> {
+    storage: Storage<T, N>,
+}
+
+enum Storage<T, const N: usize> {
+    Inline(Chunk<T, N>),
+    Spilled(ReversibleList<T>),
+}
+
+impl<T, const N: usize> SmallList<T, N> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Storage::Inline(Chunk::new()),
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Inline(chunk) => chunk.len,
+            Storage::Spilled(list) => list.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns if the elements have been moved onto the heap already.
+    #[must_use]
+    pub fn is_spilled(&self) -> bool {
+        matches!(self.storage, Storage::Spilled(_))
+    }
+
+    /// Returns an iterator through this list.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: match &self.storage {
+                Storage::Inline(chunk) => IterInner::Inline(chunk.as_slice().iter()),
+                Storage::Spilled(list) => IterInner::Spilled(list.iter()),
+            },
+        }
+    }
+
+    /// Inserts the given item before the first element of the list, spilling onto the heap if
+    /// there's no inline space left.
+    pub fn push_front(&mut self, item: T) {
+        match self.inline_with_space() {
+            Some(chunk) => chunk.insert(0, item),
+            None => {
+                self.spill().push_front(item);
+            }
+        }
+    }
+
+    /// Appends the given item to the end of the list, spilling onto the heap if there's no
+    /// inline space left.
+    pub fn push_back(&mut self, item: T) {
+        match self.inline_with_space() {
+            Some(chunk) => chunk.insert(chunk.len, item),
+            None => {
+                self.spill().push_back(item);
+            }
+        }
+    }
+
+    /// Removes the element at the beginning of the list.
+    pub fn pop_front(&mut self) -> Option<T> {
+        match &mut self.storage {
+            Storage::Inline(chunk) => (chunk.len > 0).then(|| chunk.remove(0)),
+            Storage::Spilled(list) => list.pop_front(),
+        }
+    }
+
+    /// Removes the element at the end of the list.
+    pub fn pop_back(&mut self) -> Option<T> {
+        match &mut self.storage {
+            Storage::Inline(chunk) => (chunk.len > 0).then(|| chunk.remove(chunk.len - 1)),
+            Storage::Spilled(list) => list.pop_back(),
+        }
+    }
+
+    /// Converts this into a full [`ReversibleList`], allocating nodes for the inline elements
+    /// if it hasn't spilled yet.
+    pub fn into_list(mut self) -> ReversibleList<T> {
+        self.spill();
+        match self.storage {
+            Storage::Spilled(list) => list,
+            Storage::Inline(_) => unreachable!("storage has been spilled just now"),
+        }
+    }
+
+    /// Moves the elements back into inline storage if the list has spilled, but they would fit
+    /// inline again, deallocating their nodes.
+    pub fn shrink_to_fit(&mut self) {
+        if let Storage::Spilled(list) = &mut self.storage {
+            if list.len() <= N {
+                let mut chunk = Chunk::new();
+                while let Some(item) = list.pop_front() {
+                    chunk.insert(chunk.len, item);
+                }
+                self.storage = Storage::Inline(chunk);
+            }
+        }
+    }
+
+    /// Returns the inline storage if the list hasn't spilled yet and there's space left.
+    fn inline_with_space(&mut self) -> Option<&mut Chunk<T, N>> {
+        match &mut self.storage {
+            Storage::Inline(chunk) if !chunk.is_full() => Some(chunk),
+            _ => None,
+        }
+    }
+
+    /// Moves all inline elements onto the heap, if that hasn't happened already.
+    fn spill(&mut self) -> &mut ReversibleList<T> {
+        if let Storage::Inline(chunk) = &mut self.storage {
+            let mut list = ReversibleList::new();
+            while chunk.len > 0 {
+                list.push_back(chunk.remove(0));
+            }
+            self.storage = Storage::Spilled(list);
+        }
+
+        match &mut self.storage {
+            Storage::Spilled(list) => list,
+            Storage::Inline(_) => unreachable!("storage has been spilled just now"),
+        }
+    }
+}
+
+/// Iterator through a [`SmallList`].
+pub struct Iter<'list, T: 'list> {
+    inner: IterInner<'list, T>,
+}
+
+enum IterInner<'list, T: 'list> {
+    Inline(slice::Iter<'list, T>),
+    Spilled(iter::Iter<'list, T>),
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: match &self.inner {
+                IterInner::Inline(iter) => IterInner::Inline(iter.clone()),
+                IterInner::Spilled(iter) => IterInner::Spilled(iter.clone()),
+            },
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'list, T: 'list> Iterator for Iter<'list, T> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        match &mut self.inner {
+            IterInner::Inline(iter) => iter.next(),
+            IterInner::Spilled(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            IterInner::Inline(iter) => iter.size_hint(),
+            IterInner::Spilled(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'list, T: 'list> DoubleEndedIterator for Iter<'list, T> {
+    fn next_back(&mut self) -> Option<&'list T> {
+        match &mut self.inner {
+            IterInner::Inline(iter) => iter.next_back(),
+            IterInner::Spilled(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<'list, T: 'list> ExactSizeIterator for Iter<'list, T> {}
+
+impl<'list, T: 'list> FusedIterator for Iter<'list, T> {}
+
+impl<T: Clone, const N: usize> Clone for SmallList<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            storage: match &self.storage {
+                Storage::Inline(chunk) => Storage::Inline(chunk.clone()),
+                Storage::Spilled(list) => Storage::Spilled(list.clone()),
+            },
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SmallList<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Default for SmallList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallList<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, const N: usize> From<SmallList<T, N>> for ReversibleList<T> {
+    fn from(value: SmallList<T, N>) -> Self {
+        value.into_list()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for SmallList<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallList<T, N> {}
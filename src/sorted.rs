@@ -0,0 +1,225 @@
+//! A list which keeps its elements sorted, usable as a simple priority queue.
+//!
+//! A [`SortedList`] only lets elements in through [`insert`](SortedList::insert), which places
+//! them according to its comparator, so the smallest element is always at the front and the
+//! largest at the back. Equal elements stay in insertion order. Finding the insertion point
+//! walks the list, so inserting takes _O_(_n_), while looking at or popping the minimum and
+//! maximum is _O_(1).
+//!
+//! Reading goes through [`Deref`] to the underlying [`ReversibleList`]. There's no mutable
+//! access to single elements, since changing them could break the order.
+
+use std::{
+    cmp, fmt,
+    ops::{Bound, Deref, RangeBounds},
+};
+
+use crate::{handle, iter, ReversibleList};
+
+/// A [`ReversibleList`] kept sorted by a comparator `C`, which is `T`'s own order by default.
+///
+/// See the module docs for details.
+pub struct SortedList<T, C = fn(&T, &T) -> cmp::Ordering> {
+    list: ReversibleList<T>,
+    compare: C,
+}
+
+impl<T: Ord> SortedList<T> {
+    /// Creates an empty list sorted by `T`'s own order.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::new_by(T::cmp)
+    }
+}
+
+impl<T> SortedList<T> {
+    /// Creates an empty list sorted by the keys extracted by `f`.
+    pub fn new_by_key<K: Ord, F>(f: F) -> SortedList<T, impl Fn(&T, &T) -> cmp::Ordering>
+    where
+        F: Fn(&T) -> K,
+    {
+        SortedList::new_by(move |a: &T, b: &T| f(a).cmp(&f(b)))
+    }
+}
+
+impl<T, C> SortedList<T, C>
+where
+    C: Fn(&T, &T) -> cmp::Ordering,
+{
+    /// Creates an empty list sorted according to `compare`, which must be a total order.
+    pub fn new_by(compare: C) -> Self {
+        Self {
+            list: ReversibleList::new(),
+            compare,
+        }
+    }
+
+    /// Inserts the given item after all elements comparing less than or equal to it, in
+    /// _O_(_n_). Returns a [`handle::NodeHandle`] to the new node.
+    pub fn insert(&mut self, item: T) -> handle::NodeHandle<T> {
+        self.list.insert_sorted_by(item, &self.compare)
+    }
+
+    /// Returns the smallest element, or `None` if the list is empty.
+    #[must_use]
+    pub fn first(&self) -> Option<&T> {
+        self.list.iter().next()
+    }
+
+    /// Returns the largest element, or `None` if the list is empty.
+    #[must_use]
+    pub fn last(&self) -> Option<&T> {
+        self.list.iter().next_back()
+    }
+
+    /// Removes the smallest element and returns it. Among equal ones, that's the one inserted
+    /// first.
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Removes the largest element and returns it. Among equal ones, that's the one inserted
+    /// last.
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// Returns if an element comparing equal to `item` is in the list.
+    #[must_use]
+    pub fn contains(&self, item: &T) -> bool {
+        self.find(item).is_some()
+    }
+
+    /// Removes the first element comparing equal to `item` and returns it.
+    pub fn remove(&mut self, item: &T) -> Option<T> {
+        let idx = self.find(item)?;
+        let node = self.list.node_at(idx);
+        // SAFETY: `node_at` only returns nodes owned by this list.
+        Some(unsafe { self.list.remove(node) })
+    }
+
+    /// Returns an iterator through all elements in the given range, in order.
+    pub fn range<R: RangeBounds<T>>(
+        &self,
+        range: R,
+    ) -> std::iter::Take<std::iter::Skip<iter::Iter<'_, T>>> {
+        let (start, len) = self.span(&range);
+        self.list.iter().skip(start).take(len)
+    }
+
+    /// Removes all elements in the given range and returns them as a separate list, still
+    /// sorted. Finding the range takes _O_(_n_), the nodes themselves are only relinked.
+    pub fn drain_range<R: RangeBounds<T>>(&mut self, range: R) -> ReversibleList<T> {
+        let (start, len) = self.span(&range);
+        if len == 0 {
+            return ReversibleList::new();
+        }
+        let first = self.list.node_at(start);
+        let last = self.list.node_at(start + len - 1);
+        // SAFETY: Both are owned by this list, and `last` comes `len - 1` nodes after `first`.
+        unsafe { self.list.detach_range(first, last, len) }
+    }
+
+    /// Removes all elements, keeping the comparator.
+    pub fn clear(&mut self) {
+        self.list = ReversibleList::new();
+    }
+
+    /// Returns the underlying list.
+    pub fn into_list(self) -> ReversibleList<T> {
+        self.list
+    }
+
+    /// Returns the index of the first element comparing equal to `item`.
+    fn find(&self, item: &T) -> Option<usize> {
+        self.list
+            .iter()
+            .map(|other| (self.compare)(other, item))
+            .take_while(|ordering| ordering.is_le())
+            .position(cmp::Ordering::is_eq)
+    }
+
+    /// Returns the index of the first element in the given range and how many there are.
+    fn span<R: RangeBounds<T>>(&self, range: &R) -> (usize, usize) {
+        let below = |item: &T| match range.start_bound() {
+            Bound::Included(start) => (self.compare)(item, start).is_lt(),
+            Bound::Excluded(start) => (self.compare)(item, start).is_le(),
+            Bound::Unbounded => false,
+        };
+        let above = |item: &T| match range.end_bound() {
+            Bound::Included(end) => (self.compare)(item, end).is_gt(),
+            Bound::Excluded(end) => (self.compare)(item, end).is_ge(),
+            Bound::Unbounded => false,
+        };
+
+        let start = self.list.iter().take_while(|item| below(item)).count();
+        let len = self
+            .list
+            .iter()
+            .skip(start)
+            .take_while(|item| !above(item))
+            .count();
+        (start, len)
+    }
+}
+
+impl<T, C> Deref for SortedList<T, C> {
+    type Target = ReversibleList<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T: Clone, C: Clone> Clone for SortedList<T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, C> fmt::Debug for SortedList<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.list, f)
+    }
+}
+
+impl<T: Ord> Default for SortedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C> Extend<T> for SortedList<T, C>
+where
+    C: Fn(&T, &T) -> cmp::Ordering,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedList<T> {
+    /// Sorts all items at once, in _O_(_n_ log _n_).
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut items: Vec<T> = iter.into_iter().collect();
+        items.sort();
+        Self {
+            list: ReversibleList::from(items),
+            compare: T::cmp,
+        }
+    }
+}
+
+impl<'list, T, C> IntoIterator for &'list SortedList<T, C> {
+    type Item = &'list T;
+    type IntoIter = iter::Iter<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list.iter()
+    }
+}
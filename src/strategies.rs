@@ -0,0 +1,138 @@
+//! [`proptest`] strategies for generating lists and operations on them, behind the `proptest`
+//! feature.
+//!
+//! [`list`] generates lists built by mixed pushes to both ends, while [`ops`] generates
+//! sequences of [`Op`]s, which can be applied both to a [`ReversibleList`] and to a
+//! [`VecDeque`] as model collection. Comparing the two after each step is a quick way to
+//! property-test code built on top of the list.
+
+use std::collections::VecDeque;
+
+use proptest::{collection::SizeRange, prelude::*};
+
+use crate::ReversibleList;
+
+/// One operation on a list, see the module docs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Op<T> {
+    PushFront(T),
+    PushBack(T),
+    PopFront,
+    PopBack,
+    /// Inserts before the given index, which is taken modulo the length plus one at the time
+    /// of application.
+    Insert(usize, T),
+    /// Removes the given index, which is taken modulo the length at the time of application.
+    Remove(usize),
+}
+
+impl<T> Op<T> {
+    /// Applies this operation to the given list, returning the removed element, if any.
+    pub fn apply(self, list: &mut ReversibleList<T>) -> Option<T> {
+        match self {
+            Op::PushFront(item) => {
+                list.push_front(item);
+                None
+            }
+            Op::PushBack(item) => {
+                list.push_back(item);
+                None
+            }
+            Op::PopFront => list.pop_front(),
+            Op::PopBack => list.pop_back(),
+            Op::Insert(idx, item) => {
+                let idx = idx % (list.len() + 1);
+                if idx == list.len() {
+                    list.push_back(item);
+                } else {
+                    let mut cursor = list.cursor_mut_front();
+                    cursor.move_to(idx);
+                    cursor.insert_before(item);
+                }
+                None
+            }
+            Op::Remove(idx) => {
+                if list.is_empty() {
+                    return None;
+                }
+                let idx = idx % list.len();
+                let mut cursor = list.cursor_mut_front();
+                cursor.move_to(idx);
+                cursor.remove_current()
+            }
+        }
+    }
+
+    /// Applies this operation to the given model collection, returning the removed element,
+    /// if any. Behaves exactly like [`Self::apply`].
+    pub fn apply_to_model(self, model: &mut VecDeque<T>) -> Option<T> {
+        match self {
+            Op::PushFront(item) => {
+                model.push_front(item);
+                None
+            }
+            Op::PushBack(item) => {
+                model.push_back(item);
+                None
+            }
+            Op::PopFront => model.pop_front(),
+            Op::PopBack => model.pop_back(),
+            Op::Insert(idx, item) => {
+                model.insert(idx % (model.len() + 1), item);
+                None
+            }
+            Op::Remove(idx) => {
+                if model.is_empty() {
+                    return None;
+                }
+                model.remove(idx % model.len())
+            }
+        }
+    }
+}
+
+/// Generates lists with a length in `size`, built by pushing elements generated by `element`
+/// to either end.
+pub fn list<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ReversibleList<S::Value>> {
+    proptest::collection::vec((any::<bool>(), element), size).prop_map(|steps| {
+        let mut list = ReversibleList::new();
+        for (to_front, item) in steps {
+            if to_front {
+                list.push_front(item);
+            } else {
+                list.push_back(item);
+            }
+        }
+        list
+    })
+}
+
+/// Generates one [`Op`], with elements generated by `element`.
+pub fn op<S: Strategy + Clone>(element: S) -> impl Strategy<Value = Op<S::Value>>
+where
+    S::Value: Clone,
+{
+    prop_oneof![
+        element.clone().prop_map(Op::PushFront),
+        element.clone().prop_map(Op::PushBack),
+        Just(Op::PopFront),
+        Just(Op::PopBack),
+        (any::<usize>(), element).prop_map(|(idx, item)| Op::Insert(idx, item)),
+        any::<usize>().prop_map(Op::Remove),
+    ]
+}
+
+/// Generates sequences of [`Op`]s with a length in `size`, with elements generated by
+/// `element`.
+pub fn ops<S: Strategy + Clone>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<Op<S::Value>>>
+where
+    S::Value: Clone,
+{
+    proptest::collection::vec(op(element), size)
+}
@@ -0,0 +1,70 @@
+//! [`Stream`](futures_core::Stream) adapters, behind the `futures-core` feature.
+//!
+//! All elements are already there, so polling is always immediately ready. This is mostly
+//! useful for feeding a list, say of queued jobs, into an async pipeline without collecting it
+//! somewhere else first.
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::{iter, ReversibleList};
+
+impl<T, A: Allocator> ReversibleList<T, A> {
+    /// Returns a stream yielding references to all elements, front to back.
+    pub fn stream(&self) -> Stream<'_, T> {
+        Stream { iter: self.iter() }
+    }
+
+    /// Converts this list into a stream yielding all elements, front to back.
+    pub fn into_stream(self) -> IntoStream<T, A> {
+        IntoStream { list: self }
+    }
+}
+
+/// Stream through a borrowed [`ReversibleList`].
+pub struct Stream<'list, T: 'list> {
+    iter: iter::Iter<'list, T>,
+}
+
+impl<'list, T: 'list> futures_core::Stream for Stream<'list, T> {
+    type Item = &'list T;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<&'list T>> {
+        Poll::Ready(self.iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Stream taking ownership of a [`ReversibleList`].
+pub struct IntoStream<T, A: Allocator = Global> {
+    list: ReversibleList<T, A>,
+}
+
+impl<T, A: Allocator> IntoStream<T, A> {
+    /// Returns the list with all elements not yielded yet.
+    pub fn into_inner(self) -> ReversibleList<T, A> {
+        self.list
+    }
+}
+
+// The list is never pinned structurally, so moving it around is fine even if `A` is `!Unpin`.
+impl<T, A: Allocator> Unpin for IntoStream<T, A> {}
+
+impl<T, A: Allocator> futures_core::Stream for IntoStream<T, A> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<T>> {
+        Poll::Ready(self.list.pop_front())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
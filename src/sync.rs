@@ -0,0 +1,125 @@
+//! A list which can be shared between threads, guarded by one internal lock.
+//!
+//! [`SyncReversibleList`] is just a [`Mutex`] around a [`ReversibleList`], but saves you from
+//! locking by hand for the common operations at both ends. Cursors are only handed out inside
+//! closures, so the lock is held exactly as long as the cursor is in use and can't be leaked
+//! into a long-lived borrow by accident.
+//!
+//! The lock is coarse-grained: every operation locks the whole list. That's fine for short
+//! critical sections like pushing and popping, but if many threads hammer the same list, a
+//! dedicated concurrent queue will scale better.
+//!
+//! Panics while the lock is held don't poison the list permanently. All list operations keep
+//! the list consistent even when unwinding, so the next lock just carries on.
+
+use std::{
+    fmt,
+    sync::{Mutex, MutexGuard, PoisonError, TryLockError},
+};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::{cursor::CursorMut, ReversibleList};
+
+/// A [`ReversibleList`] behind an internal lock, usable from multiple threads at once.
+///
+/// See the module docs for details.
+pub struct SyncReversibleList<T, A: Allocator = Global> {
+    list: Mutex<ReversibleList<T, A>>,
+}
+
+impl<T> SyncReversibleList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from(ReversibleList::new())
+    }
+}
+
+impl<T, A: Allocator> SyncReversibleList<T, A> {
+    /// Locks the list for arbitrary access, until the returned guard is dropped.
+    pub fn lock(&self) -> MutexGuard<'_, ReversibleList<T, A>> {
+        self.list.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the list, without locking since this is the only reference to it.
+    pub fn get_mut(&mut self) -> &mut ReversibleList<T, A> {
+        self.list.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the list, consuming the lock.
+    pub fn into_inner(self) -> ReversibleList<T, A> {
+        self.list
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the number of elements at the time of locking. Other threads might have changed
+    /// it already by the time this returns.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns if the list was empty at the time of locking, see [`Self::len`].
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Inserts the given item before the first element of the list.
+    pub fn push_front(&self, item: T) {
+        self.lock().push_front(item);
+    }
+
+    /// Appends the given item to the end of the list.
+    pub fn push_back(&self, item: T) {
+        self.lock().push_back(item);
+    }
+
+    /// Removes the element at the beginning of the list.
+    pub fn pop_front(&self) -> Option<T> {
+        self.lock().pop_front()
+    }
+
+    /// Removes the element at the end of the list.
+    pub fn pop_back(&self) -> Option<T> {
+        self.lock().pop_back()
+    }
+
+    /// Locks the list and calls `f` with a mutable cursor pointing at the **first** node. The
+    /// lock is released as soon as `f` returns.
+    pub fn with_cursor_front<R>(&self, f: impl FnOnce(&mut CursorMut<'_, T, A>) -> R) -> R {
+        f(&mut self.lock().cursor_mut_front())
+    }
+
+    /// Locks the list and calls `f` with a mutable cursor pointing at the **last** node. The
+    /// lock is released as soon as `f` returns.
+    pub fn with_cursor_back<R>(&self, f: impl FnOnce(&mut CursorMut<'_, T, A>) -> R) -> R {
+        f(&mut self.lock().cursor_mut_back())
+    }
+}
+
+impl<T> Default for SyncReversibleList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> From<ReversibleList<T, A>> for SyncReversibleList<T, A> {
+    fn from(value: ReversibleList<T, A>) -> Self {
+        Self {
+            list: Mutex::new(value),
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for SyncReversibleList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = match self.list.try_lock() {
+            Ok(list) => list,
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner(),
+            Err(TryLockError::WouldBlock) => return f.write_str("SyncReversibleList(<locked>)"),
+        };
+        f.debug_tuple("SyncReversibleList").field(&*list).finish()
+    }
+}
@@ -1,6 +1,11 @@
-use std::collections::HashSet;
+use std::{cell::Cell, collections::HashSet, ptr::NonNull};
 
-use crate::ReversibleList;
+use allocator_api2::alloc::{AllocError, Allocator, Global, Layout};
+
+use crate::{
+    cow::CowList, ordered::OrderedMap, persistent::PersistentList, small::SmallList,
+    sync::SyncReversibleList, unrolled::UnrolledList, ReversibleList,
+};
 
 #[test]
 fn casual_push_and_observe() {
@@ -43,7 +48,7 @@ fn snake_and_reverse() {
 
     snake.push_front(1);
 
-    assert_eq!(snake.iter().sum::<i32>(), snake.iter().rev().sum());
+    assert_eq!(snake.iter().sum::<i32>(), snake.iter().rev().sum::<i32>());
     assert_eq!(
         snake.iter().copied().collect::<Vec<_>>(),
         vec![1, -45, 10, 1_000_000]
@@ -120,6 +125,7 @@ fn curious_cursors() {
     player.remove_current().unwrap();
     assert_eq!(player.remove_current(), None);
     assert_eq!(player.index(), None);
+    list.assert_invariants();
 }
 
 #[test]
@@ -137,7 +143,7 @@ fn standard_traits() {
     manually_pushed.push_front("is");
     manually_pushed.push_front("this");
 
-    let collected = ["this", "is", "a", "sentence"].into_iter().collect();
+    let collected: ReversibleList<_> = ["this", "is", "a", "sentence"].into_iter().collect();
     assert_eq!(manually_pushed, collected);
 
     let cloned = collected.clone();
@@ -158,3 +164,3507 @@ fn standard_traits() {
     set.insert(from_vec);
     assert_eq!(set.len(), 1);
 }
+
+#[test]
+fn least_recently_used() {
+    let mut queue = ReversibleList::from(["tea", "coffee", "juice", "water"]);
+
+    let mut cursor = queue.cursor_mut_front();
+    cursor.move_to(2);
+    cursor.move_current_to_front();
+    assert_eq!(cursor.current(), Some(&"water"));
+    assert_eq!(cursor.index(), Some(3));
+    assert_eq!(
+        queue,
+        ReversibleList::from(["juice", "tea", "coffee", "water"])
+    );
+
+    let mut cursor = queue.cursor_mut_back();
+    cursor.move_current_to_front();
+    assert_eq!(cursor.current(), Some(&"coffee"));
+    assert_eq!(cursor.index(), Some(3));
+    cursor.move_prev_n(2);
+    cursor.move_current_to_back();
+    assert_eq!(cursor.current(), Some(&"tea"));
+    assert_eq!(cursor.index(), Some(1));
+    assert_eq!(
+        queue,
+        ReversibleList::from(["water", "tea", "coffee", "juice"])
+    );
+    assert_eq!(
+        queue.iter().rev().collect::<Vec<_>>(),
+        vec![&"juice", &"coffee", &"tea", &"water"]
+    );
+    queue.assert_invariants();
+
+    let mut lonely = ReversibleList::from(["milk"]);
+    let mut cursor = lonely.cursor_mut_front();
+    cursor.move_current_to_back();
+    cursor.move_current_to_front();
+    assert_eq!(cursor.current(), Some(&"milk"));
+    assert_eq!(cursor.index(), Some(0));
+    lonely.assert_invariants();
+}
+
+#[test]
+fn bubbly_swaps() {
+    let mut list = ReversibleList::from([1, 2, 4, 3, 5]);
+
+    let mut bubble = list.cursor_mut_front();
+    bubble.move_to(2);
+    assert!(bubble.swap_with_next());
+    assert_eq!(bubble.current(), Some(&4));
+    assert_eq!(bubble.index(), Some(3));
+    assert!(bubble.swap_with_next());
+    assert!(!bubble.swap_with_next());
+    assert_eq!(bubble.index(), Some(4));
+
+    assert!(bubble.swap_with_prev());
+    assert_eq!(bubble.index(), Some(3));
+    bubble.move_to(0);
+    assert!(!bubble.swap_with_prev());
+    list.assert_invariants();
+    assert_eq!(list, ReversibleList::from([1, 2, 3, 4, 5]));
+    assert_eq!(
+        list.iter().rev().copied().collect::<Vec<_>>(),
+        vec![5, 4, 3, 2, 1]
+    );
+
+    let mut empty = ReversibleList::<()>::new();
+    assert!(!empty.cursor_mut_front().swap_with_next());
+}
+
+#[test]
+fn splitting_hairs() {
+    let mut hair = ReversibleList::from(["root", "shaft", "split", "ends", "more ends"]);
+
+    let mut scissors = hair.cursor_mut_front();
+    scissors.move_to(2);
+    let cut = scissors.split_rest();
+    assert_eq!(scissors.current(), Some(&"shaft"));
+    assert_eq!(scissors.index(), Some(1));
+    hair.assert_invariants();
+    cut.assert_invariants();
+    assert_eq!(hair, ReversibleList::from(["root", "shaft"]));
+    assert_eq!(cut, ReversibleList::from(["split", "ends", "more ends"]));
+    assert_eq!(cut.len(), 3);
+    assert_eq!(
+        cut.iter().rev().collect::<Vec<_>>(),
+        vec![&"more ends", &"ends", &"split"]
+    );
+
+    let mut scissors = hair.cursor_mut_front();
+    let everything = scissors.split_rest();
+    assert_eq!(scissors.current(), None);
+    assert_eq!(scissors.index(), None);
+    assert!(scissors.split_rest().is_empty());
+    assert!(hair.is_empty());
+    assert_eq!(everything, ReversibleList::from(["root", "shaft"]));
+}
+
+#[test]
+fn chunked_queue() {
+    let mut queue = (0..10).collect::<ReversibleList<_>>();
+
+    let mut worker = queue.cursor_mut_front();
+    worker.move_to(2);
+    let chunk = worker.remove_n(3);
+    assert_eq!(chunk, ReversibleList::from([2, 3, 4]));
+    assert_eq!(worker.current(), Some(&5));
+    assert_eq!(worker.index(), Some(2));
+    assert!(worker.remove_n(0).is_empty());
+
+    // there's only 5 more nodes until the end
+    let chunk = worker.remove_n(100);
+    assert_eq!(chunk, ReversibleList::from([5, 6, 7, 8, 9]));
+    assert_eq!(
+        chunk.iter().rev().copied().collect::<Vec<_>>(),
+        vec![9, 8, 7, 6, 5]
+    );
+    assert_eq!(worker.current(), Some(&1));
+    assert_eq!(worker.index(), Some(1));
+
+    worker.move_next();
+    let chunk = worker.remove_n(2);
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(worker.index(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn bookmarks() {
+    let mut book = (1..=100).collect::<ReversibleList<_>>();
+
+    let mut reader = book.cursor_mut_front();
+    reader.move_to(42);
+    let bookmark = reader.save();
+    assert_eq!(bookmark, reader.save());
+
+    reader.move_to(97);
+    *reader.current_mut().unwrap() *= 2;
+    reader.move_to(1);
+    // SAFETY: nothing has been inserted or removed since saving
+    unsafe { reader.restore(bookmark) };
+    assert_eq!(reader.current(), Some(&43));
+    assert_eq!(reader.index(), Some(42));
+
+    // changes after the bookmark are fine too
+    reader.move_to(50);
+    reader.remove_current();
+    // SAFETY: only nodes after the bookmark have been removed
+    unsafe { reader.restore(bookmark) };
+    reader.move_next();
+    assert_eq!(reader.current(), Some(&44));
+
+    let reader = book.cursor_back();
+    let mut other_reader = book.cursor_front();
+    // SAFETY: both cursors look at the same, unchanged list
+    unsafe { other_reader.restore(reader.save()) };
+    assert_eq!(other_reader.current(), Some(&100));
+}
+
+#[test]
+fn handles_stay_put() {
+    let mut shelf = ReversibleList::new();
+    let plant = shelf.push_back("plant");
+    let lamp = shelf.push_front("lamp");
+    shelf.push_back("books");
+
+    let mut cursor = shelf.cursor_mut_back();
+    let mug = cursor.insert_before("mug");
+    assert_eq!(cursor.handle(), cursor.cursor().handle());
+    cursor.move_current_to_front();
+
+    // SAFETY: none of the handled nodes are removed before their handles are used
+    unsafe {
+        assert_eq!(shelf.get_by_handle(plant), &"plant");
+        *shelf.get_by_handle_mut(lamp) = "lava lamp";
+        assert_eq!(shelf.cursor_at_handle(lamp).index(), Some(1));
+        assert_eq!(shelf.cursor_at_handle(mug).current(), Some(&"mug"));
+
+        let mut cursor = shelf.cursor_mut_at_handle(plant);
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_current_to_front();
+
+        assert_eq!(shelf.remove_by_handle(mug), "mug");
+    }
+    assert_eq!(shelf, ReversibleList::from(["plant", "books", "lava lamp"]));
+}
+
+#[test]
+#[cfg(feature = "checked-handles")]
+fn checked_handles() {
+    let mut garden = ReversibleList::from(["daisy", "tulip"]);
+    let rose = garden.push_back("rose");
+    let weed = garden.push_front("weed");
+
+    assert_eq!(garden.try_get_by_handle(rose), Some(&"rose"));
+    assert_eq!(garden.try_remove_by_handle(weed), Some("weed"));
+    assert_eq!(garden.try_remove_by_handle(weed), None);
+    assert_eq!(garden.try_get_by_handle_mut(weed), None);
+    assert!(garden.try_cursor_at_handle(weed).is_none());
+
+    // the same address might be reused, but the generation won't match
+    let clover = garden.push_front("clover");
+    assert!(garden.contains_handle(clover));
+    assert!(!garden.contains_handle(weed));
+
+    // handles move along with their nodes
+    let mut cursor = garden.try_cursor_mut_at_handle(rose).unwrap();
+    cursor.move_prev();
+    let bouquet = cursor.split_rest();
+    assert!(!garden.contains_handle(rose));
+    assert_eq!(bouquet.try_get_by_handle(rose), Some(&"rose"));
+    assert_eq!(garden.try_get_by_handle(clover), Some(&"clover"));
+}
+
+#[test]
+fn trading_places() {
+    let mut wallets = ReversibleList::from([10, 20, 30, 40]);
+
+    let [alice, bob] = wallets.get_disjoint_mut([3, 1]).unwrap();
+    *alice -= 5;
+    *bob += 5;
+    assert_eq!(wallets, ReversibleList::from([10, 25, 30, 35]));
+
+    let [first, second, third] = wallets.get_disjoint_mut([2, 0, 1]).unwrap();
+    std::mem::swap(first, second);
+    std::mem::swap(second, third);
+    assert_eq!(wallets, ReversibleList::from([25, 30, 10, 35]));
+
+    assert!(wallets.get_disjoint_mut([1, 1]).is_none());
+    assert!(wallets.get_disjoint_mut([0, 4]).is_none());
+    assert!(wallets.get_disjoint_mut([]).is_some());
+    assert!(ReversibleList::<u8>::new().get_disjoint_mut([0]).is_none());
+}
+
+/// Allocates using [`Global`], but keeps track of how many allocations are currently alive.
+#[derive(Default)]
+struct CountingAlloc {
+    live: Cell<usize>,
+}
+
+unsafe impl Allocator for &CountingAlloc {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.live.set(self.live.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.live.set(self.live.get() - 1);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
+
+#[test]
+fn custom_allocator() {
+    let counter = CountingAlloc::default();
+
+    let mut list = ReversibleList::new_in(&counter);
+    list.extend(["a", "b", "c"]);
+    list.push_front("z");
+    assert_eq!(counter.live.get(), 4);
+
+    // the popped node is kept around in the pool
+    list.pop_back();
+    let cloned = list.clone();
+    assert_eq!(counter.live.get(), 7);
+
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_next();
+    let rest = cursor.split_rest();
+    assert_eq!(rest, ReversibleList::from(["a", "b"]));
+    assert_eq!(counter.live.get(), 7);
+
+    drop(cloned);
+    drop(rest);
+    assert_eq!(counter.live.get(), 2);
+    drop(list);
+    assert_eq!(counter.live.get(), 0);
+}
+
+#[test]
+fn pooled_nodes() {
+    let counter = CountingAlloc::default();
+    let mut queue = ReversibleList::new_in(&counter);
+    assert_eq!(queue.pool_limit(), 16);
+
+    queue.extend(0..4);
+    for i in 4..100 {
+        queue.pop_front();
+        queue.push_back(i);
+    }
+    assert_eq!(counter.live.get(), 4);
+    assert_eq!(queue, ReversibleList::from([96, 97, 98, 99]));
+
+    // only 2 of the 4 nodes fit into the pool
+    queue.set_pool_limit(2);
+    while queue.pop_back().is_some() {}
+    assert_eq!(counter.live.get(), 2);
+
+    queue.set_pool_limit(0);
+    assert_eq!(counter.live.get(), 0);
+    queue.push_front(-1);
+    queue.pop_front();
+    assert_eq!(counter.live.get(), 0);
+}
+
+#[test]
+fn burst_without_allocations() {
+    let counter = CountingAlloc::default();
+    let mut burst = ReversibleList::with_capacity_in(40, &counter);
+    assert_eq!(burst.capacity(), 40);
+    assert_eq!(burst.pool_limit(), 40);
+    assert_eq!(counter.live.get(), 40);
+
+    burst.extend(0..30);
+    burst.reserve(5);
+    assert_eq!(burst.capacity(), 40);
+    burst.reserve(20);
+    assert_eq!(burst.capacity(), 50);
+    assert_eq!(burst.pool_limit(), 50);
+    burst.extend(30..50);
+    assert_eq!(counter.live.get(), 50);
+
+    while burst.pop_front().is_some() {}
+    assert_eq!(burst.capacity(), 50);
+    assert_eq!(ReversibleList::<()>::with_capacity(3).capacity(), 3);
+}
+
+#[test]
+fn after_the_spike() {
+    let counter = CountingAlloc::default();
+    let mut spiky = ReversibleList::with_capacity_in(100, &counter);
+    spiky.extend(0..10);
+
+    spiky.shrink_to(50);
+    assert_eq!(spiky.capacity(), 50);
+    assert_eq!(counter.live.get(), 50);
+    spiky.shrink_to(60);
+    assert_eq!(spiky.capacity(), 50);
+    spiky.shrink_to(5);
+    assert_eq!(spiky.capacity(), 10);
+
+    spiky.pop_back();
+    assert_eq!(spiky.capacity(), 10);
+    spiky.shrink_to_fit();
+    assert_eq!(spiky.capacity(), 9);
+    assert_eq!(counter.live.get(), 9);
+    assert_eq!(spiky.pool_limit(), 100);
+}
+
+#[test]
+fn unrolled_carpet() {
+    let mut carpet = UnrolledList::<_, 4>::new();
+    carpet.extend(0..10);
+    carpet.push_front(-1);
+    assert_eq!(carpet.len(), 11);
+    assert_eq!(carpet.chunk_count(), 4);
+
+    // inserting into a full chunk splits it
+    carpet.insert(2, 100);
+    carpet.insert(12, 200);
+    assert_eq!(carpet.remove(0), -1);
+    assert_eq!(
+        carpet.iter().copied().collect::<Vec<_>>(),
+        vec![0, 100, 1, 2, 3, 4, 5, 6, 7, 8, 9, 200]
+    );
+    assert_eq!(
+        carpet.iter().rev().copied().collect::<Vec<_>>(),
+        vec![200, 9, 8, 7, 6, 5, 4, 3, 2, 1, 100, 0]
+    );
+    assert_eq!(carpet.get(1), Some(&100));
+    assert_eq!(carpet.get(12), None);
+    *carpet.get_mut(11).unwrap() += 1;
+
+    let mut cursor = carpet.cursor_back();
+    assert_eq!(cursor.current(), Some(&201));
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&0));
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_prev_n(2);
+    assert_eq!(cursor.current(), Some(&9));
+    cursor.move_next_n(15);
+    assert_eq!(cursor.index(), Some(1));
+    for expected in 2..=9 {
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&(expected - 1)));
+    }
+    cursor.move_to(5);
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&3));
+
+    let cloned = carpet.clone();
+    assert_eq!(carpet, cloned);
+    while carpet.pop_back().is_some() {}
+    assert_eq!(carpet.chunk_count(), 0);
+    assert_eq!(carpet.cursor_front().current(), None);
+    assert_eq!(cloned.len(), 12);
+
+    let mut tiny = (0..3).collect::<UnrolledList<_, 1>>();
+    tiny.insert(1, 10);
+    assert_eq!(format!("{tiny:?}"), "[0, 10, 1, 2]");
+    assert_eq!(tiny.pop_front(), Some(0));
+
+    let mut iter = tiny.iter();
+    assert_eq!(iter.len(), 3);
+    iter.next_back();
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+
+    let mut cursor = tiny.cursor_mut_at(1);
+    *cursor.current_mut().unwrap() *= 7;
+    cursor.insert_before(5);
+    cursor.insert_after(6);
+    assert_eq!(cursor.index(), Some(2));
+    assert_eq!(cursor.cursor().current(), Some(&7));
+    assert_eq!(cursor.remove_current(), Some(7));
+    assert_eq!(cursor.current(), Some(&6));
+    assert_eq!(cursor.remove_current(), Some(6));
+    assert_eq!(cursor.current(), Some(&2));
+    assert_eq!(tiny.iter().copied().collect::<Vec<_>>(), [10, 5, 2]);
+}
+
+#[test]
+fn unrolled_rebalancing() {
+    let (inserts, removals) = if cfg!(miri) { (100, 90) } else { (1000, 900) };
+    let mut rug = UnrolledList::<_, 8>::new();
+    let mut expected = Vec::new();
+    for i in 0..inserts {
+        rug.insert(rug.len() / 2, i);
+        expected.insert(expected.len() / 2, i);
+    }
+    for _ in 0..removals {
+        assert_eq!(
+            rug.remove(rug.len() / 3),
+            expected.remove(expected.len() / 3)
+        );
+    }
+    assert!(rug.iter().eq(&expected));
+    // every chunk but the last stays at least half full
+    assert!(rug.chunk_count() <= (inserts - removals) / 4 + 1);
+
+    let mut left = rug.len();
+    let mut cursor = rug.cursor_mut_back();
+    while cursor.remove_current().is_some() {
+        left -= 1;
+        assert_eq!(cursor.index(), left.checked_sub(1));
+    }
+    assert_eq!(rug.chunk_count(), 0);
+}
+
+#[test]
+fn pocket_sized() {
+    let mut pocket = SmallList::<_, 3>::new();
+    pocket.push_back("keys");
+    pocket.push_front("phone");
+    pocket.push_back("coin");
+    assert!(!pocket.is_spilled());
+    assert_eq!(pocket.iter().len(), 3);
+    assert_eq!(
+        pocket.iter().rev().collect::<Vec<_>>(),
+        vec![&"coin", &"keys", &"phone"]
+    );
+    assert_eq!(pocket.pop_front(), Some("phone"));
+    assert_eq!(pocket.pop_back(), Some("coin"));
+
+    pocket.extend(["wallet", "lint", "receipt"]);
+    assert!(pocket.is_spilled());
+    assert_eq!(pocket.len(), 4);
+    assert_eq!(pocket.iter().len(), 4);
+    assert_eq!(pocket.clone(), pocket);
+    assert_eq!(pocket.pop_front(), Some("keys"));
+
+    // emptying the pocket a bit lets everything fit inline again
+    let mut tidied = pocket.clone();
+    tidied.push_back("gum");
+    tidied.shrink_to_fit();
+    assert!(tidied.is_spilled());
+    tidied.pop_back();
+    tidied.shrink_to_fit();
+    assert!(!tidied.is_spilled());
+    assert_eq!(tidied, pocket);
+
+    let bag = ReversibleList::from(pocket);
+    assert_eq!(bag, ReversibleList::from(["wallet", "lint", "receipt"]));
+
+    let empty = SmallList::<u8>::default();
+    assert_eq!(empty.iter().next(), None);
+    assert!(empty.into_list().is_empty());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_roundtrip() {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    let mut list = ReversibleList::from([3, 1, 4]);
+    list.push_front(0);
+
+    assert_tokens(
+        &list,
+        &[
+            Token::Seq { len: Some(4) },
+            Token::I32(0),
+            Token::I32(3),
+            Token::I32(1),
+            Token::I32(4),
+            Token::SeqEnd,
+        ],
+    );
+    assert_de_tokens_error::<ReversibleList<i32>>(
+        &[Token::Map { len: Some(0) }],
+        "invalid type: map, expected a sequence",
+    );
+}
+
+#[test]
+#[cfg(feature = "rkyv")]
+fn rkyv_roundtrip() {
+    use rkyv::{rancor::Error, vec::ArchivedVec, Archived};
+
+    let mut list = ReversibleList::from(["archived".to_owned(), "on".to_owned()]);
+    list.push_front("lists".to_owned());
+    list.push_back("disk".to_owned());
+
+    let bytes = rkyv::to_bytes::<Error>(&list).unwrap();
+    let archived = rkyv::access::<ArchivedVec<Archived<String>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 4);
+    assert_eq!(archived[0], "lists");
+    assert_eq!(archived[3], "disk");
+
+    let back: ReversibleList<String> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(back, list);
+}
+
+#[test]
+#[cfg(feature = "arbitrary")]
+fn arbitrary_pushes() {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    // every step is a byte for continuing, one for the direction, then the element itself
+    let raw = [1, 1, 10, 1, 0, 20, 1, 1, 30, 1, 0, 40];
+    let list = ReversibleList::<u8>::arbitrary(&mut Unstructured::new(&raw)).unwrap();
+    assert_eq!(list, ReversibleList::from([30, 10, 20, 40]));
+    let list = ReversibleList::<u8>::arbitrary_take_rest(Unstructured::new(&raw)).unwrap();
+    assert_eq!(list, ReversibleList::from([30, 10, 20, 40]));
+
+    let list = ReversibleList::<u8>::arbitrary(&mut Unstructured::new(&[])).unwrap();
+    assert!(list.is_empty());
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn behaves_like_vec_deque(
+        list in crate::strategies::list(0..100u8, 0..20),
+        ops in crate::strategies::ops(0..100u8, 0..50),
+    ) {
+        let mut list = list;
+        let mut model = list.iter().copied().collect::<std::collections::VecDeque<_>>();
+
+        for op in ops {
+            proptest::prop_assert_eq!(op.clone().apply(&mut list), op.apply_to_model(&mut model));
+            list.assert_invariants();
+            proptest::prop_assert_eq!(list.len(), model.len());
+            proptest::prop_assert!(list.iter().eq(model.iter()));
+            proptest::prop_assert!(list.iter().rev().eq(model.iter().rev()));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn parallel_lines() {
+    use rayon::prelude::*;
+
+    let lines = (0..1000u64).collect::<ReversibleList<_>>();
+    assert_eq!(lines.par_iter().sum::<u64>(), 999 * 1000 / 2);
+    assert_eq!(lines.par_iter().count(), 1000);
+    assert_eq!(
+        lines.par_iter().map(|x| x * 2).collect::<Vec<_>>(),
+        lines.iter().map(|x| x * 2).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        (&lines).into_par_iter().rev().copied().collect::<Vec<_>>(),
+        (0..1000).rev().collect::<Vec<_>>()
+    );
+    assert_eq!(ReversibleList::<u8>::new().par_iter().count(), 0);
+}
+
+#[test]
+#[cfg(feature = "futures-core")]
+fn job_stream() {
+    use std::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use futures_core::Stream;
+
+    fn drain<S: Stream>(stream: S) -> Vec<S::Item> {
+        let mut stream = pin!(stream);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut items = Vec::new();
+        while let Poll::Ready(Some(item)) = stream.as_mut().poll_next(&mut cx) {
+            items.push(item);
+        }
+        items
+    }
+
+    let jobs = ReversibleList::from(["wash", "dry", "fold"]);
+    assert_eq!(jobs.stream().size_hint(), (3, Some(3)));
+    assert_eq!(drain(jobs.stream()), [&"wash", &"dry", &"fold"]);
+
+    let stream = jobs.into_stream();
+    assert_eq!(stream.size_hint(), (3, Some(3)));
+    assert_eq!(drain(stream), ["wash", "dry", "fold"]);
+}
+
+#[test]
+fn across_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<ReversibleList<String>>();
+    assert_send_sync::<crate::iter::Iter<'_, String>>();
+    assert_send_sync::<crate::iter::IterMut<'_, String>>();
+    assert_send_sync::<crate::iter::Split<'_, String, fn(&String) -> bool>>();
+    assert_send_sync::<crate::iter::Windows<'_, String>>();
+    assert_send_sync::<crate::iter::Cycle<'_, String>>();
+    assert_send_sync::<crate::iter::ChunkBy<'_, String, fn(&String, &String) -> bool>>();
+    assert_send_sync::<crate::iter::Cursors<'_, String>>();
+    assert_send_sync::<crate::cursor::Cursor<'_, String>>();
+    assert_send_sync::<crate::cursor::CursorMut<'_, String>>();
+    assert_send_sync::<crate::cursor::Position<String>>();
+    assert_send_sync::<crate::handle::NodeHandle<String>>();
+    assert_send_sync::<UnrolledList<String, 4>>();
+    assert_send_sync::<SmallList<String>>();
+
+    let mut list = ReversibleList::from([1, 2, 3]);
+    let sum = std::thread::scope(|s| {
+        let sum = s.spawn(|| list.iter().sum::<i32>());
+        let len = s.spawn(|| list.len());
+        assert_eq!(len.join().unwrap(), 3);
+        sum.join().unwrap()
+    });
+    assert_eq!(sum, 6);
+
+    let mut cursor = list.cursor_mut_back();
+    std::thread::scope(|s| {
+        s.spawn(|| *cursor.current_mut().unwrap() *= 10);
+    });
+    let list = std::thread::spawn(move || list).join().unwrap();
+    assert_eq!(list, [1, 2, 30]);
+}
+
+#[test]
+fn shrinking_lifetimes() {
+    fn list<'a>(list: ReversibleList<&'static str>) -> ReversibleList<&'a str> {
+        list
+    }
+    fn iter<'a, 'list>(
+        iter: crate::iter::Iter<'list, &'static str>,
+    ) -> crate::iter::Iter<'list, &'a str> {
+        iter
+    }
+    fn cursor<'a, 'list>(
+        cursor: crate::cursor::Cursor<'list, &'static str>,
+    ) -> crate::cursor::Cursor<'list, &'a str> {
+        cursor
+    }
+
+    let eternal = ReversibleList::from(["forever"]);
+    let local = String::from("for now");
+    let local = local.as_str();
+    let iter = iter(eternal.iter()).chain([&local]);
+    assert_eq!(iter.count(), 2);
+    assert_eq!(cursor(eternal.cursor_front()).current(), Some(&"forever"));
+
+    let mut list = list(eternal);
+    list.push_back(local);
+    assert_eq!(list.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "dropck_eyepatch")]
+fn dangling_at_the_end() {
+    let mut list = ReversibleList::new();
+    let borrowed = String::from("gone before the list");
+    list.push_back(&borrowed);
+    assert_eq!(list.len(), 1);
+}
+
+#[test]
+fn overlapping_views() {
+    let mut list = (0..8).collect::<ReversibleList<_>>();
+
+    // shared views may freely overlap
+    let mut forward = list.iter();
+    let mut backward = list.iter().rev();
+    let cursor = list.cursor_at(3);
+    assert_eq!(forward.next(), Some(&0));
+    assert_eq!(backward.next(), Some(&7));
+    assert_eq!(cursor.current(), Some(&3));
+    assert_eq!(forward.chain(backward).count(), 14);
+
+    // relinking neighbours must not invalidate the references derived from the moved node
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_next();
+    *cursor.current_mut().unwrap() += 100;
+    assert!(cursor.swap_with_next());
+    *cursor.current_mut().unwrap() += 100;
+    cursor.move_current_to_back();
+    *cursor.current_mut().unwrap() += 100;
+
+    let handle = list.push_front(-1);
+    list.push_back(8);
+    // SAFETY: The node behind `handle` is still in the list.
+    unsafe { *list.get_by_handle_mut(handle) -= 1 };
+    if let Some([first, last]) = list.get_disjoint_mut([0, 9]) {
+        std::mem::swap(first, last);
+    }
+    list.assert_invariants();
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        [8, 0, 2, 103, 4, 5, 6, 7, 201, -2],
+    );
+}
+
+#[test]
+#[should_panic = "forward walk doesn't match len"]
+fn caught_lying() {
+    let mut list = ReversibleList::from(["truth", "more truth"]);
+    list.assert_invariants();
+    list.len = 3;
+    list.assert_invariants();
+}
+
+#[test]
+fn connect_the_dots() {
+    let list = ReversibleList::from(["a", "\"quoted\""]);
+    let dot = list.to_dot();
+    assert!(dot.starts_with("digraph ReversibleList {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert_eq!(dot.matches("[label=next").count(), 1);
+    assert_eq!(dot.matches("[label=prev").count(), 1);
+    assert_eq!(dot.matches("\\n").count(), 4);
+    assert!(dot.contains(r#"\"\\\"quoted\\\"\""#));
+    assert!(dot.contains("start -> "));
+    assert!(dot.contains("end -> "));
+
+    let empty = ReversibleList::<()>::new().to_dot();
+    assert!(!empty.contains("->"));
+}
+
+#[test]
+fn under_the_hood() {
+    let list = ReversibleList::from(['x', 'y']);
+    assert_eq!(format!("{list:#?}"), "[\n    'x',\n    'y',\n]");
+
+    let start = format!("{:p}", list.start.unwrap());
+    let end = format!("{:p}", list.end.unwrap());
+    let nodes = format!("{:?}", list.debug_nodes());
+    assert!(nodes.starts_with("ReversibleList { len: 2, "));
+    assert!(nodes.contains(&format!(
+        "Node {{ addr: {start}, prev: None, next: Some({end}), data: 'x' }}"
+    )));
+    assert!(nodes.contains(&format!(
+        "Node {{ addr: {end}, prev: Some({start}), next: None, data: 'y' }}"
+    )));
+    assert!(format!("{:#?}", list.debug_nodes()).contains("pooled: 0,\n"));
+}
+
+#[test]
+fn weighing_in() {
+    let node_size = std::mem::size_of::<u64>() + 2 * std::mem::size_of::<usize>();
+
+    let mut list = (0..10u64).collect::<ReversibleList<_>>();
+    let usage = list.memory_usage();
+    assert_eq!((usage.live_nodes, usage.pooled_nodes), (10, 0));
+    assert_eq!(usage.heap_bytes, 10 * node_size);
+
+    list.pop_back();
+    list.pop_back();
+    let usage = list.memory_usage();
+    assert_eq!((usage.live_nodes, usage.pooled_nodes), (8, 2));
+    assert_eq!(usage.heap_bytes, 10 * node_size);
+
+    list.shrink_to_fit();
+    assert_eq!(list.memory_usage().heap_bytes, 8 * node_size);
+    assert_eq!(ReversibleList::<u64>::new().memory_usage().heap_bytes, 0);
+}
+
+#[test]
+fn back_to_vec() {
+    let alloc = CountingAlloc::default();
+    let mut list = ReversibleList::new_in(&alloc);
+    list.extend(["one".to_string(), "two".to_string(), "three".to_string()]);
+
+    assert_eq!(list.to_vec(), ["one", "two", "three"]);
+    assert_eq!(alloc.live.get(), 3);
+    assert_eq!(list.into_vec(), ["one", "two", "three"]);
+    assert_eq!(alloc.live.get(), 0);
+
+    let vec: Vec<_> = ReversibleList::from([1, 2]).into();
+    assert_eq!(vec, [1, 2]);
+    assert!(ReversibleList::<u8>::new().into_vec().is_empty());
+}
+
+#[test]
+fn migrating_from_std() {
+    let mut std_list = std::collections::LinkedList::new();
+    std_list.push_back(vec![1]);
+    std_list.push_back(vec![2, 3]);
+    std_list.push_front(vec![]);
+
+    let list = ReversibleList::from(std_list);
+    assert_eq!(list, [vec![], vec![1], vec![2, 3]]);
+    list.assert_invariants();
+
+    let back = std::collections::LinkedList::from(list);
+    assert_eq!(
+        back.into_iter().collect::<Vec<_>>(),
+        [vec![], vec![1], vec![2, 3]]
+    );
+}
+
+#[test]
+fn deque_handoff() {
+    let mut deque = std::collections::VecDeque::from([2, 3]);
+    deque.push_front(1);
+
+    let mut list = ReversibleList::from(deque);
+    list.push_front(0);
+    assert_eq!(list, [0, 1, 2, 3]);
+
+    let mut deque = std::collections::VecDeque::from(list);
+    deque.rotate_left(1);
+    assert_eq!(deque, [1, 2, 3, 0]);
+}
+
+#[test]
+fn compared_to_the_classics() {
+    let list = ReversibleList::from([1, 2, 3]);
+    assert_eq!(list, [1, 2, 3]);
+    assert_eq!([1, 2, 3], list);
+    assert_eq!(list, vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3], list);
+    assert_eq!(list, [1, 2, 3][..]);
+    assert_eq!([1, 2, 3][..], list);
+
+    assert_ne!(list, [1, 2]);
+    assert_ne!(list, [1, 2, 3, 4]);
+    assert_ne!(list, vec![3, 2, 1]);
+    assert_eq!(ReversibleList::<u8>::new(), []);
+
+    let names = ReversibleList::from([String::from("ferris")]);
+    assert_eq!(names, ["ferris"]);
+}
+
+#[test]
+fn literally_a_list() {
+    use crate::reversible_list;
+
+    let empty: ReversibleList<u8> = reversible_list![];
+    assert!(empty.is_empty());
+
+    assert_eq!(reversible_list![1, 2, 3], [1, 2, 3]);
+    assert_eq!(
+        reversible_list!["trailing", "comma",],
+        ["trailing", "comma"]
+    );
+    assert_eq!(reversible_list![vec![0]; 3], [vec![0], vec![0], vec![0]]);
+    assert!(reversible_list![String::new(); 0].is_empty());
+}
+
+#[test]
+fn comma_separated() {
+    let path = ReversibleList::from(["usr", "local", "bin"]);
+    assert_eq!(path.display_with("/").to_string(), "usr/local/bin");
+    assert_eq!(format!("{:>4}", path.display_with(",")), " usr,local, bin");
+
+    let scores = ReversibleList::from([1.5, 2.25]);
+    assert_eq!(format!("{:.1}", scores.display_with(" | ")), "1.5 | 2.2");
+    assert_eq!(
+        ReversibleList::<u8>::new().display_with(", ").to_string(),
+        ""
+    );
+}
+
+#[test]
+fn same_order_same_hash() {
+    use std::hash::{BuildHasher, RandomState};
+
+    let state = RandomState::new();
+    let straight = ReversibleList::from([1, 2, 3, 4]);
+
+    // the same logical order, reached by relinking in all kinds of ways
+    let mut shuffled = ReversibleList::from([3, 4, 2, 1]);
+    let mut cursor = shuffled.cursor_mut_front();
+    cursor.move_current_to_back();
+    cursor.move_current_to_back();
+    assert!(cursor.swap_with_next());
+    let mut cursor = shuffled.cursor_mut_front();
+    cursor.move_next();
+    let rest = cursor.split_rest();
+    shuffled.extend(rest.into_vec());
+    let mut pushed = ReversibleList::new();
+    for i in (1..=4).rev() {
+        pushed.push_front(i);
+    }
+
+    for other in [&shuffled, &pushed] {
+        assert_eq!(&straight, other);
+        assert_eq!(straight.cmp(other), std::cmp::Ordering::Equal);
+        assert_eq!(state.hash_one(&straight), state.hash_one(other));
+    }
+
+    let reversed = straight
+        .iter()
+        .rev()
+        .copied()
+        .collect::<ReversibleList<_>>();
+    assert_ne!(straight, reversed);
+    assert!(straight < reversed);
+}
+
+#[test]
+fn counting_down() {
+    let list = ReversibleList::from(['a', 'b', 'c', 'd']);
+
+    let mut iter = list.iter();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some(&'a'));
+    assert_eq!(iter.next_back(), Some(&'d'));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.next_back(), Some(&'c'));
+    assert_eq!(iter.next(), Some(&'b'));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+
+    let fused = list.iter().fuse().skip(1).collect::<Vec<_>>();
+    assert_eq!(fused, [&'b', &'c', &'d']);
+    assert_eq!(ReversibleList::<()>::new().iter().len(), 0);
+}
+
+#[test]
+fn peeking_at_iterators() {
+    let list = ReversibleList::from([1, 2, 3]);
+    let mut iter = list.iter();
+    iter.next();
+    let copy = iter.clone();
+    assert_eq!(format!("{iter:?}"), "Iter([2, 3])");
+    assert_eq!(iter.sum::<i32>(), 5);
+    assert_eq!(copy.rev().collect::<Vec<_>>(), [&3, &2]);
+
+    let small = SmallList::<_, 2>::from_iter(["x"]);
+    assert_eq!(format!("{:?}", small.iter()), r#"Iter(["x"])"#);
+    assert_eq!(small.iter().clone().count(), 1);
+
+    let unrolled = UnrolledList::<_, 2>::from_iter(0..5);
+    let mut iter = unrolled.iter();
+    iter.next_back();
+    assert_eq!(format!("{:?}", iter.clone()), "Iter([0, 1, 2, 3])");
+    assert_eq!(iter.count(), 4);
+}
+
+#[test]
+fn touching_everything() {
+    let mut list = ReversibleList::from([1, 2, 3, 4, 5]);
+
+    let mut iter = list.iter_mut();
+    assert_eq!(iter.len(), 5);
+    *iter.next().unwrap() *= 10;
+    *iter.next_back().unwrap() *= 100;
+    assert_eq!(format!("{iter:?}"), "IterMut([2, 3, 4])");
+    for item in iter.rev() {
+        *item += 1;
+    }
+    assert_eq!(list, [10, 3, 4, 5, 500]);
+
+    for item in &mut list {
+        *item -= 1;
+    }
+    let mut iter = (&list).into_iter();
+    assert_eq!(iter.next(), Some(&9));
+    assert_eq!(iter.len(), 4);
+
+    let mut iter = list.iter_mut();
+    let [a, b] = [iter.next().unwrap(), iter.next().unwrap()];
+    std::mem::swap(a, b);
+    assert_eq!(iter.by_ref().count(), 3);
+    assert!(iter.next().is_none());
+    assert_eq!(list, [2, 9, 3, 4, 499]);
+    list.assert_invariants();
+}
+
+#[test]
+fn emptying_the_box() {
+    let list = ReversibleList::from(["bottom", "middle", "top"]);
+    let mut iter = list.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some("top"));
+    assert_eq!(format!("{iter:?}"), r#"IntoIter(["bottom", "middle"])"#);
+    assert_eq!(iter.clone().collect::<Vec<_>>(), ["bottom", "middle"]);
+    assert_eq!(iter.next(), Some("bottom"));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+
+    // dropping midway frees everything left
+    let alloc = CountingAlloc::default();
+    let mut list = ReversibleList::new_in(&alloc);
+    list.extend((0..10).map(|i| i.to_string()));
+    let mut iter = list.into_iter();
+    assert_eq!(iter.nth(2).as_deref(), Some("2"));
+    assert_eq!(alloc.live.get(), 7);
+    drop(iter);
+    assert_eq!(alloc.live.get(), 0);
+}
+
+#[test]
+fn defusing_while_draining() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct Bomb<'a>(bool, &'a Cell<usize>);
+
+    impl Drop for Bomb<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+            if self.0 {
+                panic!("boom");
+            }
+        }
+    }
+
+    let dropped = Cell::new(0);
+    let alloc = CountingAlloc::default();
+    let mut list = ReversibleList::new_in(&alloc);
+    list.extend([false, true, false, false].map(|armed| Bomb(armed, &dropped)));
+
+    let mut iter = list.into_iter();
+    drop(iter.next());
+    let result = catch_unwind(AssertUnwindSafe(|| drop(iter)));
+    assert!(result.is_err());
+    assert_eq!(dropped.get(), 4);
+    assert_eq!(alloc.live.get(), 0);
+}
+
+#[test]
+fn chain_reaction() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct Bomb<'a>(bool, &'a Cell<usize>);
+
+    impl Drop for Bomb<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() + 1);
+            if self.0 {
+                panic!("boom");
+            }
+        }
+    }
+
+    let dropped = Cell::new(0);
+    let alloc = CountingAlloc::default();
+    let arm = |armed: [bool; 5]| {
+        let mut list = ReversibleList::new_in(&alloc);
+        list.extend(armed.map(|armed| Bomb(armed, &dropped)));
+        list
+    };
+
+    let list = arm([false, true, false, false, false]);
+    assert!(catch_unwind(AssertUnwindSafe(|| drop(list))).is_err());
+    assert_eq!(dropped.get(), 5);
+    assert_eq!(alloc.live.get(), 0);
+
+    dropped.set(0);
+    let mut list = arm([false, false, false, true, false]);
+    assert!(catch_unwind(AssertUnwindSafe(|| list.truncate(1))).is_err());
+    list.assert_invariants();
+    assert_eq!(list.len(), 1);
+    assert_eq!(dropped.get(), 4);
+
+    list.extend([Bomb(false, &dropped), Bomb(true, &dropped)]);
+    assert!(catch_unwind(AssertUnwindSafe(|| list.clear())).is_err());
+    list.assert_invariants();
+    assert!(list.is_empty());
+    assert_eq!(dropped.get(), 7);
+
+    list.push_back(Bomb(false, &dropped));
+    list.truncate(3);
+    assert_eq!(list.len(), 1);
+    drop(list);
+    assert_eq!(dropped.get(), 8);
+    assert_eq!(alloc.live.get(), 0);
+}
+
+#[test]
+fn every_frame_again() {
+    let alloc = CountingAlloc::default();
+    let mut frame = ReversibleList::new_in(&alloc);
+    frame.extend([vec![0; 64], vec![1; 64], vec![2; 64]]);
+    let first_buffer = frame.iter().next().unwrap().as_ptr();
+
+    let mut scene = ReversibleList::new_in(&alloc);
+    scene.extend([vec![7; 8], vec![8; 8]]);
+    frame.clone_from(&scene);
+    assert_eq!(frame, scene);
+    // the node and the vec buffer have been reused
+    assert_eq!(frame.iter().next().unwrap().as_ptr(), first_buffer);
+    assert_eq!(frame.capacity(), 3);
+
+    scene.push_back(vec![9]);
+    scene.push_back(vec![10]);
+    let live = alloc.live.get();
+    frame.clone_from(&scene);
+    assert_eq!(frame, scene);
+    assert_eq!(alloc.live.get(), live + 1);
+    frame.assert_invariants();
+
+    frame.clone_from(&ReversibleList::new_in(&alloc));
+    assert!(frame.is_empty());
+}
+
+#[test]
+fn carbon_copy() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let original = (0..100).collect::<ReversibleList<_>>();
+    let copy = original.clone();
+    copy.assert_invariants();
+    assert_eq!(copy, original);
+    assert_eq!(copy.iter().next_back(), Some(&99));
+
+    /// Panics when cloned for the third time.
+    struct Fragile<'a>(&'a Cell<usize>);
+
+    impl Clone for Fragile<'_> {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            assert!(self.0.get() < 3, "too fragile");
+            Self(self.0)
+        }
+    }
+
+    let clones = Cell::new(0);
+    let alloc = CountingAlloc::default();
+    let mut fragile = ReversibleList::new_in(&alloc);
+    fragile.extend((0..5).map(|_| Fragile(&clones)));
+    assert!(catch_unwind(AssertUnwindSafe(|| fragile.clone())).is_err());
+    assert_eq!(alloc.live.get(), 5);
+}
+
+#[test]
+fn butterfingers() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let alloc = CountingAlloc::default();
+    let mut list = ReversibleList::new_in(&alloc);
+    list.extend([0, 1]);
+    assert_eq!(list.get(1), Some(&1));
+
+    let dropped = catch_unwind(AssertUnwindSafe(|| {
+        list.extend((10..).inspect(|&item| assert!(item < 13, "slipped")));
+    }));
+    assert!(dropped.is_err());
+    list.assert_invariants();
+    assert!(list.iter().eq(&[0, 1, 10, 11, 12]));
+    assert_eq!(list.get(4), Some(&12));
+    list.push_back(13);
+    assert_eq!(alloc.live.get(), 6);
+    drop(list);
+    assert_eq!(alloc.live.get(), 0);
+
+    let collected = catch_unwind(|| {
+        (0..10)
+            .map(|item| {
+                assert!(item < 5, "slipped");
+                vec![item]
+            })
+            .collect::<ReversibleList<_>>()
+    });
+    assert!(collected.is_err());
+
+    /// Panics when cloning the given value.
+    #[derive(Debug, PartialEq)]
+    struct Slippery(u8);
+
+    impl Clone for Slippery {
+        fn clone(&self) -> Self {
+            assert_ne!(self.0, 3, "slipped");
+            Self(self.0)
+        }
+    }
+
+    let mut stack = ReversibleList::new_in(&alloc);
+    stack.extend((1..=4).map(Slippery));
+    let _ = stack.get(2);
+    assert!(catch_unwind(AssertUnwindSafe(|| stack.repeat_into(2))).is_err());
+    stack.assert_invariants();
+    assert_eq!(stack.len(), 6);
+    assert_eq!(stack.get(5), Some(&Slippery(2)));
+    assert_eq!(stack.get(2), Some(&Slippery(3)));
+
+    let mut target = ReversibleList::new_in(&alloc);
+    target.push_back(Slippery(9));
+    assert!(catch_unwind(AssertUnwindSafe(|| target.clone_from(&stack))).is_err());
+    target.assert_invariants();
+    assert!(target.iter().eq(&[Slippery(1), Slippery(2)]));
+    let mut cursor = target.cursor_mut_back();
+    cursor.insert_after(Slippery(7));
+    assert_eq!(cursor.remove_current(), Some(Slippery(2)));
+
+    drop((stack, target));
+    assert_eq!(alloc.live.get(), 0);
+}
+
+#[test]
+fn guessing_game() {
+    let primes = ReversibleList::from([2, 3, 5, 7, 11, 13, 17, 19, 23]);
+    for (i, prime) in primes.iter().enumerate() {
+        let found = primes.binary_search(prime).unwrap();
+        assert_eq!(found.index(), Some(i));
+        assert_eq!(found.current(), Some(prime));
+    }
+    assert_eq!(primes.binary_search(&1).unwrap_err(), 0);
+    assert_eq!(primes.binary_search(&4).unwrap_err(), 2);
+    assert_eq!(primes.binary_search(&100).unwrap_err(), 9);
+    assert_eq!(ReversibleList::new().binary_search(&0).unwrap_err(), 0);
+
+    let words = ReversibleList::from(["a", "bb", "ccc", "dddd"]);
+    let found = words.binary_search_by_key(&3, |word| word.len()).unwrap();
+    assert_eq!(found.current(), Some(&"ccc"));
+
+    let mut comparisons = 0;
+    let _ = primes.binary_search_by(|prime| {
+        comparisons += 1;
+        prime.cmp(&20)
+    });
+    assert!(comparisons <= 4);
+}
+
+#[test]
+fn waiting_room() {
+    let mut queue = ReversibleList::new();
+    for priority in [3, 1, 4, 1, 5, 9, 2, 6] {
+        queue.insert_sorted(priority);
+    }
+    assert_eq!(queue, [1, 1, 2, 3, 4, 5, 6, 9]);
+    queue.assert_invariants();
+
+    let mut patients = ReversibleList::new();
+    patients.insert_sorted_by_key(("anna", 2), |patient| patient.1);
+    patients.insert_sorted_by_key(("ben", 1), |patient| patient.1);
+    let cleo = patients.insert_sorted_by_key(("cleo", 2), |patient| patient.1);
+    patients.insert_sorted_by(("dan", 3), |a, b| a.1.cmp(&b.1));
+    // equal priorities stay in arrival order
+    assert_eq!(patients, [("ben", 1), ("anna", 2), ("cleo", 2), ("dan", 3)]);
+    // SAFETY: cleo is still in the list.
+    assert_eq!(unsafe { patients.get_by_handle(cleo) }, &("cleo", 2));
+}
+
+#[test]
+fn in_order_or_not() {
+    let mut list = ReversibleList::from([1, 2, 2, 5]);
+    assert!(list.is_sorted());
+    assert!(!list.is_sorted_by(|a, b| a < b));
+    assert!(list.is_sorted_by_key(|x| *x / 2));
+    list.push_front(9);
+    assert!(!list.is_sorted());
+    assert!(list.is_sorted_by_key(|x| *x != 9));
+
+    assert!(ReversibleList::<f32>::new().is_sorted());
+    assert!(!ReversibleList::from([1.0, f32::NAN]).is_sorted());
+}
+
+#[test]
+fn sorting_the_mail() {
+    let alloc = CountingAlloc::default();
+    let mut mail = ReversibleList::new_in(&alloc);
+    mail.extend(["bill", "letter", "ad", "bill", "postcard", "ad"]);
+    let postcard = {
+        let mut cursor = mail.cursor_mut_back();
+        cursor.move_prev();
+        cursor.handle().unwrap()
+    };
+
+    let (bills, rest) = mail.partition(|item| *item == "bill");
+    assert_eq!(bills, ["bill", "bill"]);
+    assert_eq!(rest, ["letter", "ad", "postcard", "ad"]);
+    assert_eq!(alloc.live.get(), 6);
+    bills.assert_invariants();
+    rest.assert_invariants();
+    // SAFETY: The postcard node moved into `rest`.
+    assert_eq!(unsafe { rest.get_by_handle(postcard) }, &"postcard");
+
+    let (nothing, everything) = rest.partition(|_| false);
+    assert!(nothing.is_empty());
+    assert_eq!(everything.len(), 4);
+}
+
+#[test]
+fn record_separators() {
+    let stream = ReversibleList::from(["a", "b", ";", "c", ";", ";", "d", ";"]);
+    let records = stream
+        .split(|item| *item == ";")
+        .map(|record| record.copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        records,
+        [vec!["a", "b"], vec!["c"], vec![], vec!["d"], vec![]]
+    );
+
+    let mut runs = stream.split(|item| *item == ";");
+    let mut first = runs.next().unwrap();
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.next_back(), Some(&"b"));
+    assert_eq!(
+        ReversibleList::<u8>::new()
+            .split(|_| true)
+            .map(Iterator::count)
+            .collect::<Vec<_>>(),
+        [0]
+    );
+
+    let alloc = CountingAlloc::default();
+    let mut stream = ReversibleList::new_in(&alloc);
+    stream.extend([1, 2, 0, 3, 0, 0, 4]);
+    let lists = stream.into_split(|item| *item == 0).collect::<Vec<_>>();
+    assert_eq!(lists.len(), 4);
+    for (list, expected) in lists.iter().zip([&[1, 2][..], &[3], &[], &[4]]) {
+        list.assert_invariants();
+        assert_eq!(list, expected);
+    }
+    // the nodes of the separators are pooled in the last run
+    assert_eq!(lists[3].capacity(), 4);
+    assert_eq!(alloc.live.get(), 7);
+}
+
+#[test]
+fn batch_processing() {
+    let jobs = (1..=7).collect::<ReversibleList<_>>();
+    let mut batches = jobs.chunks(3);
+    assert_eq!(batches.len(), 3);
+    assert_eq!(
+        format!("{batches:?}"),
+        "Chunks { size: 3, rest: Iter([1, 2, 3, 4, 5, 6, 7]) }"
+    );
+    let sums = batches
+        .clone()
+        .map(|batch| batch.sum::<i32>())
+        .collect::<Vec<_>>();
+    assert_eq!(sums, [6, 15, 7]);
+
+    let mut first = batches.next().unwrap();
+    assert_eq!(first.len(), 3);
+    assert_eq!(first.next_back(), Some(&3));
+    assert_eq!(batches.nth(1).unwrap().collect::<Vec<_>>(), [&7]);
+    assert!(batches.next().is_none());
+
+    assert_eq!(jobs.chunks(10).count(), 1);
+    assert_eq!(ReversibleList::<u8>::new().chunks(2).count(), 0);
+}
+
+#[test]
+#[should_panic = "chunk size must be non-zero"]
+fn empty_handed_batches() {
+    let _ = ReversibleList::from([1]).chunks(0);
+}
+
+#[test]
+fn moving_average() {
+    let samples = ReversibleList::from([1.0, 3.0, 5.0, 3.0, 1.0]);
+    let windows = samples.windows(3);
+    assert_eq!(windows.len(), 3);
+    let averages = windows
+        .map(|window| window.sum::<f64>() / 3.0)
+        .collect::<Vec<_>>();
+    assert_eq!(averages, [3.0, 11.0 / 3.0, 3.0]);
+
+    let mut pairs = samples.windows(2);
+    assert_eq!(
+        pairs.nth(3).unwrap().rev().collect::<Vec<_>>(),
+        [&1.0, &3.0]
+    );
+    assert!(pairs.next().is_none());
+
+    assert_eq!(samples.windows(5).count(), 1);
+    assert_eq!(samples.windows(6).count(), 0);
+    assert_eq!(ReversibleList::<u8>::new().windows(1).len(), 0);
+}
+
+#[test]
+fn round_robin() {
+    let mut left = ReversibleList::from(["l1", "l2"]);
+    let mut right = ReversibleList::from(["r1", "r2", "r3", "r4"]);
+    let r2 = {
+        let mut cursor = right.cursor_mut_front();
+        cursor.move_next();
+        cursor.handle().unwrap()
+    };
+
+    left.interleave(&mut right);
+    assert_eq!(left, ["l1", "r1", "l2", "r2", "r3", "r4"]);
+    assert!(right.is_empty());
+    left.assert_invariants();
+    right.assert_invariants();
+    // SAFETY: r2 has moved into `left`.
+    assert_eq!(unsafe { left.get_by_handle(r2) }, &"r2");
+
+    let mut longer = ReversibleList::from([1, 3, 5, 6]);
+    longer.interleave(&mut ReversibleList::from([2, 4]));
+    assert_eq!(longer, [1, 2, 3, 4, 5, 6]);
+
+    let mut empty = ReversibleList::new();
+    empty.interleave(&mut longer);
+    assert_eq!(empty, [1, 2, 3, 4, 5, 6]);
+    empty.interleave(&mut longer);
+    assert_eq!(empty.len(), 6);
+    empty.assert_invariants();
+}
+
+#[test]
+fn pairing_socks() {
+    let left = ReversibleList::from(["red", "blue", "green"]);
+    let right = ReversibleList::from([1, 2, 3, 4]);
+    let pairs = left.zip_into(right);
+    assert_eq!(pairs, [("red", 1), ("blue", 2), ("green", 3)]);
+    pairs.assert_invariants();
+
+    let alloc = CountingAlloc::default();
+    let mut left = ReversibleList::new_in(&alloc);
+    left.extend([1, 2]);
+    let zipped = left.zip_into(ReversibleList::from(['a', 'b', 'c']));
+    assert_eq!(zipped, [(1, 'a'), (2, 'b')]);
+    assert_eq!(alloc.live.get(), 2);
+    assert!(ReversibleList::<u8>::new().zip_into(zipped).is_empty());
+}
+
+#[test]
+fn glued_together() {
+    let mut head = ReversibleList::from([1, 2]);
+    let mut tail = ReversibleList::from([3, 4]);
+    let four = tail.cursor_back().handle().unwrap();
+    head.append(&mut tail);
+    assert_eq!(head, [1, 2, 3, 4]);
+    assert!(tail.is_empty());
+    head.assert_invariants();
+    tail.assert_invariants();
+    // SAFETY: The node moved into `head`.
+    assert_eq!(unsafe { head.remove_by_handle(four) }, 4);
+
+    let nested = ReversibleList::from([
+        ReversibleList::from(["a"]),
+        ReversibleList::new(),
+        ReversibleList::from(["b", "c"]),
+    ]);
+    let flat = nested.flatten();
+    assert_eq!(flat, ["a", "b", "c"]);
+    flat.assert_invariants();
+
+    let joined = ReversibleList::concat([head, ReversibleList::from([9])]);
+    assert_eq!(joined, [1, 2, 3, 9]);
+    assert!(ReversibleList::<u8>::concat([]).is_empty());
+}
+
+#[test]
+fn broken_record() {
+    let chorus = ReversibleList::from(["la", "di", "da"]);
+    let song = chorus.repeat(3);
+    assert_eq!(song.len(), 9);
+    assert_eq!(
+        song.iter().rev().take(4).copied().collect::<Vec<_>>(),
+        ["da", "di", "la", "da"]
+    );
+    song.assert_invariants();
+    assert!(chorus.repeat(0).is_empty());
+    assert!(ReversibleList::<u8>::new().repeat(5).is_empty());
+
+    let alloc = CountingAlloc::default();
+    let mut beat = ReversibleList::new_in(&alloc);
+    beat.extend([1, 2]);
+    let first = beat.cursor_front().handle().unwrap();
+    beat.repeat_into(3);
+    assert_eq!(beat, [1, 2, 1, 2, 1, 2]);
+    assert_eq!(alloc.live.get(), 6);
+    beat.assert_invariants();
+    // SAFETY: The original nodes stay where they are.
+    assert_eq!(unsafe { beat.remove_by_handle(first) }, 1);
+
+    beat.repeat_into(1);
+    assert_eq!(beat.len(), 5);
+    beat.repeat_into(0);
+    assert!(beat.is_empty());
+    beat.assert_invariants();
+}
+
+#[test]
+fn cut_and_paste() {
+    let mut shelf = ReversibleList::from([String::from("atlas"), "bible".into(), "comic".into()]);
+    let bible = shelf.cursor_at(1).handle().unwrap();
+    let bible_data: *const String = shelf.iter().nth(1).unwrap();
+
+    let mut cursor = shelf.cursor_mut_front();
+    cursor.move_next();
+    let mut token = cursor.unlink_current().unwrap();
+    assert_eq!(cursor.current().map(String::as_str), Some("comic"));
+    token.get_mut().push('s');
+    assert_eq!(format!("{token:?}"), r#"DetachedNode("bibles")"#);
+
+    cursor.move_next();
+    assert_eq!(cursor.insert_node_before(token), bible);
+    assert_eq!(cursor.index(), Some(1));
+    assert_eq!(shelf, ["bibles", "atlas", "comic"]);
+    // neither the node nor its data moved
+    assert!(std::ptr::eq(shelf.iter().next().unwrap(), bible_data));
+    // SAFETY: The node has been pasted back into the same list.
+    assert_eq!(unsafe { shelf.get_by_handle(bible) }, "bibles");
+    shelf.assert_invariants();
+
+    let mut other = ReversibleList::new();
+    let token = shelf.cursor_mut_back().unlink_current().unwrap();
+    let mut cursor = other.cursor_mut_front();
+    cursor.insert_node_after(token);
+    assert_eq!(cursor.current().map(String::as_str), Some("comic"));
+    assert_eq!(other, ["comic"]);
+    other.assert_invariants();
+    shelf.assert_invariants();
+
+    let token = shelf.cursor_mut_front().unlink_current().unwrap();
+    assert_eq!(token.into_inner(), "bibles");
+    drop(shelf.cursor_mut_front().unlink_current());
+    assert!(shelf.is_empty());
+    assert!(shelf.cursor_mut_front().unlink_current().is_none());
+}
+
+#[test]
+fn where_is_waldo() {
+    let crowd = ReversibleList::from(["anna", "waldo", "bob", "waldo", "carl"]);
+    assert_eq!(crowd.position(|name| *name == "waldo"), Some(1));
+    assert_eq!(crowd.rposition(|name| *name == "waldo"), Some(3));
+    assert_eq!(crowd.position(|name| name.starts_with('c')), Some(4));
+    assert_eq!(crowd.rposition(|name| name.starts_with('a')), Some(0));
+    assert_eq!(crowd.position(|name| name.is_empty()), None);
+
+    let mut looked_at = 0;
+    crowd.rposition(|name| {
+        looked_at += 1;
+        *name == "bob"
+    });
+    assert_eq!(looked_at, 3);
+    assert_eq!(ReversibleList::<u8>::new().rposition(|_| true), None);
+}
+
+#[test]
+fn picky_eater() {
+    let mut plate = ReversibleList::from(["peas", "carrot", "peas", "potato"]);
+    assert_eq!(plate.remove_item(&"peas"), Some("peas"));
+    assert_eq!(plate, ["carrot", "peas", "potato"]);
+    assert_eq!(
+        plate.remove_first(|food| food.starts_with('p')),
+        Some("peas")
+    );
+    assert_eq!(plate.remove_first(|food| food.len() > 10), None);
+    assert_eq!(plate.remove_item(&"potato"), Some("potato"));
+    assert_eq!(plate, ["carrot"]);
+    plate.assert_invariants();
+
+    assert_eq!(plate.remove_item(&"carrot"), Some("carrot"));
+    assert_eq!(plate.remove_item(&"carrot"), None);
+    plate.assert_invariants();
+}
+
+#[test]
+fn weed_the_garden() {
+    let alloc = CountingAlloc::default();
+    let mut garden = ReversibleList::new_in(&alloc);
+    garden.extend(["weed", "rose", "weed", "weed", "tulip", "weed"]);
+    assert_eq!(garden.remove_all(|plant| *plant == "weed"), 4);
+    assert_eq!(garden, ["rose", "tulip"]);
+    garden.assert_invariants();
+    assert_eq!(garden.remove_all(|plant| *plant == "weed"), 0);
+
+    assert_eq!(garden.remove_all(|_| true), 2);
+    assert!(garden.is_empty());
+    garden.assert_invariants();
+    // removed nodes are pooled as usual
+    assert_eq!(alloc.live.get(), 6);
+    garden.shrink_to_fit();
+    assert_eq!(alloc.live.get(), 0);
+}
+
+#[test]
+fn highs_and_lows() {
+    let mut temperatures = ReversibleList::from([12, -3, 25, -3, 25, 7]);
+    assert_eq!(temperatures.cursor_to_min().index(), Some(1));
+    assert_eq!(temperatures.cursor_to_max().index(), Some(4));
+    assert_eq!(
+        temperatures.cursor_to_min_by_key(|t: &i32| t.abs()).index(),
+        Some(1)
+    );
+    assert_eq!(
+        temperatures.cursor_to_max_by_key(|t: &i32| -t).current(),
+        Some(&-3)
+    );
+
+    let mut cursor = temperatures.cursor_mut_to_max();
+    *cursor.current_mut().unwrap() = 30;
+    cursor.insert_after(0);
+    assert_eq!(temperatures, [12, -3, 25, -3, 30, 0, 7]);
+
+    let mut cursor = temperatures.cursor_mut_to_min_by_key(|t: &i32| t.abs());
+    assert_eq!(cursor.remove_current(), Some(0));
+    assert_eq!(cursor.current(), Some(&7));
+    temperatures.cursor_mut_to_min().remove_current();
+    temperatures
+        .cursor_mut_to_max_by_key(|t: &i32| t % 10)
+        .remove_current();
+    assert_eq!(temperatures, [12, 25, -3, 30]);
+    temperatures.assert_invariants();
+
+    let empty = ReversibleList::<u8>::new();
+    assert_eq!(empty.cursor_to_min().current(), None);
+    assert_eq!(empty.cursor_to_max_by_key(|x| *x).index(), None);
+}
+
+#[test]
+fn page_cache() {
+    let mut recency = ReversibleList::new();
+    let mut handles = std::collections::HashMap::new();
+    for page in ["home", "about", "blog"] {
+        handles.insert(page, recency.push_front(page));
+    }
+    assert_eq!(recency, ["blog", "about", "home"]);
+
+    // SAFETY: All handles point into `recency` until their page is evicted.
+    unsafe {
+        recency.touch(handles["home"]);
+        assert_eq!(recency, ["home", "blog", "about"]);
+        recency.touch(handles["home"]);
+        assert_eq!(recency, ["home", "blog", "about"]);
+        recency.touch_back(handles["blog"]);
+        assert_eq!(recency, ["home", "about", "blog"]);
+        recency.touch(handles["blog"]);
+        assert_eq!(*recency.get_by_handle(handles["blog"]), "blog");
+    }
+    recency.assert_invariants();
+
+    let evicted = recency.pop_lru().unwrap();
+    assert_eq!(evicted, "about");
+    let _stale = handles.remove(evicted).unwrap();
+    #[cfg(feature = "checked-handles")]
+    {
+        assert!(recency.try_touch(handles["home"]));
+        assert!(!recency.try_touch(_stale));
+    }
+    assert_eq!(recency.len(), 2);
+    recency.assert_invariants();
+
+    let mut single = ReversibleList::from(["only"]);
+    let only = single.cursor_front().handle().unwrap();
+    // SAFETY: The node is still in the list.
+    unsafe { single.touch_back(only) };
+    assert_eq!(single, ["only"]);
+    single.assert_invariants();
+}
+
+#[test]
+fn shared_workbench() {
+    let bench = SyncReversibleList::new();
+    std::thread::scope(|scope| {
+        for worker in 0..4 {
+            let bench = &bench;
+            scope.spawn(move || {
+                for job in 0..100 {
+                    if job % 2 == 0 {
+                        bench.push_back(worker * 100 + job);
+                    } else {
+                        bench.push_front(worker * 100 + job);
+                    }
+                }
+            });
+        }
+    });
+    assert_eq!(bench.len(), 400);
+
+    let taken = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| (0..50).filter_map(|_| bench.pop_front()).count()))
+            .collect();
+        workers
+            .into_iter()
+            .map(|w| w.join().unwrap())
+            .sum::<usize>()
+    });
+    assert_eq!(taken, 200);
+
+    let second_last = bench.with_cursor_back(|cursor| {
+        cursor.move_prev();
+        cursor.remove_current()
+    });
+    assert!(second_last.is_some());
+    assert_eq!(bench.len(), 199);
+    bench.with_cursor_front(|cursor| cursor.insert_before(-1));
+    assert_eq!(bench.lock().iter().next(), Some(&-1));
+
+    // a panic while locked doesn't take the list down with it
+    let poisoner = std::panic::catch_unwind(|| {
+        let _guard = bench.lock();
+        panic!("whoops");
+    });
+    assert!(poisoner.is_err());
+    assert_eq!(bench.pop_front(), Some(-1));
+    {
+        let _guard = bench.lock();
+        assert_eq!(format!("{bench:?}"), "SyncReversibleList(<locked>)");
+    }
+
+    let list = bench.into_inner();
+    assert_eq!(list.len(), 199);
+    list.assert_invariants();
+}
+
+#[test]
+fn event_pipeline() {
+    let (producer, mut consumer) = crate::mpsc::queue();
+    assert!(consumer.is_empty());
+    assert_eq!(consumer.pop_front(), None);
+
+    std::thread::scope(|scope| {
+        for source in 0..4 {
+            let producer = producer.clone();
+            scope.spawn(move || {
+                for event in 0..250 {
+                    producer.push_back((source, event));
+                }
+            });
+        }
+    });
+
+    let mut log = ReversibleList::new();
+    assert_eq!(consumer.drain_into(&mut log), 1000);
+    assert!(consumer.is_empty());
+    // events of the same source stay in order
+    for source in 0..4 {
+        let events: Vec<_> = log
+            .iter()
+            .filter(|(s, _)| *s == source)
+            .map(|(_, e)| *e)
+            .collect();
+        assert_eq!(events, (0..250).collect::<Vec<_>>());
+    }
+
+    // leftover elements are dropped along with the queue
+    let witness = std::rc::Rc::new(());
+    let (producer, mut consumer) = crate::mpsc::queue();
+    for _ in 0..3 {
+        producer.push_back(witness.clone());
+    }
+    drop(consumer.pop_front());
+    drop(consumer);
+    producer.push_back(witness.clone());
+    assert_eq!(std::rc::Rc::strong_count(&witness), 4);
+    drop(producer);
+    assert_eq!(std::rc::Rc::strong_count(&witness), 1);
+
+    let (producer, consumer) = crate::mpsc::queue();
+    producer.push_back(1);
+    producer.push_back(2);
+    assert_eq!(consumer.collect::<Vec<_>>(), [1, 2]);
+}
+
+#[test]
+fn family_tree() {
+    let roots: PersistentList<_> = ["grandma", "great-grandma"].into_iter().collect();
+    let mom = roots.prepend("mom");
+    let aunt = roots.prepend("aunt");
+    let mut me = mom.prepend("me");
+    assert_eq!(
+        me.iter().copied().collect::<Vec<_>>(),
+        ["me", "mom", "grandma", "great-grandma"]
+    );
+    assert_eq!(aunt.len(), 3);
+    assert!(mom.tail().ptr_eq(&aunt.tail()));
+    assert_eq!(mom.tail(), roots);
+
+    let snapshot = me.clone();
+    me.push_front("my kid");
+    assert_eq!(snapshot.front(), Some(&"me"));
+    assert_eq!(me.pop_front(), Some("my kid"));
+    assert_eq!(me, snapshot);
+    assert_eq!(
+        format!("{:?}", aunt.iter()),
+        r#"Iter(["aunt", "grandma", "great-grandma"])"#
+    );
+
+    let list: ReversibleList<_> = me.clone().into();
+    assert_eq!(list, ["me", "mom", "grandma", "great-grandma"]);
+    assert_eq!(mom.to_list(), ["mom", "grandma", "great-grandma"]);
+    assert_eq!(PersistentList::from(list), me);
+    assert!(PersistentList::<u8>::new().tail().is_empty());
+
+    // long chains don't blow the stack when dropped
+    let len = if cfg!(miri) { 1_000 } else { 200_000 };
+    let long: PersistentList<_> = (0..len).collect();
+    let shared_half = (0..len / 2).fold(long.clone(), |list, _| list.tail());
+    drop(long);
+    assert_eq!(shared_half.front(), Some(&(len / 2)));
+
+    // neither do snapshots sharing a tail which are dropped on different threads at once
+    let snapshots = [shared_half.prepend(0), shared_half.prepend(1)];
+    drop(shared_half);
+    std::thread::scope(|s| {
+        for snapshot in snapshots {
+            s.spawn(move || drop(snapshot));
+        }
+    });
+}
+
+#[test]
+fn photocopier() {
+    let alloc = CountingAlloc::default();
+    let mut original = ReversibleList::new_in(&alloc);
+    original.extend(["draft", "notes"]);
+    let mut master = CowList::from(original);
+    let copies: Vec<_> = (0..10).map(|_| master.clone()).collect();
+    assert_eq!(alloc.live.get(), 2);
+    assert!(master.is_shared());
+    assert!(copies
+        .iter()
+        .all(|copy| copy.ptr_eq(&master) && *copy == master));
+    assert_eq!(copies[3].iter().next(), Some(&"draft"));
+
+    master.make_mut().push_back("final");
+    assert_eq!(alloc.live.get(), 5);
+    assert!(!master.is_shared());
+    master.make_mut().push_back("signed");
+    assert_eq!(alloc.live.get(), 6);
+    assert_eq!(*master, ["draft", "notes", "final", "signed"]);
+    assert_eq!(*copies[0], ["draft", "notes"]);
+    assert_ne!(master, copies[0]);
+
+    drop(copies);
+    let list = master.into_inner();
+    assert_eq!(alloc.live.get(), 4);
+    list.assert_invariants();
+    assert_eq!(format!("{:?}", CowList::<u8>::new()), "[]");
+}
+
+#[test]
+fn conga_line() {
+    use crate::intrusive::{IntrusiveList, Link};
+
+    #[derive(Debug)]
+    struct Dancer {
+        name: &'static str,
+        line: Link<Dancer>,
+        stage: Link<Dancer>,
+    }
+    crate::intrusive_adapter!(Line = Dancer { line });
+    crate::intrusive_adapter!(Stage = Dancer { stage });
+
+    let dancers: Vec<_> = ["ada", "bo", "cy", "di"]
+        .into_iter()
+        .map(|name| Dancer {
+            name,
+            line: Link::new(),
+            stage: Link::new(),
+        })
+        .collect();
+    let names = |list: &IntrusiveList<'_, Line>| list.iter().map(|d| d.name).collect::<Vec<_>>();
+
+    let mut line = IntrusiveList::<Line>::new();
+    line.extend(&dancers[..3]);
+    let mut stage = IntrusiveList::<Stage>::new();
+    stage.push_back(&dancers[1]);
+    assert_eq!(names(&line), ["ada", "bo", "cy"]);
+    assert_eq!(
+        line.iter().rev().map(|d| d.name).collect::<Vec<_>>(),
+        ["cy", "bo", "ada"]
+    );
+    assert!(dancers[1].line.is_linked() && dancers[1].stage.is_linked());
+
+    let mut cursor = line.cursor_mut_front();
+    cursor.move_next();
+    assert_eq!(cursor.remove_current().map(|d| d.name), Some("bo"));
+    assert_eq!(cursor.current().map(|d| d.name), Some("cy"));
+    cursor.insert_before(&dancers[3]);
+    assert_eq!(cursor.index(), Some(2));
+    cursor.move_next();
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_prev();
+    cursor.insert_after(&dancers[1]);
+    assert_eq!(names(&line), ["ada", "di", "cy", "bo"]);
+    assert_eq!(line.cursor_back().current().map(|d| d.name), Some("bo"));
+    assert_eq!(stage.pop_front().map(|d| d.name), Some("bo"));
+
+    let twice =
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| line.push_back(&dancers[0])));
+    assert!(twice.is_err());
+    assert_eq!(line.len(), 4);
+    assert_eq!(line.pop_back().map(|d| d.name), Some("bo"));
+    drop(line);
+    assert!(dancers.iter().all(|d| !d.line.is_linked()));
+}
+
+#[test]
+fn exclusive_or() {
+    use crate::xor::XorList;
+
+    let mut list: XorList<_> = (1..=5).collect();
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    assert_eq!(
+        list.iter().rev().copied().collect::<Vec<_>>(),
+        [5, 4, 3, 2, 1]
+    );
+    let mut iter = list.iter();
+    iter.nth(2);
+    assert_eq!(format!("{iter:?}"), "Iter([4, 5])");
+
+    list.reverse();
+    assert_eq!((list.front(), list.back()), (Some(&5), Some(&1)));
+    assert_eq!(list.pop_back(), Some(1));
+    list.push_front(6);
+    assert_eq!(list, [6, 5, 4, 3, 2].into_iter().collect());
+
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(4));
+    assert_eq!((cursor.current(), cursor.index()), (Some(&3), Some(2)));
+    cursor.insert_before(40);
+    cursor.insert_after(30);
+    *cursor.current_mut().unwrap() *= 10;
+    assert_eq!(cursor.index(), Some(3));
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!((cursor.current(), cursor.index()), (Some(&2), Some(5)));
+    assert_eq!(cursor.remove_current(), Some(2));
+    assert_eq!((cursor.current(), cursor.index()), (Some(&30), Some(4)));
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&6));
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [6, 5, 40, 30, 30]);
+    assert_eq!(list.cursor_back().current(), Some(&30));
+
+    assert_eq!(list.clone(), list);
+
+    // freed slots are reused
+    let mut pair = XorList::with_capacity(2);
+    let capacity = pair.capacity();
+    pair.extend((0..capacity).map(|i| i * 2));
+    pair.pop_front();
+    pair.push_back(7);
+    assert_eq!(pair.capacity(), capacity);
+
+    let mut single = XorList::new();
+    single.cursor_mut_back().insert_before(String::from("one"));
+    assert_eq!(
+        single.cursor_mut_front().remove_current().as_deref(),
+        Some("one")
+    );
+    assert!(single.is_empty());
+    single.extend([String::from("dropped"), String::from("with the list")]);
+}
+
+#[test]
+fn express_lanes() {
+    use crate::indexed::IndexedList;
+
+    let size = if cfg!(miri) { 200 } else { 5_000 };
+    let mut list = IndexedList::new();
+    let mut model = Vec::new();
+    // insert all over the place, so nodes of all heights end up everywhere
+    for i in 0..size {
+        let idx = (i * 7919) % (model.len() + 1);
+        list.insert(idx, i);
+        model.insert(idx, i);
+    }
+    assert_eq!(list.len(), size);
+    assert!(list.iter().eq(&model));
+    assert!(list.iter().rev().eq(model.iter().rev()));
+    for idx in (0..size).step_by(97) {
+        assert_eq!(list.get(idx), Some(&model[idx]));
+    }
+    assert_eq!(list.get(size), None);
+
+    for i in 0..size / 2 {
+        let idx = (i * 104_729) % model.len();
+        assert_eq!(list.remove(idx), Some(model.remove(idx)));
+    }
+    assert_eq!(list.remove(model.len()), None);
+    assert!(list.iter().eq(&model));
+
+    // refilling reuses the slots and lanes of removed nodes
+    for i in 0..size / 4 {
+        let idx = (i * 6007) % (model.len() + 1);
+        list.insert(idx, size + i);
+        model.insert(idx, size + i);
+    }
+    assert!(list.iter().eq(&model));
+    for idx in (0..model.len()).step_by(89) {
+        assert_eq!(list.get(idx), Some(&model[idx]));
+    }
+
+    *list.get_mut(3).unwrap() = usize::MAX;
+    model[3] = usize::MAX;
+    let mut cursor = list.cursor_at(3);
+    assert_eq!((cursor.current(), cursor.index()), (&usize::MAX, 3));
+    cursor.move_next();
+    assert_eq!(cursor.current(), &model[4]);
+    cursor.move_to(0);
+    cursor.move_prev();
+    assert_eq!(
+        (cursor.current(), cursor.index()),
+        (model.last().unwrap(), model.len() - 1)
+    );
+    cursor.move_next();
+    assert_eq!(cursor.index(), 0);
+
+    let mut small: IndexedList<_> = ["b"].into_iter().collect();
+    small.push_front("a");
+    small.push_back("c");
+    assert_eq!(format!("{small:?}"), r#"["a", "b", "c"]"#);
+    assert_eq!(small.clone(), small);
+    assert_eq!(small.pop_back(), Some("c"));
+    assert_eq!(small.pop_front(), Some("a"));
+    assert_eq!(small.pop_front(), Some("b"));
+    assert_eq!(small.pop_back(), None);
+    small.push_back("reused");
+    assert_eq!(small.get(0), Some(&"reused"));
+}
+
+#[test]
+fn thumbing_through() {
+    let mut list: ReversibleList<_> = (0..10).collect();
+    for idx in 0..10 {
+        assert_eq!(list.get(idx), Some(&idx));
+    }
+    for idx in (0..10).rev() {
+        assert_eq!(list.cursor_at(idx).current(), Some(&idx));
+    }
+    assert_eq!(list.get(10), None);
+
+    // the remembered node must not survive changes to the list
+    assert_eq!(list.get(5), Some(&5));
+    list.pop_front();
+    assert_eq!(list.get(5), Some(&6));
+    list.push_front(0);
+    assert_eq!(list.get(5), Some(&5));
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_next();
+    let mut tail = cursor.split_rest();
+    assert_eq!(list.get(0), Some(&0));
+    assert_eq!(list.get(5), None);
+    assert_eq!(tail.get(4), Some(&5));
+    list.append(&mut tail);
+    assert_eq!(tail.get(4), None);
+    assert_eq!(list.get(5), Some(&5));
+
+    *list.get_mut(7).unwrap() *= 10;
+    assert_eq!(list.get_mut(10), None);
+
+    let mut inserted = ReversibleList::new();
+    for (idx, item) in [(0, 'b'), (0, 'a'), (2, 'd'), (2, 'c'), (4, 'e')] {
+        inserted.insert(idx, item);
+        inserted.assert_invariants();
+    }
+    assert_eq!(inserted, ['a', 'b', 'c', 'd', 'e']);
+    let handle = inserted.insert(3, 'x');
+    // SAFETY: The node was just inserted.
+    assert_eq!(unsafe { inserted.get_by_handle(handle) }, &'x');
+    assert_eq!(inserted, ['a', 'b', 'c', 'x', 'd', 'e']);
+    assert_eq!(list, [0, 1, 2, 3, 4, 5, 6, 70, 8, 9]);
+}
+
+#[test]
+#[should_panic = "tried to insert at index 2 but the len is 1"]
+fn insert_out_of_bounds() {
+    ReversibleList::from([1]).insert(2, 2);
+}
+
+#[test]
+#[cfg(feature = "slab")]
+fn packed_lunch() {
+    use crate::slab::SlabList;
+
+    let mut list: SlabList<_> = (1..=4).collect();
+    let zero = list.push_front(0);
+    let five = list.push_back(5);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(list.iter().rev().nth(1), Some(&4));
+    let mut iter = list.iter();
+    iter.nth(3);
+    assert_eq!(format!("{iter:?}"), "Iter([4, 5])");
+
+    for item in &mut list {
+        *item *= 10;
+    }
+    assert_eq!((list.get(2), list.get(6)), (Some(&20), None));
+    *list.get_mut(3).unwrap() += 1;
+    list.insert(3, 25);
+    list.insert(list.len(), 60);
+    assert_eq!(list, [0, 10, 20, 25, 31, 40, 50, 60].into_iter().collect());
+
+    // handles notice when their element is gone, even if its slot is reused
+    assert_eq!(list.try_remove_by_handle(zero), Some(0));
+    assert_eq!(list.try_remove_by_handle(zero), None);
+    let reused = list.push_front(-1);
+    assert!(!list.contains_handle(zero));
+    assert_eq!(list.try_get_by_handle(reused), Some(&-1));
+    *list.try_get_by_handle_mut(five).unwrap() += 5;
+    assert_eq!(list.try_get_by_handle(five), Some(&55));
+
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_to(4);
+    assert_eq!(cursor.remove_current(), Some(31));
+    assert_eq!((cursor.current(), cursor.index()), (Some(&40), Some(4)));
+    cursor.insert_before(35);
+    let handle = cursor.insert_after(45);
+    assert_eq!(cursor.index(), Some(5));
+    cursor.move_prev();
+    cursor.move_prev();
+    *cursor.current_mut().unwrap() += 1;
+    cursor.move_to(8);
+    assert_eq!(cursor.remove_current(), Some(60));
+    assert_eq!((cursor.current(), cursor.index()), (Some(&55), Some(7)));
+    cursor.move_next();
+    assert_eq!(cursor.handle(), list.cursor_front().handle());
+    assert_eq!(list.try_get_by_handle(handle), Some(&45));
+    assert_eq!(
+        list.clone().into_iter().rev().collect::<Vec<_>>(),
+        [55, 45, 40, 35, 26, 20, 10, -1]
+    );
+    assert_eq!(list.cursor_at(3).current(), Some(&26));
+    assert_eq!(list.cursor_back().index(), Some(7));
+
+    let capacity = list.capacity();
+    list.clear();
+    assert!(list.is_empty() && list.try_get_by_handle(handle).is_none());
+    list.extend(std::iter::repeat_n(7, capacity));
+    assert_eq!(list.capacity(), capacity);
+    assert_eq!(list.pop_back(), Some(7));
+
+    let mut empty = SlabList::new();
+    assert_eq!(empty.cursor_mut_back().remove_current(), None);
+    empty.cursor_mut_back().insert_before(String::from("only"));
+    assert_eq!(format!("{empty:?}"), r#"["only"]"#);
+}
+
+#[test]
+fn merry_go_round() {
+    use crate::circular::CircularList;
+
+    let mut ring: CircularList<_> = ["ann", "bo", "cy", "di"].into_iter().collect();
+    assert_eq!(ring.head(), Some(&"ann"));
+    assert_eq!(ring.get(9), Some(&"bo"));
+
+    let turns: Vec<_> = (0..6).map(|_| *ring.round_robin().unwrap()).collect();
+    assert_eq!(turns, ["ann", "bo", "cy", "di", "ann", "bo"]);
+    assert_eq!(ring.head(), Some(&"cy"));
+
+    ring.rotate_prev();
+    assert_eq!(
+        ring.iter().copied().collect::<Vec<_>>(),
+        ["bo", "cy", "di", "ann"]
+    );
+    ring.rotate_backward(6);
+    assert_eq!(ring.head(), Some(&"di"));
+    ring.rotate_forward(4);
+    assert_eq!(ring.head(), Some(&"di"));
+    ring.rotate_to(3);
+    assert_eq!(ring.head(), Some(&"cy"));
+    let mut iter = ring.iter_from(2);
+    iter.next();
+    assert_eq!(format!("{iter:?}"), r#"Iter(["bo", "cy", "di"])"#);
+
+    let eve = ring.push("eve");
+    ring.push_after_head("fay");
+    assert_eq!(
+        format!("{ring:?}"),
+        r#"["cy", "fay", "di", "ann", "bo", "eve"]"#
+    );
+    // SAFETY: `eve` is still in the ring.
+    unsafe { ring.rotate_to_handle(eve) };
+    *ring.head_mut().unwrap() = "eva";
+    assert_eq!(ring.pop_head(), Some("eva"));
+
+    let mut more: CircularList<_> = ["gus", "hal"].into_iter().collect();
+    ring.append(&mut more);
+    assert!(more.is_empty());
+    let mut cursor = ring.cursor_mut_head();
+    cursor.move_prev();
+    assert_eq!(cursor.remove_current(), Some("hal"));
+
+    let list = ring.clone().into_list();
+    list.assert_invariants();
+    assert_eq!(list, ["cy", "fay", "di", "ann", "bo", "gus"]);
+    assert_eq!(ring.cursor_head().current(), Some(&"cy"));
+    assert_ne!(ring, {
+        let mut rotated = ring.clone();
+        rotated.rotate_next();
+        rotated
+    });
+
+    let mut empty = CircularList::<u8>::default();
+    empty.rotate_forward(3);
+    empty.rotate_prev();
+    assert_eq!(empty.round_robin(), None);
+    assert_eq!(empty.get(2), None);
+    assert_eq!(empty.iter_from(5).count(), 0);
+}
+
+#[test]
+fn word_processor() {
+    use crate::text::TextList;
+
+    let mut text = TextList::from("Hello, wörld!");
+    assert_eq!((text.len(), text.len_chars()), (14, 13));
+    text.insert_str(7, "dear ");
+    text.insert_char(text.len(), '🦀');
+    assert_eq!(text, "Hello, dear wörld!🦀");
+    assert_eq!(text.slice(12..18), "wörld");
+    assert_eq!((text.char_to_byte(14), text.byte_to_char(15)), (15, 14));
+    text.remove(5..12);
+    assert_eq!(text.to_string(), "Hellowörld!🦀");
+    assert!(!text.is_char_boundary(7) && !text.is_char_boundary(100));
+
+    let mut cursor = text.cursor_at(5);
+    assert_eq!(
+        (cursor.char_before(), cursor.char_after()),
+        (Some('o'), Some('w'))
+    );
+    cursor.insert_str(", ");
+    assert!(cursor.move_right() && cursor.move_right());
+    assert_eq!(cursor.delete_forward(), Some('r'));
+    assert_eq!(cursor.delete_backward(), Some('ö'));
+    cursor.insert_char('o');
+    cursor.move_to(0);
+    assert!(!cursor.move_left());
+    assert_eq!(cursor.position(), 0);
+    assert_eq!(format!("{text:?}"), r#""Hello, wold!🦀""#);
+
+    // scatter edits all over a long text and compare against a plain string
+    let mut model = String::new();
+    let mut text = TextList::new();
+    let mut seed = 0x2545_f491_u64;
+    let rounds = if cfg!(miri) { 60 } else { 2_000 };
+    for round in 0..rounds {
+        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+        let mut idx = (seed >> 33) as usize % (model.len() + 1);
+        while !model.is_char_boundary(idx) {
+            idx -= 1;
+        }
+        if round % 3 == 2 {
+            let mut end = (idx + (seed % 90) as usize).min(model.len());
+            while !model.is_char_boundary(end) {
+                end -= 1;
+            }
+            model.replace_range(idx..end, "");
+            text.remove(idx..end);
+        } else {
+            let insert = [
+                "a",
+                "βγ",
+                "✓ done ",
+                "longer piece of text, which spans chunks! ",
+            ][(seed % 4) as usize]
+                .repeat((seed % 5) as usize);
+            model.insert_str(idx, &insert);
+            text.insert_str(idx, &insert);
+        }
+        assert_eq!(
+            (text.len(), text.len_chars()),
+            (model.len(), model.chars().count())
+        );
+    }
+    assert_eq!(text, &*model);
+    assert!(text
+        .chunks()
+        .all(|chunk| !chunk.is_empty() && chunk.len() <= 64));
+    assert!(text.chars().eq(model.chars()));
+    assert_eq!(text.clone(), text);
+
+    // and once more with a single cursor, which has to keep track of its chunk throughout
+    let mut cursor = text.cursor_at(0);
+    let mut position = 0;
+    for round in 0..rounds {
+        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+        match round % 5 {
+            0 => {
+                position = (seed >> 33) as usize % (model.len() + 1);
+                while !model.is_char_boundary(position) {
+                    position -= 1;
+                }
+                cursor.move_to(position);
+            }
+            1 => {
+                let insert = [
+                    "ü",
+                    "typing along ",
+                    "longer piece of text, which spans chunks! ",
+                ][(seed % 3) as usize]
+                    .repeat((seed % 4) as usize);
+                cursor.insert_str(&insert);
+                model.insert_str(position, &insert);
+                position += insert.len();
+            }
+            2 => {
+                if let Some(ch) = cursor.delete_backward() {
+                    position -= ch.len_utf8();
+                    assert_eq!(model.remove(position), ch);
+                }
+            }
+            3 => {
+                if let Some(ch) = cursor.delete_forward() {
+                    assert_eq!(model.remove(position), ch);
+                }
+            }
+            _ => {
+                if cursor.move_right() {
+                    position += model[position..].chars().next().unwrap().len_utf8();
+                }
+            }
+        }
+        assert_eq!(cursor.position(), position);
+        assert_eq!(cursor.char_after(), model[position..].chars().next());
+        assert_eq!(cursor.char_before(), model[..position].chars().next_back());
+    }
+    assert_eq!(text, &*model);
+    assert_eq!(text.len_chars(), model.chars().count());
+    text.remove(..);
+    assert!(text.is_empty() && text.chunks().next().is_none());
+}
+
+#[test]
+fn ctrl_z() {
+    use crate::journal::JournaledList;
+
+    let mut list: JournaledList<_> = (1..=5).map(|i| i.to_string()).collect();
+    let original = list.to_vec();
+    assert!(!list.can_undo() && !list.undo());
+
+    let handle = list.insert(2, "new".into());
+    list.remove(0);
+    list.push_back("last".into());
+    list.reverse();
+    list.push_front("first".into());
+    let mut other: ReversibleList<_> = ["x", "y"].map(String::from).into_iter().collect();
+    list.splice(3, &mut other);
+    assert!(other.is_empty());
+    list.remove_range(1..3);
+    list.remove_range(..0);
+    let edited = list.to_vec();
+    assert_eq!(edited, ["first", "x", "y", "4", "3", "new", "2"]);
+    list.assert_invariants();
+
+    // undoing goes through the very same nodes, so the handle stays valid until its insertion
+    // is undone
+    // SAFETY: The node is in the list until the very first edit is undone.
+    let data_ptr: *const String = unsafe { list.get_by_handle(handle) };
+    for _ in 0..6 {
+        assert!(list.undo());
+        list.assert_invariants();
+        assert!(std::ptr::eq(
+            unsafe { list.get_by_handle(handle) },
+            data_ptr
+        ));
+    }
+    assert!(list.undo());
+    assert_eq!(list.to_vec(), original);
+    assert!(!list.undo());
+
+    while list.redo() {
+        list.assert_invariants();
+    }
+    assert_eq!(list.to_vec(), edited);
+    assert!(!list.can_redo());
+
+    // a new edit drops everything which was undone
+    list.undo();
+    list.undo();
+    *list.get_mut(0).unwrap() = "FIRST".into();
+    for item in list.iter_mut() {
+        item.make_ascii_lowercase();
+    }
+    list.remove(0);
+    assert!(!list.can_redo());
+    list.clear_history();
+    assert!(!list.can_undo());
+    assert_eq!(
+        list.into_inner().to_vec(),
+        ["last", "5", "4", "3", "new", "2"]
+    );
+}
+
+#[test]
+fn nosy_neighbour() {
+    use crate::observed::{ObservedList, Observer};
+    use std::collections::HashMap;
+
+    /// Counts how often each element is in the list.
+    #[derive(Default)]
+    struct Census {
+        counts: HashMap<u32, usize>,
+        reversals: usize,
+    }
+
+    impl Observer<u32> for Census {
+        fn on_insert(&mut self, item: &u32) {
+            *self.counts.entry(*item).or_default() += 1;
+        }
+
+        fn on_remove(&mut self, item: &u32) {
+            let count = self.counts.get_mut(item).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(item);
+            }
+        }
+
+        fn on_reverse(&mut self) {
+            self.reversals += 1;
+        }
+    }
+
+    fn in_sync(list: &ObservedList<u32, Census>) -> bool {
+        let mut counts = HashMap::new();
+        for item in list.iter() {
+            *counts.entry(*item).or_default() += 1;
+        }
+        counts == list.observer().counts
+    }
+
+    let mut list = ObservedList::with_list([1, 2, 2].into_iter().collect(), Census::default());
+    assert!(in_sync(&list));
+
+    list.push_front(3);
+    let handle = list.push_back(4);
+    list.insert(2, 2);
+    list.extend([5, 5, 6]);
+    let mut other: ReversibleList<_> = [7, 1].into_iter().collect();
+    list.append(&mut other);
+    assert!(other.is_empty());
+    assert!(in_sync(&list));
+    assert_eq!(list.to_vec(), [3, 1, 2, 2, 2, 4, 5, 5, 6, 7, 1]);
+
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.pop_back(), Some(1));
+    assert_eq!(list.remove(1), Some(2));
+    assert_eq!(list.remove(100), None);
+    // SAFETY: The node is still in the list.
+    assert_eq!(unsafe { list.remove_by_handle(handle) }, 4);
+    assert_eq!(list.remove_all(|&item| item == 5), 2);
+    assert!(in_sync(&list));
+    assert_eq!(list.to_vec(), [1, 2, 2, 6, 7]);
+
+    list.reverse();
+    list.reverse();
+    list.reverse();
+    assert_eq!(list.observer().reversals, 3);
+    assert_eq!(list.to_vec(), [7, 6, 2, 2, 1]);
+    list.assert_invariants();
+
+    // mutating elements in place isn't reported, the observer has to be told by hand
+    *list.get_mut(0).unwrap() = 8;
+    list.observer_mut().on_remove(&7);
+    list.observer_mut().on_insert(&8);
+    assert!(in_sync(&list));
+
+    list.clear();
+    assert!(list.is_empty() && list.observer().counts.is_empty());
+
+    let (list, census) = list.into_parts();
+    assert!(list.is_empty());
+    assert_eq!(census.reversals, 3);
+
+    // the unit observer ignores everything
+    let mut list = ObservedList::new(());
+    list.extend(0..4);
+    list.reverse();
+    assert_eq!(list.to_vec(), [3, 2, 1, 0]);
+}
+
+#[test]
+fn moving_house() {
+    let mut street: ReversibleList<_> = (1..=10).collect();
+    let mut version = street.version();
+
+    // only structural changes count
+    *street.get_mut(3).unwrap() *= 10;
+    street.iter_mut().for_each(|house| *house += 1);
+    assert_eq!(street.get(7), Some(&9));
+    assert_eq!(street.version(), version);
+
+    let mut check = |street: &ReversibleList<_>, changed: bool| {
+        assert_eq!(street.version() != version, changed);
+        version = street.version();
+    };
+    street.push_back(12);
+    check(&street, true);
+    street.pop_front();
+    check(&street, true);
+    let moved = {
+        let mut cursor = street.cursor_mut_front();
+        cursor.move_to(4);
+        cursor.split_rest()
+    };
+    check(&street, true);
+    street.append(&mut moved.clone());
+    check(&street, true);
+    street.append(&mut ReversibleList::new());
+    check(&street, false);
+    street.cursor_mut_front().remove_current();
+    check(&street, true);
+    street.cursor_mut_back().move_to(2);
+    check(&street, false);
+}
+
+#[test]
+#[cfg(feature = "checked-versions")]
+#[should_panic = "tried to restore a position at index 4 but its node moved to index 5 since"]
+fn stale_bookmark() {
+    let mut book: ReversibleList<_> = (1..=10).collect();
+    let mut reader = book.cursor_mut_front();
+    reader.move_to(4);
+    let bookmark = reader.save();
+
+    // removals after the bookmark are fine
+    reader.move_to(8);
+    reader.remove_current();
+    // SAFETY: Checked by the feature.
+    unsafe { reader.restore(bookmark) };
+
+    reader.insert_before(0);
+    // SAFETY: Checked by the feature, which panics here.
+    unsafe { reader.restore(bookmark) };
+}
+
+#[test]
+#[cfg(feature = "checked-versions")]
+#[should_panic = "tried to restore a position saved on an empty list, which has elements now"]
+fn bookmark_in_blank_book() {
+    let mut book = ReversibleList::new();
+    let mut reader = book.cursor_mut_front();
+    let bookmark = reader.save();
+
+    // nothing happened in between, or the book is blank again
+    // SAFETY: Checked by the feature.
+    unsafe { reader.restore(bookmark) };
+    reader.insert_after(1);
+    reader.remove_current();
+    // SAFETY: Checked by the feature.
+    unsafe { reader.restore(bookmark) };
+
+    reader.insert_after(1);
+    reader.insert_after(2);
+    // SAFETY: Checked by the feature, which panics here.
+    unsafe { reader.restore(bookmark) };
+}
+
+#[test]
+fn leaderboard() {
+    use crate::indexed::IndexedList;
+
+    // players sorted by descending score, tracked by handle while their rank changes
+    let players = if cfg!(miri) { 60 } else { 1_000 };
+    let mut board = IndexedList::new();
+    let mut scores = vec![0; players];
+    let mut handles: Vec<_> = (0..players).map(|player| board.push_back(player)).collect();
+    for (player, &handle) in handles.iter().enumerate() {
+        assert_eq!(board.index_of(handle), Some(player));
+    }
+
+    for round in 0..players * 3 {
+        let player = (round * 7919) % players;
+        scores[player] += round % 13;
+        assert_eq!(board.try_remove_by_handle(handles[player]), Some(player));
+        assert_eq!(board.index_of(handles[player]), None);
+
+        let rank = board
+            .iter()
+            .position(|&other| scores[other] < scores[player])
+            .unwrap_or(board.len());
+        handles[player] = board.insert(rank, player);
+    }
+
+    for (player, &handle) in handles.iter().enumerate() {
+        let rank = board.index_of(handle).unwrap();
+        assert_eq!(board.get(rank), Some(&player));
+        assert_eq!(board.select(rank), Some(handle));
+        assert_eq!(board.try_get_by_handle(handle), Some(&player));
+    }
+    assert!(board
+        .iter()
+        .is_sorted_by_key(|&player| std::cmp::Reverse(scores[player])));
+    assert_eq!(board.select(players), None);
+
+    let leader = board.select(0).unwrap();
+    assert_eq!(board.cursor_at(0).handle(), leader);
+    *board.try_get_by_handle_mut(leader).unwrap() = usize::MAX;
+    assert_eq!(board.pop_front(), Some(usize::MAX));
+    assert!(!board.contains_handle(leader));
+    assert_eq!(board.try_get_by_handle(leader), None);
+    // the freed slot is reused, but the old handle doesn't match the new element
+    let newcomer = board.push_back(players);
+    assert!(!board.contains_handle(leader) && board.contains_handle(newcomer));
+    assert_eq!(board.index_of(newcomer), Some(players - 1));
+}
+
+#[test]
+fn triage() {
+    use crate::sorted::SortedList;
+    use std::{cmp, ops::Bound};
+
+    // patients by urgency, first come first served among equally urgent ones
+    let mut room = SortedList::new_by_key(|&(urgency, _): &(u8, &str)| cmp::Reverse(urgency));
+    room.extend([(2, "sprained ankle"), (5, "broken arm"), (2, "cough")]);
+    room.insert((9, "heart attack"));
+    room.insert((5, "concussion"));
+    room.insert((0, "paperwork"));
+    assert!(room.is_sorted_by_key(|&(urgency, _)| cmp::Reverse(urgency)));
+    assert_eq!(room.first(), Some(&(9, "heart attack")));
+    assert_eq!(room.last(), Some(&(0, "paperwork")));
+
+    assert_eq!(room.pop_min(), Some((9, "heart attack")));
+    assert_eq!(room.pop_min(), Some((5, "broken arm")));
+    assert_eq!(room.pop_max(), Some((0, "paperwork")));
+    assert_eq!(room.pop_max(), Some((2, "cough")));
+    assert_eq!(room.len(), 2);
+
+    let mut numbers: SortedList<_> = [8, 3, 5, 1, 9, 5, 2, 7].into_iter().collect();
+    assert_eq!(numbers.to_vec(), [1, 2, 3, 5, 5, 7, 8, 9]);
+    assert!(numbers.range(3..=7).eq(&[3, 5, 5, 7]));
+    assert!(numbers.range(4..7).eq(&[5, 5]));
+    assert!(numbers
+        .range((Bound::Excluded(5), Bound::Unbounded))
+        .eq(&[7, 8, 9]));
+    assert_eq!(numbers.range(10..).count(), 0);
+    assert_eq!(numbers.range(6..6).count(), 0);
+
+    assert!(numbers.contains(&5) && !numbers.contains(&4));
+    assert_eq!(numbers.remove(&5), Some(5));
+    assert_eq!(numbers.remove(&4), None);
+
+    let middle = numbers.drain_range(2..8);
+    assert_eq!(middle.to_vec(), [2, 3, 5, 7]);
+    middle.assert_invariants();
+    numbers.assert_invariants();
+    assert_eq!(numbers.to_vec(), [1, 8, 9]);
+    assert!(numbers.drain_range(..0).is_empty());
+
+    numbers.insert(4);
+    assert_eq!(format!("{numbers:?}"), "[1, 4, 8, 9]");
+    assert_eq!(numbers.clone().into_list().to_vec(), [1, 4, 8, 9]);
+    numbers.clear();
+    assert_eq!(numbers.pop_min(), None);
+
+    let mut by_len = SortedList::new_by(|a: &&str, b: &&str| a.len().cmp(&b.len()));
+    by_len.extend(["three", "one", "eleven", "two"]);
+    assert_eq!(by_len.to_vec(), ["one", "two", "three", "eleven"]);
+}
+
+#[test]
+fn drop_in_replacement() {
+    use crate::deque::ListDeque;
+    use std::collections::VecDeque;
+
+    // runs the very same code against both types, recording everything observable
+    macro_rules! script {
+        ($deque:ident) => {{
+            let mut log = Vec::new();
+            let mut deque: $deque<i32> = (1..=10).collect();
+            deque.push_front(0);
+            deque.push_back(11);
+            log.push(format!(
+                "{:?} {:?} {:?}",
+                deque.front(),
+                deque.back(),
+                deque.len()
+            ));
+            *deque.front_mut().unwrap() -= 1;
+            *deque.back_mut().unwrap() += 1;
+            deque[3] *= 10;
+            deque.swap(1, 5);
+            deque.insert(4, 44);
+            log.push(format!("{:?} {:?}", deque.remove(2), deque.remove(100)));
+            log.push(format!("{deque:?}"));
+
+            deque.rotate_left(3);
+            deque.rotate_right(5);
+            deque.rotate_left(0);
+            deque.rotate_right(deque.len());
+            log.push(format!("{deque:?}"));
+
+            log.push(format!(
+                "{:?} {:?}",
+                deque.swap_remove_front(4),
+                deque.swap_remove_back(2)
+            ));
+            log.push(format!("{:?}", deque.swap_remove_back(99)));
+            deque.retain(|&x| x % 3 != 0);
+            deque.retain_mut(|x| {
+                *x += 1;
+                *x != 9
+            });
+            log.push(format!("{deque:?}"));
+
+            log.push(format!("{:?}", deque.drain(1..3).collect::<Vec<_>>()));
+            log.push(format!("{:?}", deque.range(2..).collect::<Vec<_>>()));
+            deque.range_mut(..=1).for_each(|x| *x = -*x);
+            let mut back = deque.split_off(3);
+            log.push(format!("{deque:?} {back:?}"));
+            deque.append(&mut back);
+            log.push(format!("{deque:?} {}", back.is_empty()));
+
+            deque.resize(10, 7);
+            deque.resize_with(12, || 8);
+            deque.truncate(11);
+            deque.extend(&[9, 9]);
+            deque.make_contiguous();
+            log.push(format!("{deque:?} {}", deque.contains(&8)));
+
+            let mut sorted: $deque<_> = (0..20).map(|x| x * 2).collect();
+            log.push(format!(
+                "{:?} {:?} {:?} {}",
+                sorted.binary_search(&14),
+                sorted.binary_search(&15),
+                sorted.binary_search_by_key(&42, |&x| x + 4),
+                sorted.partition_point(|&x| x < 25)
+            ));
+            sorted.clear();
+            log.push(format!(
+                "{sorted:?} {:?} {:?}",
+                sorted.pop_front(),
+                sorted.back()
+            ));
+
+            for x in &mut deque {
+                *x += 1;
+            }
+            log.push(format!(
+                "{:?}",
+                (&deque).into_iter().rev().collect::<Vec<_>>()
+            ));
+            log.push(format!("{:?}", deque.into_iter().collect::<Vec<_>>()));
+            log
+        }};
+    }
+
+    assert_eq!(script!(ListDeque), script!(VecDeque));
+
+    let deque: ListDeque<_> = VecDeque::from([1, 2, 3]).into();
+    assert_eq!(VecDeque::from(deque.clone()), [1, 2, 3]);
+    assert!(deque < ListDeque::from([1, 2, 4]));
+    assert_eq!(deque.into_list().to_vec(), [1, 2, 3]);
+}
+
+#[test]
+fn flat_pack() {
+    let mut shelf: ReversibleList<_> = ["board", "screw", "dowel"].map(String::from).into();
+    let handle = shelf.push_back("manual".into());
+    shelf.pop_front();
+    let (start, end, len) = shelf.into_raw_parts();
+    assert_eq!(len, 3);
+    assert!(!start.is_null() && !end.is_null());
+
+    // SAFETY: The parts were just returned by `into_raw_parts`.
+    let mut shelf = unsafe { ReversibleList::<String>::from_raw_parts(start, end, len) };
+    shelf.assert_invariants();
+    assert_eq!(shelf.to_vec(), ["screw", "dowel", "manual"]);
+    #[cfg(feature = "checked-handles")]
+    assert!(!shelf.contains_handle(handle));
+    #[cfg(not(feature = "checked-handles"))]
+    // SAFETY: The node is still in the list.
+    assert_eq!(unsafe { shelf.get_by_handle(handle) }, "manual");
+    shelf.push_front("hex key".into());
+
+    let (start, end, len) = ReversibleList::<String>::new().into_raw_parts();
+    assert!(start.is_null() && end.is_null() && len == 0);
+    // SAFETY: Same as above.
+    let empty = unsafe { ReversibleList::<String>::from_raw_parts(start, end, len) };
+    assert!(empty.is_empty());
+
+    let counter = CountingAlloc::default();
+    let mut list = ReversibleList::new_in(&counter);
+    list.extend(0..5);
+    list.pop_back();
+    assert_eq!(counter.live.get(), 5);
+    let (start, end, len, alloc) = list.into_raw_parts_with_alloc();
+    // the pooled node is gone, the others are waiting to be reassembled
+    assert_eq!(counter.live.get(), 4);
+    // SAFETY: The parts were just returned by `into_raw_parts_with_alloc`.
+    let list = unsafe { ReversibleList::<i32, _>::from_raw_parts_in(start, end, len, alloc) };
+    list.assert_invariants();
+    assert_eq!(list.to_vec(), [0, 1, 2, 3]);
+    drop(list);
+    assert_eq!(counter.live.get(), 0);
+}
+
+#[test]
+#[cfg(feature = "capi")]
+fn foreign_exchange() {
+    use crate::capi::*;
+    use std::{ffi::c_void, ptr};
+
+    let mut coins = [1_u8, 2, 5, 10];
+    let [one, two, five, ten] = coins
+        .each_mut()
+        .map(|coin| ptr::from_mut(coin).cast::<c_void>());
+    let mut out = ptr::null_mut();
+    let mut index = usize::MAX;
+
+    // SAFETY: Everything is used as documented, just like C code would.
+    unsafe {
+        let purse = rl_new();
+        assert!(!rl_pop_back(purse, &mut out) && out.is_null());
+        rl_push_back(purse, two);
+        rl_push_front(purse, one);
+        assert!(rl_insert(purse, 2, ten));
+        assert!(!rl_insert(purse, 4, ten));
+        assert_eq!(rl_len(purse), 3);
+        assert!(rl_get(purse, 2, &mut out) && out == ten);
+        assert!(!rl_get(purse, 3, &mut out));
+
+        let cursor = rl_cursor_back(purse);
+        rl_cursor_insert_before(cursor, five);
+        rl_cursor_move_prev(cursor);
+        assert!(rl_cursor_current(cursor, &mut out) && out == five);
+        assert!(rl_cursor_index(cursor, &mut index) && index == 2);
+        rl_cursor_move_next(cursor);
+        rl_cursor_move_next(cursor);
+        assert!(rl_cursor_remove_current(cursor, &mut out) && out == one);
+        rl_cursor_insert_after(cursor, one);
+        rl_cursor_free(cursor);
+
+        (*purse).assert_invariants();
+        let values: Vec<u8> = (*purse).iter().map(|&coin| *coin.cast::<u8>()).collect();
+        assert_eq!(values, [2, 1, 5, 10]);
+        assert!(rl_pop_front(purse, &mut out) && out == two);
+        rl_free(purse);
+        rl_free(ptr::null_mut());
+        rl_cursor_free(ptr::null_mut());
+    }
+}
+
+trait Shape {
+    fn area(&self) -> f64;
+
+    fn scale(&mut self, factor: f64);
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+
+    fn scale(&mut self, factor: f64) {
+        self.0 *= factor;
+    }
+}
+
+struct Circle(f64);
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        3.0 * self.0 * self.0
+    }
+
+    fn scale(&mut self, factor: f64) {
+        self.0 *= factor;
+    }
+}
+
+#[test]
+fn show_and_tell() {
+    let mut shapes: ReversibleList<Box<dyn Shape>> = ReversibleList::new();
+    shapes.push_back(Box::new(Square(2.0)));
+    shapes.push_front(Box::new(Circle(1.0)));
+    assert_eq!(shapes.iter().map(|shape| shape.area()).sum::<f64>(), 7.0);
+
+    for shape in shapes.iter_mut_unboxed() {
+        shape.scale(2.0);
+    }
+    let areas: Vec<f64> = shapes.iter_unboxed().rev().map(Shape::area).collect();
+    assert_eq!(areas, [16.0, 12.0]);
+
+    let words = ReversibleList::from([Box::<str>::from("unsized"), "too".into()]);
+    assert!(words.iter_unboxed().eq(["unsized", "too"]));
+}
+
+#[test]
+#[cfg(feature = "unsize")]
+fn shapeshifter() {
+    let mut shapes = ReversibleList::<Box<dyn Shape>>::new();
+    shapes.push_back_unsized(Square(1.0));
+    let handle = shapes.push_front_unsized(Circle(1.0));
+    // SAFETY: The node is still in the list.
+    assert_eq!(unsafe { shapes.get_by_handle(handle) }.area(), 3.0);
+    assert_eq!(shapes.iter_unboxed().map(Shape::area).sum::<f64>(), 4.0);
+}
+
+#[test]
+fn hopscotch() {
+    for len in 1..=9 {
+        let list = (0..len).collect::<ReversibleList<_>>();
+        for from in 0..len {
+            for to in 0..len {
+                let mut cursor = list.cursor_at(from);
+                cursor.move_to(to);
+                assert_eq!((cursor.index(), cursor.current()), (Some(to), Some(&to)));
+            }
+        }
+    }
+
+    let mut list = (0..100).collect::<ReversibleList<_>>();
+    let mut cursor = list.cursor_mut_front();
+    for target in [50, 98, 1, 49, 51, 0, 99, 3] {
+        cursor.move_to(target);
+        assert_eq!(cursor.current(), Some(&target));
+    }
+}
+
+#[test]
+fn edge_of_the_map() {
+    let mut empty = ReversibleList::<u8>::new();
+    let mut cursor = empty.cursor_mut_front();
+    cursor.move_next_n(3);
+    cursor.move_prev_n(3);
+    assert!(!cursor.try_move_next_n(0));
+    assert!(!cursor.try_move_prev_n(0));
+    assert_eq!(cursor.index(), None);
+
+    let list = ReversibleList::from(['a', 'b', 'c', 'd']);
+    let mut cursor = list.cursor_at(1);
+    assert!(cursor.try_move_next_n(2));
+    assert_eq!(cursor.current(), Some(&'d'));
+    assert!(!cursor.try_move_next_n(1));
+    assert!(!cursor.try_move_next_n(usize::MAX));
+    assert_eq!(cursor.index(), Some(3));
+    assert!(cursor.try_move_prev_n(3));
+    assert_eq!(cursor.current(), Some(&'a'));
+    assert!(!cursor.try_move_prev_n(1));
+    assert!(cursor.try_move_prev_n(0));
+    assert_eq!(cursor.index(), Some(0));
+
+    // the unchecked ones still wrap
+    cursor.move_prev_n(5);
+    assert_eq!(cursor.current(), Some(&'d'));
+}
+
+#[test]
+fn on_a_budget() {
+    /// Fails once the given number of allocations has been made.
+    struct BudgetAlloc {
+        left: Cell<usize>,
+    }
+
+    unsafe impl Allocator for &BudgetAlloc {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let left = self.left.get().checked_sub(1).ok_or(AllocError)?;
+            self.left.set(left);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    let budget = BudgetAlloc { left: Cell::new(3) };
+    let mut list = ReversibleList::new_in(&budget);
+    assert!(list.try_push_back(2).is_ok());
+    assert!(list.try_push_front(0).is_ok());
+    let handle = list.try_insert(1, 1).unwrap();
+    // SAFETY: The node is still in the list.
+    assert_eq!(unsafe { *list.get_by_handle(handle) }, 1);
+
+    let error = list.try_push_back(3).unwrap_err();
+    assert_eq!(error.into_inner(), 3);
+    assert_eq!(list.try_insert(1, 4).map_err(|error| error.0), Err(4));
+    assert_eq!(
+        list.try_push_front(5).unwrap_err().to_string(),
+        "no memory left for another list node"
+    );
+    list.assert_invariants();
+    assert!(list.iter().eq(&[0, 1, 2]));
+
+    // pooled nodes don't need the allocator
+    list.pop_front();
+    let mut cursor = list.cursor_mut_back();
+    assert!(cursor.try_insert_before(6).is_ok());
+    assert_eq!(cursor.index(), Some(2));
+    assert!(cursor.try_insert_after(7).is_err());
+    list.assert_invariants();
+    assert!(list.iter().eq(&[1, 6, 2]));
+
+    let mut empty = ReversibleList::<u8, _>::new_in(&budget);
+    let mut cursor = empty.cursor_mut_front();
+    assert!(cursor.try_insert_after(8).is_err());
+    budget.left.set(1);
+    assert!(cursor.try_insert_after(8).is_ok());
+    assert_eq!(cursor.current(), Some(&8));
+}
+
+#[test]
+fn fixed_pockets() {
+    use crate::fixed::StaticReversibleList;
+
+    const EMPTY: StaticReversibleList<String, 4> = StaticReversibleList::new();
+
+    let mut pocket = EMPTY;
+    assert_eq!(pocket.capacity(), 4);
+    pocket.push_back("keys".to_string());
+    pocket.push_front("lint".to_string());
+    let coin = pocket.insert(1, "coin".to_string());
+    pocket.push_back("phone".to_string());
+    assert!(pocket.is_full());
+    let error = pocket.try_push_back("wallet".to_string()).unwrap_err();
+    assert_eq!(error.into_inner(), "wallet");
+    assert!(pocket.iter().eq(["lint", "coin", "keys", "phone"]));
+    assert!(pocket.iter().rev().eq(["phone", "keys", "coin", "lint"]));
+
+    assert_eq!(pocket.try_remove_by_handle(coin).as_deref(), Some("coin"));
+    assert!(!pocket.contains_handle(coin));
+    for item in &mut pocket {
+        item.make_ascii_uppercase();
+    }
+    assert_eq!(
+        pocket.iter_mut().next_back().map(|item| item.pop()),
+        Some(Some('E'))
+    );
+
+    let mut cursor = pocket.cursor_mut_front();
+    cursor.move_to(1);
+    assert_eq!(cursor.current().map(String::as_str), Some("KEYS"));
+    cursor.insert_before("gum".to_string());
+    assert_eq!(cursor.index(), Some(2));
+    assert!(cursor.try_insert_after("wallet".to_string()).is_err());
+    assert_eq!(cursor.remove_current().as_deref(), Some("KEYS"));
+    assert_eq!(cursor.current().map(String::as_str), Some("PHON"));
+    cursor.move_next();
+    assert_eq!(cursor.index(), Some(0));
+
+    let copy = pocket.clone();
+    assert_eq!(copy, pocket);
+    assert!(pocket.into_iter().rev().eq(["PHON", "gum", "LINT"]));
+}
+
+#[test]
+#[should_panic = "tried to insert into a full list, which holds at most 2 elements"]
+fn pocket_overflow() {
+    crate::fixed::StaticReversibleList::<u8, 2>::from_iter(0..3);
+}
+
+#[test]
+fn hot_loop() {
+    use crate::scratch::ScratchSlot;
+
+    let mut storage = [ScratchSlot::UNINIT; 4];
+    for round in 0..if cfg!(miri) { 3 } else { 100 } {
+        let mut scratch = ReversibleList::new_scratch(&mut storage);
+        assert_eq!(scratch.allocator().capacity(), 4);
+        for item in 0..4 {
+            scratch.try_push_back(vec![round, item]).unwrap();
+        }
+        assert_eq!(
+            scratch.try_push_front(vec![]).unwrap_err().0,
+            Vec::<i32>::new()
+        );
+
+        // freed slots are handed out again, even with pooling turned off
+        scratch.set_pool_limit(0);
+        assert_eq!(scratch.pop_front(), Some(vec![round, 0]));
+        let mut cursor = scratch.cursor_mut_back();
+        assert!(cursor.try_insert_before(vec![-1]).is_ok());
+        assert!(cursor.try_insert_after(vec![-2]).is_err());
+        scratch.assert_invariants();
+        assert!(scratch
+            .iter()
+            .eq(&[vec![round, 1], vec![round, 2], vec![-1], vec![round, 3]]));
+
+        let mut drained = scratch.into_iter();
+        assert_eq!(drained.next_back(), Some(vec![round, 3]));
+    }
+}
+
+#[test]
+fn guest_list() {
+    let mut guests: OrderedMap<String, u32> = OrderedMap::new();
+    for (name, seat) in [("ada", 1), ("bo", 2), ("cy", 3), ("di", 4)] {
+        assert_eq!(guests.insert(name.to_owned(), seat), None);
+    }
+
+    // looking up by `&str` works just like with a `HashMap<String, _>`
+    assert_eq!(guests.get("bo"), Some(&2));
+    assert!(!guests.contains_key("eve"));
+
+    // replacing keeps the position, refreshing moves to the back
+    assert_eq!(guests.insert("ada".to_owned(), 10), Some(1));
+    assert!(guests.keys().eq(["ada", "bo", "cy", "di"]));
+    *guests.get_refresh("bo").unwrap() += 10;
+    assert!(guests.keys().eq(["ada", "cy", "di", "bo"]));
+    assert_eq!(guests.front(), Some((&"ada".to_owned(), &10)));
+    assert_eq!(guests.back(), Some((&"bo".to_owned(), &12)));
+
+    assert_eq!(guests.remove("cy"), Some(3));
+    assert_eq!(guests.remove("cy"), None);
+    assert_eq!(guests.pop_front(), Some(("ada".to_owned(), 10)));
+    assert_eq!(guests.pop_back(), Some(("bo".to_owned(), 12)));
+    assert_eq!(guests.get("bo"), None);
+    assert_eq!(guests.len(), 1);
+
+    for (_, seat) in &mut guests {
+        *seat *= 2;
+    }
+    guests.extend([("eve".to_owned(), 5), ("di".to_owned(), 0)]);
+    assert_eq!(format!("{guests:?}"), r#"{"di": 0, "eve": 5}"#);
+
+    let copy = guests.clone();
+    guests.clear();
+    assert!(guests.is_empty());
+    assert_eq!(guests.get("di"), None);
+    assert!(copy.values().eq(&[0, 5]));
+    assert_eq!(
+        copy.into_iter().collect::<Vec<_>>(),
+        [("di".to_owned(), 0), ("eve".to_owned(), 5)]
+    );
+
+    let counts: OrderedMap<char, usize> = "hello".chars().rev().map(|c| (c, 1)).collect();
+    assert!(counts.keys().eq(&['o', 'l', 'e', 'h']));
+}
+
+#[test]
+fn lookouts() {
+    let list: ReversibleList<_> = (0..10).collect();
+
+    let mut cursors = list.cursors();
+    assert_eq!(cursors.len(), 10);
+    let first = cursors.next().unwrap();
+    let last = cursors.next_back().unwrap();
+    assert_eq!((first.index(), first.current()), (Some(0), Some(&0)));
+    assert_eq!((last.index(), last.current()), (Some(9), Some(&9)));
+    assert_eq!(cursors.len(), 8);
+    assert!(cursors
+        .rev()
+        .all(|cursor| cursor.index().map(|idx| idx as i32) == cursor.current().copied()));
+
+    // cursors are independent of each other and of the iterator
+    let mut second = list.cursors().nth(1).unwrap();
+    second.move_prev();
+    assert_eq!(second.current(), Some(&0));
+
+    let starts = |count| {
+        list.spaced_cursors(count)
+            .iter()
+            .map(|cursor| cursor.index().unwrap())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(starts(1), [0]);
+    assert_eq!(starts(3), [0, 4, 7]);
+    assert_eq!(starts(4), [0, 3, 6, 8]);
+    assert_eq!(starts(5), [0, 2, 4, 6, 8]);
+    assert_eq!(starts(20), (0..10).collect::<Vec<_>>());
+    assert!(starts(0).is_empty());
+    assert!(ReversibleList::<u8>::new().spaced_cursors(4).is_empty());
+    assert_eq!(
+        ReversibleList::<u8>::new()
+            .cursors()
+            .next()
+            .map(|c| c.index()),
+        None
+    );
+
+    // handing each part to its own thread
+    let parts = list.spaced_cursors(3);
+    let sums: Vec<i32> = std::thread::scope(|scope| {
+        let workers: Vec<_> = parts
+            .iter()
+            .enumerate()
+            .map(|(part, start)| {
+                let len = parts
+                    .get(part + 1)
+                    .map_or(list.len(), |next| next.index().unwrap())
+                    - start.index().unwrap();
+                let mut cursor = start.clone();
+                scope.spawn(move || {
+                    (0..len)
+                        .map(|_| {
+                            let item = *cursor.current().unwrap();
+                            cursor.move_next();
+                            item
+                        })
+                        .sum()
+                })
+            })
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+    assert_eq!(sums, [1 + 2 + 3, 4 + 5 + 6, 7 + 8 + 9]);
+}
+
+#[test]
+fn splice_in_one_go() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut list = ReversibleList::new();
+    let mut cursor = list.cursor_mut_front();
+    cursor.insert_all_after(std::iter::empty());
+    assert_eq!(cursor.index(), None);
+    cursor.insert_all_before([3, 4, 5]);
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&3)));
+    cursor.move_next();
+    cursor.insert_all_after([10, 11]);
+    assert_eq!((cursor.index(), cursor.current()), (Some(1), Some(&4)));
+    cursor.insert_all_before(0..3);
+    assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&4)));
+    cursor.move_to(0);
+    cursor.insert_all_before([-1]);
+    cursor.move_prev_n(2);
+    cursor.insert_all_after([20, 21]);
+    assert_eq!(cursor.current(), Some(&5));
+    assert_eq!(list, [-1, 3, 0, 1, 2, 4, 10, 11, 5, 20, 21]);
+    list.assert_invariants();
+
+    let caught = catch_unwind(AssertUnwindSafe(|| {
+        let mut cursor = list.cursor_mut_front();
+        cursor.move_to(2);
+        let outcome = catch_unwind(AssertUnwindSafe(|| {
+            cursor.insert_all_before((100..).inspect(|&item| assert!(item < 102, "tripped")));
+        }));
+        assert!(outcome.is_err());
+        // the cursor still knows where it is
+        assert_eq!((cursor.index(), cursor.current()), (Some(4), Some(&0)));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&101));
+    }));
+    assert!(caught.is_ok());
+    list.assert_invariants();
+    assert_eq!(list, [-1, 3, 100, 101, 0, 1, 2, 4, 10, 11, 5, 20, 21]);
+}
+
+#[test]
+fn how_much_further() {
+    fn around(cursor: &crate::cursor::Cursor<'_, i32>) -> (usize, usize) {
+        (cursor.remaining_len_back(), cursor.remaining_len())
+    }
+
+    let mut list: ReversibleList<_> = (0..5).collect();
+    let mut cursor = list.cursor_front();
+    assert_eq!(around(&cursor), (0, 4));
+    cursor.move_next_n(3);
+    assert_eq!(around(&cursor), (3, 1));
+    cursor.move_next();
+    assert_eq!(around(&cursor), (4, 0));
+    cursor.move_next();
+    assert_eq!(around(&cursor), (0, 4));
+
+    let mut cursor = list.cursor_mut_back();
+    cursor.remove_current();
+    cursor.move_prev();
+    assert_eq!(around(&cursor.cursor()), (2, 1));
+    assert_eq!(cursor.remaining_len(), 1);
+
+    let empty = ReversibleList::new();
+    assert_eq!(around(&empty.cursor_front()), (0, 0));
+}
+
+#[test]
+fn who_is_ahead() {
+    use std::cmp::Ordering;
+
+    // equal elements everywhere, so only the position decides
+    let list = ReversibleList::from([7; 4]);
+    let start = list.cursor_front();
+    let mut end = list.cursor_back();
+    assert!(start < end);
+    assert_ne!(start, end);
+    end.move_next();
+    assert_eq!(start, end);
+    assert_eq!(start.partial_cmp(&end), Some(Ordering::Equal));
+
+    let mut middle = list.cursors().nth(2).unwrap();
+    assert!(start <= middle && middle < list.cursor_back());
+    middle.move_prev_n(2);
+    assert_eq!(middle, start);
+    let positions: HashSet<_> = list.cursors().chain([start, end, middle]).collect();
+    assert_eq!(positions.len(), 4);
+
+    let twin = ReversibleList::from([7; 4]);
+    assert_ne!(list.cursor_front(), twin.cursor_front());
+    assert_eq!(list.cursor_front().partial_cmp(&twin.cursor_front()), None);
+    assert!(!list.cursor_front().le(&twin.cursor_back()));
+
+    let empty = ReversibleList::<u8>::new();
+    assert_eq!(empty.cursor_front(), empty.cursor_back());
+}
+
+#[test]
+fn seat_numbers() {
+    let mut list: ReversibleList<_> = "seats".chars().collect();
+    let mut cursor = list.cursor_mut_front();
+    cursor.move_current_to_back();
+    cursor.move_current_to_back();
+    assert!(list
+        .indexed_iter()
+        .eq([(0, &'a'), (1, &'t'), (2, &'s'), (3, &'s'), (4, &'e')]));
+    for (idx, item) in list.indexed_iter().rev() {
+        assert_eq!(list.get(idx), Some(item));
+        assert_eq!(list.cursor_at(idx).current(), Some(item));
+    }
+
+    let mut seats = list.indexed_iter();
+    assert_eq!(seats.next_back(), Some((4, &'e')));
+    assert_eq!(seats.next(), Some((0, &'a')));
+    assert_eq!(seats.next_back(), Some((3, &'s')));
+    assert_eq!(seats.len(), 2);
+}
+
+#[test]
+fn word_census() {
+    let mut census = ReversibleList::<(&str, usize)>::new();
+    for word in "the cat and the hat and the bat".split(' ') {
+        let mut entry = census.find_or_insert_with(|&(other, _)| other.cmp(word), || (word, 0));
+        entry.current_mut().unwrap().1 += 1;
+    }
+    census.assert_invariants();
+    assert_eq!(
+        census,
+        [("and", 2), ("bat", 1), ("cat", 1), ("hat", 1), ("the", 3)]
+    );
+
+    let cursor = census.find_or_insert_with(|&(other, _)| other.cmp("hat"), || unreachable!());
+    assert_eq!(cursor.index(), Some(3));
+    let cursor = census.find_or_insert_with(|&(other, _)| other.cmp("zebra"), || ("zebra", 0));
+    assert_eq!(cursor.index(), Some(5));
+    let cursor =
+        census.find_or_insert_with(|&(other, _)| other.cmp("aardvark"), || ("aardvark", 0));
+    assert_eq!(
+        (cursor.index(), cursor.current()),
+        (Some(0), Some(&("aardvark", 0)))
+    );
+    assert_eq!(census.len(), 7);
+    assert_eq!(census.get(1), Some(&("and", 2)));
+}
+
+#[test]
+fn streaks() {
+    let rolls = ReversibleList::from([6, 6, 2, 3, 3, 3, 6, 1, 1]);
+    let encoded: Vec<_> = rolls
+        .chunk_by(|a, b| a == b)
+        .map(|run| (*run.clone().next().unwrap(), run.len()))
+        .collect();
+    assert_eq!(encoded, [(6, 2), (2, 1), (3, 3), (6, 1), (1, 2)]);
+
+    // ascending stretches, also checking that the runs iterate backwards
+    let climbs: Vec<Vec<_>> = rolls
+        .chunk_by(|a, b| a < b)
+        .map(|run| run.rev().copied().collect())
+        .collect();
+    assert_eq!(
+        climbs,
+        [
+            vec![6],
+            vec![6],
+            vec![3, 2],
+            vec![3],
+            vec![6, 3],
+            vec![1],
+            vec![1]
+        ]
+    );
+    assert_eq!(ReversibleList::<u8>::new().chunk_by(|_, _| true).count(), 0);
+
+    let alloc = CountingAlloc::default();
+    let mut words = ReversibleList::new_in(&alloc);
+    words.extend(["ant", "ape", "bee", "cat", "cow", "cub"]);
+    let mut by_letter = words.into_chunk_by(|a, b| a[..1] == b[..1]);
+    let ants = by_letter.next().unwrap();
+    ants.assert_invariants();
+    assert_eq!(ants, ["ant", "ape"]);
+    let rest: Vec<_> = by_letter.collect();
+    assert_eq!(rest.len(), 2);
+    assert_eq!(rest[0], ["bee"]);
+    assert_eq!(rest[1], ["cat", "cow", "cub"]);
+    rest[1].assert_invariants();
+    // moved, not reallocated
+    assert_eq!(alloc.live.get(), 6);
+    drop((ants, rest));
+    assert_eq!(alloc.live.get(), 0);
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn deck_of_cards() {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    let mut rng = SmallRng::seed_from_u64(0x5eed);
+    let mut deck: ReversibleList<_> = (0..52).collect();
+    let ace = deck.push_front(100);
+    deck.shuffle(&mut rng);
+    deck.assert_invariants();
+    assert_eq!(deck.len(), 53);
+    assert_ne!(
+        deck.iter().take(10).copied().collect::<Vec<_>>(),
+        (100..110).collect::<Vec<_>>()
+    );
+    let mut sorted = deck.to_vec();
+    sorted.sort_unstable();
+    assert!(sorted.iter().copied().eq((0..52).chain([100])));
+    // SAFETY: Shuffling only relinks nodes, so the handle stays valid.
+    assert_eq!(unsafe { deck.remove_by_handle(ace) }, 100);
+
+    // every order of three cards should come up about equally often
+    let mut hand = ReversibleList::from(['a', 'b', 'c']);
+    let mut seen = std::collections::HashMap::new();
+    let rounds = if cfg!(miri) { 60 } else { 6000 };
+    for _ in 0..rounds {
+        hand.shuffle(&mut rng);
+        *seen.entry(hand.to_vec()).or_insert(0) += 1;
+    }
+    assert_eq!(seen.len(), 6);
+    assert!(seen.values().all(|&count| count > rounds / 12));
+
+    let mut empty = ReversibleList::<u8>::new();
+    empty.shuffle(&mut rng);
+    empty.assert_invariants();
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn lucky_draw() {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    let mut rng = SmallRng::seed_from_u64(7);
+    let mut tickets: ReversibleList<_> = (0..5).collect();
+    let mut drawn = [0; 5];
+    for _ in 0..if cfg!(miri) { 50 } else { 5000 } {
+        let cursor = tickets.choose(&mut rng).unwrap();
+        let idx = cursor.index().unwrap();
+        assert_eq!(cursor.current(), Some(&idx));
+        drawn[idx] += 1;
+    }
+    assert!(drawn.iter().all(|&count| count > 0));
+
+    // a winner can be taken out right away
+    let mut winner = tickets.choose_mut(&mut rng).unwrap();
+    let prize = winner.remove_current().unwrap();
+    assert_eq!(tickets.len(), 4);
+    assert!(tickets.iter().all(|&ticket| ticket != prize));
+
+    assert!(ReversibleList::<u8>::new().choose(&mut rng).is_none());
+    assert!(ReversibleList::<u8>::new().choose_mut(&mut rng).is_none());
+}
+
+#[test]
+fn rear_view() {
+    let list = ReversibleList::from(['a', 'b', 'c', 'd']);
+    let mirror = list.reversed();
+    assert_eq!(format!("{mirror:?}"), "['d', 'c', 'b', 'a']");
+    assert_eq!(mirror.len(), 4);
+    assert_eq!(
+        (mirror.get(0), mirror.get(3), mirror.get(4)),
+        (Some(&'d'), Some(&'a'), None)
+    );
+    assert_eq!(mirror.get(usize::MAX), None);
+    assert!(mirror.into_iter().eq(&['d', 'c', 'b', 'a']));
+    assert!(mirror.iter().rev().eq(list.iter()));
+    assert_eq!(mirror.rev(), &list);
+
+    let mut cursor = mirror.cursor_front();
+    assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&'d')));
+    cursor.move_next();
+    assert_eq!((cursor.index(), cursor.current()), (Some(1), Some(&'c')));
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!((cursor.index(), cursor.current()), (Some(3), Some(&'a')));
+    assert_eq!(cursor.into_inner().index(), Some(0));
+    assert_eq!(mirror.cursor_back().current(), Some(&'a'));
+
+    let empty = ReversibleList::<u8>::new();
+    assert!(empty.reversed().is_empty());
+    assert_eq!(empty.reversed().get(0), None);
+    assert_eq!(empty.reversed().cursor_front().index(), None);
+}
+
+#[test]
+fn shift_rota() {
+    let workers = ReversibleList::from(["ann", "bo", "cy"]);
+    let shifts: Vec<_> = workers.cycle().take(7).copied().collect();
+    assert_eq!(shifts, ["ann", "bo", "cy", "ann", "bo", "cy", "ann"]);
+    assert_eq!(workers.cycle().size_hint(), (usize::MAX, None));
+
+    let mut twice = workers.cycle_n(2);
+    assert_eq!(twice.size_hint(), (6, Some(6)));
+    twice.next();
+    assert_eq!(twice.size_hint(), (5, Some(5)));
+    assert!(twice.eq(["bo", "cy", "ann", "bo", "cy"].iter()));
+    assert_eq!(workers.cycle_n(0).next(), None);
+
+    let nobody = ReversibleList::<&str>::new();
+    assert_eq!(nobody.cycle().next(), None);
+    assert_eq!(nobody.cycle_n(3).count(), 0);
+}
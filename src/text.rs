@@ -0,0 +1,701 @@
+//! A text buffer for editors, storing UTF-8 in a chain of small chunks.
+//!
+//! A [`TextList`] is the classic linked list use case: text which is edited in the middle all
+//! the time. Like an [`UnrolledList`](crate::unrolled::UnrolledList), it stores up to a few
+//! dozen bytes per node, so inserting or deleting only ever shifts the bytes of one or two
+//! chunks, and walking to an index can skip over whole chunks at once.
+//!
+//! Chunks are never split in the middle of a character, so each of them is valid UTF-8 by
+//! itself and can be handed out as a `&str`. Each chunk also remembers how many characters it
+//! holds, so converting between byte and character indices skips over chunks just as quickly.
+//!
+//! All indices are byte indices, unless named otherwise. Just like with `str`, they need to lie
+//! on character boundaries.
+
+use std::{
+    fmt,
+    iter::FusedIterator,
+    ops::{Bound, RangeBounds},
+    str,
+};
+
+use crate::{chunk::Chunk, cursor, iter, ReversibleList};
+
+/// How many bytes a chunk holds at most.
+const CHUNK: usize = 64;
+
+#[derive(Clone)]
+struct Piece {
+    /// Always valid UTF-8, and never empty while in a list.
+    bytes: Chunk<u8, CHUNK>,
+    chars: usize,
+}
+
+impl Piece {
+    /// Copies the given text, which must fit into a chunk.
+    fn new(text: &str) -> Self {
+        let mut bytes = Chunk::new();
+        for &byte in text.as_bytes() {
+            bytes.insert(bytes.len, byte);
+        }
+        Self {
+            bytes,
+            chars: text.chars().count(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.bytes.len
+    }
+
+    fn as_str(&self) -> &str {
+        // SAFETY: Pieces are only ever created from whole `str`s.
+        unsafe { str::from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+}
+
+/// Splits the given text into pieces of about equal size, each fitting into a chunk.
+fn pieces(mut text: &str) -> impl Iterator<Item = Piece> + '_ {
+    let count = text.len().div_ceil(CHUNK);
+    let target = text.len().checked_div(count).unwrap_or(0) + 1;
+    std::iter::from_fn(move || {
+        if text.is_empty() {
+            return None;
+        }
+        let mut cut = target.min(CHUNK).min(text.len());
+        while !text.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        let (piece, rest) = text.split_at(cut);
+        text = rest;
+        Some(Piece::new(piece))
+    })
+}
+
+/// A rope-like text buffer made of small chunks.
+///
+/// See the module docs for details.
+pub struct TextList {
+    pieces: ReversibleList<Piece>,
+    len: usize,
+    chars: usize,
+}
+
+impl TextList {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pieces: ReversibleList::new(),
+            len: 0,
+            chars: 0,
+        }
+    }
+
+    /// Returns the length of the text in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the length of the text in characters, in _O_(1).
+    #[must_use]
+    pub fn len_chars(&self) -> usize {
+        self.chars
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator through the chunks of the text, in order.
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            inner: self.pieces.iter(),
+        }
+    }
+
+    /// Returns an iterator through the characters of the text.
+    pub fn chars(&self) -> Chars<'_> {
+        Chars {
+            chunks: self.chunks(),
+            current: "".chars(),
+        }
+    }
+
+    /// Returns if the given byte index is at the start or end of a character. Indices past the
+    /// end are not.
+    #[must_use]
+    pub fn is_char_boundary(&self, idx: usize) -> bool {
+        match self.locate(idx) {
+            Some((piece, offset)) => piece.current().unwrap().as_str().is_char_boundary(offset),
+            None => idx <= self.len,
+        }
+    }
+
+    /// Returns the byte index of the character with the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `char_idx` is larger than [`Self::len_chars`].
+    #[must_use]
+    pub fn char_to_byte(&self, mut char_idx: usize) -> usize {
+        assert!(
+            char_idx <= self.chars,
+            "char index {char_idx} is out of bounds of {} chars",
+            self.chars
+        );
+
+        let mut byte_idx = 0;
+        for piece in &self.pieces {
+            if char_idx < piece.chars {
+                let (offset, _) = piece.as_str().char_indices().nth(char_idx).unwrap();
+                return byte_idx + offset;
+            }
+            char_idx -= piece.chars;
+            byte_idx += piece.len();
+        }
+        byte_idx
+    }
+
+    /// Returns the index of the character starting at the given byte index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte_idx` is out of bounds or not on a character boundary.
+    #[must_use]
+    pub fn byte_to_char(&self, mut byte_idx: usize) -> usize {
+        self.assert_char_boundary(byte_idx);
+
+        let mut char_idx = 0;
+        for piece in &self.pieces {
+            if byte_idx < piece.len() {
+                return char_idx + piece.as_str()[..byte_idx].chars().count();
+            }
+            byte_idx -= piece.len();
+            char_idx += piece.chars;
+        }
+        char_idx
+    }
+
+    /// Copies the text in the given byte range into a new `String`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or either end isn't on a character boundary.
+    #[must_use]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> String {
+        let (start, end) = self.bounds(range);
+        let mut slice = String::with_capacity(end - start);
+        let mut offset = 0;
+        for chunk in self.chunks() {
+            let (from, to) = (start.max(offset), end.min(offset + chunk.len()));
+            if from < to {
+                slice.push_str(&chunk[from - offset..to - offset]);
+            }
+            offset += chunk.len();
+        }
+        slice
+    }
+
+    /// Inserts the given text at the given byte index. Only the chunk containing the index is
+    /// touched, or split up if the text doesn't fit into it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds or not on a character boundary.
+    pub fn insert_str(&mut self, idx: usize, text: &str) {
+        self.cursor_at(idx).insert_str(text);
+    }
+
+    /// Inserts the given character at the given byte index, see [`Self::insert_str`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds or not on a character boundary.
+    pub fn insert_char(&mut self, idx: usize, ch: char) {
+        self.insert_str(idx, ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Removes the text in the given byte range. Chunks which become empty are removed, and
+    /// what's left of the last touched chunk is merged into its neighbour if both fit into
+    /// one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or either end isn't on a character boundary.
+    pub fn remove<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, end) = self.bounds(range);
+        self.cursor_at(start).remove_forward(end - start);
+    }
+
+    /// Creates a cursor at the given byte index, for editing the text around it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds or not on a character boundary.
+    pub fn cursor_at(&mut self, idx: usize) -> TextCursor<'_> {
+        let mut cursor = TextCursor {
+            piece: self.pieces.cursor_mut_front(),
+            offset: 0,
+            position: 0,
+            len: &mut self.len,
+            chars: &mut self.chars,
+        };
+        cursor.move_to(idx);
+        cursor
+    }
+
+    fn assert_char_boundary(&self, idx: usize) {
+        assert!(
+            self.is_char_boundary(idx),
+            "byte index {idx} is out of bounds or not a char boundary of the text"
+        );
+    }
+
+    fn bounds<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end, "range starts at {start} but ends at {end}");
+        self.assert_char_boundary(start);
+        self.assert_char_boundary(end);
+        (start, end)
+    }
+
+    /// Finds the chunk containing the given byte index, returning a cursor pointing at it and
+    /// the offset of the index inside of it. An index between two chunks is placed at the end
+    /// of the earlier one. Returns `None` if the text is empty or the index out of bounds.
+    fn locate(&self, mut idx: usize) -> Option<(cursor::Cursor<'_, Piece>, usize)> {
+        if self.len < idx {
+            return None;
+        }
+        let mut cursor = self.pieces.cursor_front();
+        loop {
+            let piece = cursor.current()?;
+            if idx <= piece.len() {
+                return Some((cursor, idx));
+            }
+            idx -= piece.len();
+            cursor.move_next();
+        }
+    }
+
+    /// Merges the current chunk into the previous one, if both fit into one chunk together.
+    /// Returns by how many bytes the start of the chunk the cursor points at moved back, which
+    /// is the length of the previous chunk if they were merged, and 0 otherwise.
+    fn merge_with_prev(cursor: &mut cursor::CursorMut<'_, Piece>) -> usize {
+        let (Some(index), Some(current)) = (cursor.index(), cursor.current()) else {
+            return 0;
+        };
+        let len = current.len();
+        if index == 0 {
+            return 0;
+        }
+        cursor.move_prev();
+        let prev_len = cursor.current().unwrap().len();
+        if prev_len + len > CHUNK {
+            cursor.move_next();
+            return 0;
+        }
+
+        cursor.move_next();
+        let current = cursor.remove_current().unwrap();
+        // the cursor moved on to the next chunk, unless there is none
+        if cursor.index() == Some(index) {
+            cursor.move_prev();
+        }
+        let prev = cursor.current_mut().unwrap();
+        *prev = Piece::new(&[prev.as_str(), current.as_str()].concat());
+        prev_len
+    }
+}
+
+impl Clone for TextList {
+    fn clone(&self) -> Self {
+        Self {
+            pieces: self.pieces.clone(),
+            len: self.len,
+            chars: self.chars,
+        }
+    }
+}
+
+impl fmt::Debug for TextList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+impl fmt::Display for TextList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chunks().try_for_each(|chunk| f.write_str(chunk))
+    }
+}
+
+impl Default for TextList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for TextList {
+    fn from(value: &str) -> Self {
+        Self {
+            pieces: pieces(value).collect(),
+            len: value.len(),
+            chars: value.chars().count(),
+        }
+    }
+}
+
+impl PartialEq for TextList {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.chars().eq(other.chars())
+    }
+}
+
+impl Eq for TextList {}
+
+impl PartialEq<str> for TextList {
+    fn eq(&self, other: &str) -> bool {
+        self.len == other.len() && self.chars().eq(other.chars())
+    }
+}
+
+impl PartialEq<&str> for TextList {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+/// Iterator through the chunks of a [`TextList`].
+#[derive(Clone)]
+pub struct Chunks<'text> {
+    inner: iter::Iter<'text, Piece>,
+}
+
+impl fmt::Debug for Chunks<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Chunks")
+            .field(&iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'text> Iterator for Chunks<'text> {
+    type Item = &'text str;
+
+    fn next(&mut self) -> Option<&'text str> {
+        self.inner.next().map(Piece::as_str)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Chunks<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(Piece::as_str)
+    }
+}
+
+impl ExactSizeIterator for Chunks<'_> {}
+
+impl FusedIterator for Chunks<'_> {}
+
+/// Iterator through the characters of a [`TextList`].
+#[derive(Clone)]
+pub struct Chars<'text> {
+    chunks: Chunks<'text>,
+    current: str::Chars<'text>,
+}
+
+impl fmt::Debug for Chars<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Chars")
+            .field(&iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(ch) = self.current.next() {
+                return Some(ch);
+            }
+            self.current = self.chunks.next()?.chars();
+        }
+    }
+}
+
+impl FusedIterator for Chars<'_> {}
+
+/// A cursor into a [`TextList`], sitting between two characters like the caret of an editor.
+///
+/// It keeps track of the chunk it's in, so moving it and editing around it only ever touches
+/// the chunks nearby, instead of searching the text from the start each time.
+pub struct TextCursor<'text> {
+    /// The chunk containing the cursor. A cursor between two chunks may be at the end of the
+    /// earlier or the start of the later one.
+    piece: cursor::CursorMut<'text, Piece>,
+    /// Byte index of the cursor inside the current chunk.
+    offset: usize,
+    position: usize,
+    len: &'text mut usize,
+    chars: &'text mut usize,
+}
+
+impl TextCursor<'_> {
+    /// Returns the byte index of this cursor.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the character right after this cursor, if any.
+    pub fn char_after(&self) -> Option<char> {
+        let current = self.piece.current()?.as_str();
+        if self.offset < current.len() {
+            return current[self.offset..].chars().next();
+        }
+        // at the end of a chunk, the next one has the character
+        if self.piece.remaining_len() == 0 {
+            return None;
+        }
+        let mut next = self.piece.cursor();
+        next.move_next();
+        next.current().unwrap().as_str().chars().next()
+    }
+
+    /// Returns the character right before this cursor, if any.
+    pub fn char_before(&self) -> Option<char> {
+        let current = self.piece.current()?.as_str();
+        if self.offset > 0 {
+            return current[..self.offset].chars().next_back();
+        }
+        // at the start of a chunk, the previous one has the character
+        if self.piece.remaining_len_back() == 0 {
+            return None;
+        }
+        let mut prev = self.piece.cursor();
+        prev.move_prev();
+        prev.current().unwrap().as_str().chars().next_back()
+    }
+
+    /// Moves this cursor one character to the left. Returns `false` if it's at the start
+    /// already.
+    pub fn move_left(&mut self) -> bool {
+        let Some(ch) = self.char_before() else {
+            return false;
+        };
+        if self.offset == 0 {
+            self.piece.move_prev();
+            self.offset = self.piece_len();
+        }
+        self.offset -= ch.len_utf8();
+        self.position -= ch.len_utf8();
+        true
+    }
+
+    /// Moves this cursor one character to the right. Returns `false` if it's at the end
+    /// already.
+    pub fn move_right(&mut self) -> bool {
+        let Some(ch) = self.char_after() else {
+            return false;
+        };
+        if self.offset == self.piece_len() {
+            self.piece.move_next();
+            self.offset = 0;
+        }
+        self.offset += ch.len_utf8();
+        self.position += ch.len_utf8();
+        true
+    }
+
+    /// Moves this cursor to the given byte index, skipping whole chunks from whichever is
+    /// closest: the current chunk, the start or the end of the text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds or not on a character boundary.
+    pub fn move_to(&mut self, idx: usize) {
+        let len = *self.len;
+        if len < idx {
+            Self::not_a_boundary(idx);
+        }
+        if self.piece.current().is_none() {
+            return;
+        }
+
+        let from_here = self.position.abs_diff(idx);
+        let from_start = idx;
+        let from_end = len - idx;
+        let mut start = self.position - self.offset;
+        if from_start < from_here && from_start <= from_end {
+            self.piece.move_to(0);
+            start = 0;
+        } else if from_end < from_here {
+            let last = self.piece.remaining_len_back() + self.piece.remaining_len();
+            self.piece.move_to(last);
+            start = len - self.piece_len();
+        }
+
+        while idx < start {
+            self.piece.move_prev();
+            start -= self.piece_len();
+        }
+        while start + self.piece_len() < idx {
+            start += self.piece_len();
+            self.piece.move_next();
+        }
+
+        let offset = idx - start;
+        if !self
+            .piece
+            .current()
+            .unwrap()
+            .as_str()
+            .is_char_boundary(offset)
+        {
+            // stay consistent with the chunk the cursor is in now
+            self.offset = 0;
+            self.position = start;
+            Self::not_a_boundary(idx);
+        }
+        self.offset = offset;
+        self.position = idx;
+    }
+
+    /// Inserts the given text at this cursor, and moves the cursor behind it, like typing.
+    /// Only the current chunk is touched, or split up if the text doesn't fit into it.
+    pub fn insert_str(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        *self.len += text.len();
+        *self.chars += text.chars().count();
+
+        let old = self.piece.current().map_or("", Piece::as_str);
+        let mut combined = String::with_capacity(old.len() + text.len());
+        combined.push_str(&old[..self.offset]);
+        combined.push_str(text);
+        combined.push_str(&old[self.offset..]);
+
+        let mut replacement = pieces(&combined);
+        let first = replacement.next().unwrap();
+        match self.piece.current_mut() {
+            Some(piece) => *piece = first,
+            None => {
+                self.piece.insert_after(first);
+            }
+        }
+        for piece in replacement {
+            self.piece.insert_after(piece);
+            self.piece.move_next();
+        }
+
+        // walk back from the last new chunk to the end of the inserted text
+        let mut from_end = combined.len() - self.offset - text.len();
+        while self.piece_len() < from_end {
+            from_end -= self.piece_len();
+            self.piece.move_prev();
+        }
+        self.offset = self.piece_len() - from_end;
+        self.position += text.len();
+    }
+
+    /// Inserts the given character at this cursor, and moves the cursor behind it.
+    pub fn insert_char(&mut self, ch: char) {
+        self.insert_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Removes the character before this cursor and returns it, like backspace.
+    pub fn delete_backward(&mut self) -> Option<char> {
+        let ch = self.char_before()?;
+        self.move_left();
+        self.remove_forward(ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Removes the character after this cursor and returns it, like the delete key.
+    pub fn delete_forward(&mut self) -> Option<char> {
+        let ch = self.char_after()?;
+        self.remove_forward(ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Removes the given number of bytes after this cursor, which must end on a character
+    /// boundary. Chunks which become empty are removed, and what's left of the last touched
+    /// chunk is merged into its neighbour if both fit into one.
+    fn remove_forward(&mut self, count: usize) {
+        if count == 0 {
+            return;
+        }
+        if self.offset == self.piece_len() {
+            self.piece.move_next();
+            self.offset = 0;
+        }
+
+        let mut start = self.position - self.offset;
+        let mut offset = self.offset;
+        let mut removed_chars = 0;
+        let mut remaining = count;
+        while remaining > 0 {
+            let piece = self.piece.current_mut().unwrap();
+            let take = remaining.min(piece.len() - offset);
+            let old = piece.as_str();
+            removed_chars += old[offset..offset + take].chars().count();
+            let kept = [&old[..offset], &old[offset + take..]].concat();
+            remaining -= take;
+            offset = 0;
+
+            if kept.is_empty() {
+                let was_last = self.piece.remaining_len() == 0;
+                self.piece.remove_current();
+                // at the end, the cursor moved back onto the previous chunk
+                if was_last {
+                    start -= self.piece_len();
+                }
+            } else {
+                *piece = Piece::new(&kept);
+                if remaining > 0 {
+                    start += kept.len();
+                    self.piece.move_next();
+                }
+            }
+        }
+        start -= TextList::merge_with_prev(&mut self.piece);
+
+        *self.len -= count;
+        *self.chars -= removed_chars;
+        self.offset = self.position - start;
+    }
+
+    /// Returns the length of the current chunk in bytes, or 0 if the text is empty.
+    fn piece_len(&self) -> usize {
+        self.piece.current().map_or(0, Piece::len)
+    }
+
+    fn not_a_boundary(idx: usize) -> ! {
+        panic!("byte index {idx} is out of bounds or not a char boundary of the text");
+    }
+}
+
+impl fmt::Debug for TextCursor<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextCursor")
+            .field("position", &self.position)
+            .finish_non_exhaustive()
+    }
+}
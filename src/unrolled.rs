@@ -0,0 +1,671 @@
+//! An unrolled variant of the list, storing up to `N` elements per node.
+//!
+//! Each node of an [`UnrolledList`] is a small inline array (a "chunk") instead of a single
+//! element. This means way fewer allocations and pointer chases while iterating, as well as less
+//! memory overhead for the `prev`/`next` pointers, at the cost of having to shift elements
+//! around inside a chunk on insertion and removal.
+//!
+//! The chunks themselves are stored in a plain [`ReversibleList`], so they also benefit from its
+//! node pool and allocator support.
+//!
+//! - Pushing to a full chunk at either end of the list creates a new chunk.
+//! - Inserting into a full chunk in the middle of the list splits it into two half-full ones.
+//! - Once a chunk drops below half full through removal, it's merged with a neighbour if both
+//!   fit into one, and takes over some of the neighbour's elements otherwise.
+//!
+//! Indexing and cursor jumps skip whole chunks, starting from the current chunk or either end of
+//! the list, whichever is closest.
+
+use std::{fmt, iter::FusedIterator, ops::Deref};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use crate::{chunk::Chunk, cursor, iter, ReversibleList};
+
+/// A list storing up to `N` elements per node.
+///
+/// See the module docs for details.
+pub struct UnrolledList<T, const N: usize, A: Allocator = Global> {
+    chunks: ReversibleList<Chunk<T, N>, A>,
+    len: usize,
+}
+
+impl<T, const N: usize> UnrolledList<T, N> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, const N: usize, A: Allocator> UnrolledList<T, N, A> {
+    /// Creates an empty list which allocates its chunks using the given allocator.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N` is 0, since chunks couldn't hold any elements then.
+    #[must_use]
+    pub fn new_in(alloc: A) -> Self {
+        const { assert!(N > 0, "chunks need to be able to hold at least one element") };
+
+        Self {
+            chunks: ReversibleList::new_in(alloc),
+            len: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns how many chunks, in effect nodes, this list currently consists of.
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns an iterator through this list.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            inner: self.chunks.iter().flat_map(Chunk::as_slice),
+            remaining: self.len,
+        }
+    }
+
+    /// Creates a cursor pointing at the **first** element in the list.
+    pub fn cursor_front(&self) -> Cursor<'_, T, N, A> {
+        Cursor {
+            chunk: self.chunks.cursor_front(),
+            offset: 0,
+            index: 0,
+            len: self.len,
+        }
+    }
+
+    /// Creates a cursor pointing at the **last** element in the list.
+    pub fn cursor_back(&self) -> Cursor<'_, T, N, A> {
+        let chunk = self.chunks.cursor_back();
+        Cursor {
+            offset: chunk.current().map_or(0, |chunk| chunk.len - 1),
+            chunk,
+            index: self.len.saturating_sub(1),
+            len: self.len,
+        }
+    }
+
+    /// Creates a cursor pointing at the element with the given index in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger or equal to the length of the list.
+    pub fn cursor_at(&self, idx: usize) -> Cursor<'_, T, N, A> {
+        let mut cursor = self.cursor_front();
+        cursor.move_to(idx);
+        cursor
+    }
+
+    /// Creates a mutable cursor pointing at the **first** element in the list.
+    pub fn cursor_mut_front(&mut self) -> CursorMut<'_, T, N, A> {
+        CursorMut {
+            chunk: self.chunks.cursor_mut_front(),
+            offset: 0,
+            index: 0,
+            len: &mut self.len,
+        }
+    }
+
+    /// Creates a mutable cursor pointing at the **last** element in the list.
+    pub fn cursor_mut_back(&mut self) -> CursorMut<'_, T, N, A> {
+        let chunk = self.chunks.cursor_mut_back();
+        CursorMut {
+            offset: chunk.current().map_or(0, |chunk| chunk.len - 1),
+            chunk,
+            index: self.len.saturating_sub(1),
+            len: &mut self.len,
+        }
+    }
+
+    /// Creates a mutable cursor pointing at the element with the given index in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger or equal to the length of the list.
+    pub fn cursor_mut_at(&mut self, idx: usize) -> CursorMut<'_, T, N, A> {
+        let mut cursor = self.cursor_mut_front();
+        cursor.move_to(idx);
+        cursor
+    }
+
+    /// Returns the element at the given index, or `None` if it is out of range. Takes _O_(_n_ /
+    /// _N_), since only whole chunks need to be skipped, starting from whichever end of the
+    /// list is closer.
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        locate(self.chunks.iter(), self.len, idx).map(|(chunk, offset)| &chunk.as_slice()[offset])
+    }
+
+    /// Returns a mutable reference to the element at the given index, or `None` if it is out
+    /// of range. Takes _O_(_n_ / _N_), just like [`Self::get`].
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        locate(self.chunks.iter_mut(), self.len, idx)
+            .map(|(chunk, offset)| &mut chunk.as_mut_slice()[offset])
+    }
+
+    /// Inserts the given item before the first element of the list.
+    pub fn push_front(&mut self, item: T) {
+        let mut front = self.chunks.cursor_mut_front();
+        match front.current_mut() {
+            Some(chunk) if !chunk.is_full() => chunk.insert(0, item),
+            _ => {
+                let mut chunk = Chunk::new();
+                chunk.insert(0, item);
+                self.chunks.push_front(chunk);
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Appends the given item to the end of the list.
+    pub fn push_back(&mut self, item: T) {
+        let mut back = self.chunks.cursor_mut_back();
+        match back.current_mut() {
+            Some(chunk) if !chunk.is_full() => chunk.insert(chunk.len, item),
+            _ => {
+                let mut chunk = Chunk::new();
+                chunk.insert(0, item);
+                self.chunks.push_back(chunk);
+            }
+        }
+        self.len += 1;
+    }
+
+    /// Removes the element at the beginning of the list.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.cursor_mut_front().remove_current()
+    }
+
+    /// Removes the element at the end of the list.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.cursor_mut_back().remove_current()
+    }
+
+    /// Inserts the given item at the given index, shifting all elements after it by one. If
+    /// the chunk at that index is full, it's split into two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the list.
+    pub fn insert(&mut self, idx: usize, item: T) {
+        if self.len < idx {
+            panic!("tried to insert at index {idx} but the len is {}", self.len);
+        }
+        if idx == self.len {
+            self.push_back(item);
+            return;
+        }
+
+        self.cursor_mut_at(idx).insert_before(item);
+    }
+
+    /// Removes and returns the element at the given index, shifting all elements after it by
+    /// one. If its chunk drops below half full, it's merged with or refilled from a neighbour.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger or equal to the length of the list.
+    pub fn remove(&mut self, idx: usize) -> T {
+        if self.len <= idx {
+            panic!("tried to remove index {idx} but the len is {}", self.len);
+        }
+
+        self.cursor_mut_at(idx)
+            .remove_current()
+            .expect("index should be in range after checking it")
+    }
+}
+
+/// Finds the chunk containing the given index, walking from whichever end of the list is closer,
+/// and returns it along with the offset of the index inside of it. Returns `None` if `idx` is out
+/// of range.
+fn locate<C, T, const N: usize>(
+    mut chunks: impl DoubleEndedIterator<Item = C>,
+    len: usize,
+    idx: usize,
+) -> Option<(C, usize)>
+where
+    C: Deref<Target = Chunk<T, N>>,
+{
+    if len <= idx {
+        return None;
+    }
+
+    if idx < len / 2 {
+        let mut offset = idx;
+        chunks.find_map(|chunk| {
+            if offset < chunk.len {
+                return Some((chunk, offset));
+            }
+            offset -= chunk.len;
+            None
+        })
+    } else {
+        let mut from_back = len - idx;
+        chunks.rev().find_map(|chunk| {
+            if from_back <= chunk.len {
+                let offset = chunk.len - from_back;
+                return Some((chunk, offset));
+            }
+            from_back -= chunk.len;
+            None
+        })
+    }
+}
+
+/// Keeps the chunk the given cursor points at from dropping below half full, by merging it with a
+/// neighbour if both fit into one, or by evening out their lengths otherwise. Removes it if it's
+/// empty and the only one.
+///
+/// `start` is the index of the first element in the current chunk. Afterwards, the cursor points
+/// at the first chunk of the pair that was balanced, and the index of its first element is
+/// returned.
+fn rebalance<T, const N: usize, A: Allocator>(
+    chunks: &mut cursor::CursorMut<'_, Chunk<T, N>, A>,
+    mut start: usize,
+) -> usize {
+    let Some(current) = chunks.current() else {
+        return start;
+    };
+    if current.len != 0 && N / 2 <= current.len {
+        return start;
+    }
+
+    // the last chunk is paired with the one before it instead
+    if chunks.remaining_len() == 0 {
+        if chunks.remaining_len_back() == 0 {
+            if current.len == 0 {
+                chunks.remove_current();
+            }
+            return start;
+        }
+        chunks.move_prev();
+        start -= chunks.current().map_or(0, |chunk| chunk.len);
+    }
+
+    let (left, right) = chunks
+        .current_and_next_mut()
+        .expect("a chunk should follow after pairing");
+    if left.len + right.len <= N {
+        left.take_front_of(right, right.len);
+        let right_was_last = chunks.remaining_len() == 1;
+        chunks.move_next();
+        chunks.remove_current();
+        if !right_was_last {
+            chunks.move_prev();
+        }
+    } else if left.len < right.len {
+        left.take_front_of(right, (right.len - left.len) / 2);
+    } else {
+        right.take_back_of(left, (left.len - right.len) / 2);
+    }
+    start
+}
+
+/// Turns a chunk into the slice of its initialized items.
+type ChunkToSlice<'list, T, const N: usize> = fn(&'list Chunk<T, N>) -> &'list [T];
+
+/// Iterator through an [`UnrolledList`].
+pub struct Iter<'list, T: 'list, const N: usize> {
+    inner:
+        std::iter::FlatMap<iter::Iter<'list, Chunk<T, N>>, &'list [T], ChunkToSlice<'list, T, N>>,
+    remaining: usize,
+}
+
+impl<T, const N: usize> Clone for Iter<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Iter<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'list, T: 'list, const N: usize> Iterator for Iter<'list, T, N> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'list, T: 'list, const N: usize> DoubleEndedIterator for Iter<'list, T, N> {
+    fn next_back(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        self.inner.next_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Iter<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for Iter<'_, T, N> {}
+
+macro_rules! impl_common_cursor {
+    ($name:ident) => {
+        impl<'a, T: 'a, const N: usize, A: Allocator> $name<'a, T, N, A> {
+            /// Returns the element the cursor points at, or `None` if the list is empty.
+            pub fn current(&self) -> Option<&T> {
+                self.chunk.current()?.as_slice().get(self.offset)
+            }
+
+            /// Returns the index of the current element, or `None` if the list is empty.
+            pub fn index(&self) -> Option<usize> {
+                (self.list_len() != 0).then_some(self.index)
+            }
+
+            /// Makes this cursor look at the **previous** element in the list. If there is
+            /// none, the cursor will point at the **end** of the list. Does nothing if the list
+            /// is empty.
+            pub fn move_prev(&mut self) {
+                if self.list_len() == 0 {
+                    return;
+                }
+
+                if self.offset > 0 {
+                    self.offset -= 1;
+                } else {
+                    self.chunk.move_prev();
+                    self.offset = self.chunk_len() - 1;
+                }
+                self.index = self.index.checked_sub(1).unwrap_or(self.list_len() - 1);
+            }
+
+            /// Makes this cursor look at the **next** element in the list. If there is none,
+            /// the cursor will point at the **beginning** of the list. Does nothing if the list
+            /// is empty.
+            pub fn move_next(&mut self) {
+                if self.list_len() == 0 {
+                    return;
+                }
+
+                if self.offset + 1 < self.chunk_len() {
+                    self.offset += 1;
+                } else {
+                    self.chunk.move_next();
+                    self.offset = 0;
+                }
+                self.index = if self.index + 1 == self.list_len() {
+                    0
+                } else {
+                    self.index + 1
+                };
+            }
+
+            /// Moves this cursor `n` elements backward. Note that wrapping behavior still
+            /// applies.
+            pub fn move_prev_n(&mut self, n: usize) {
+                let len = self.list_len();
+                if len == 0 {
+                    return;
+                }
+                self.move_to((self.index + len - n % len) % len);
+            }
+
+            /// Moves this cursor `n` elements forward. Note that wrapping behavior still
+            /// applies.
+            pub fn move_next_n(&mut self, n: usize) {
+                let len = self.list_len();
+                if len == 0 {
+                    return;
+                }
+                self.move_to((self.index + n % len) % len);
+            }
+
+            /// Moves this cursor to the given absolute list index, skipping whole chunks from
+            /// whichever is closest: the current chunk, the start or the end of the list.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the given `target_idx` is invalid (in effect larger or equal to the
+            /// length of the list).
+            pub fn move_to(&mut self, target_idx: usize) {
+                let len = self.list_len();
+                if len <= target_idx {
+                    panic!("tried to move to index {target_idx} but the len is {len}");
+                }
+
+                let from_here = self.index.abs_diff(target_idx);
+                let from_start = target_idx;
+                let from_end = len - 1 - target_idx;
+                let mut start = self.index - self.offset;
+                if from_start < from_here && from_start <= from_end {
+                    self.chunk.move_to(0);
+                    start = 0;
+                } else if from_end < from_here {
+                    let last = self.chunk.remaining_len_back() + self.chunk.remaining_len();
+                    self.chunk.move_to(last);
+                    start = len - self.chunk_len();
+                }
+
+                // the target is in bounds, so walking straight to it never wraps
+                while target_idx < start {
+                    self.chunk.move_prev();
+                    start -= self.chunk_len();
+                }
+                while start + self.chunk_len() <= target_idx {
+                    start += self.chunk_len();
+                    self.chunk.move_next();
+                }
+                self.offset = target_idx - start;
+                self.index = target_idx;
+            }
+
+            /// Returns the length of the current chunk, or 0 if the list is empty.
+            fn chunk_len(&self) -> usize {
+                self.chunk.current().map_or(0, |chunk| chunk.len)
+            }
+        }
+    };
+}
+
+/// Cursor into an [`UnrolledList`], behaving just like [`crate::cursor::Cursor`].
+pub struct Cursor<'a, T, const N: usize, A: Allocator = Global> {
+    chunk: cursor::Cursor<'a, Chunk<T, N>, A>,
+    /// Index of the current element inside its chunk.
+    offset: usize,
+    index: usize,
+    len: usize,
+}
+
+impl_common_cursor!(Cursor);
+
+impl<'a, T: 'a, const N: usize, A: Allocator> Cursor<'a, T, N, A> {
+    fn list_len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Mutable cursor into an [`UnrolledList`], behaving just like [`crate::cursor::CursorMut`].
+pub struct CursorMut<'a, T, const N: usize, A: Allocator = Global> {
+    chunk: cursor::CursorMut<'a, Chunk<T, N>, A>,
+    /// Index of the current element inside its chunk.
+    offset: usize,
+    index: usize,
+    len: &'a mut usize,
+}
+
+impl_common_cursor!(CursorMut);
+
+impl<'a, T: 'a, const N: usize, A: Allocator> CursorMut<'a, T, N, A> {
+    fn list_len(&self) -> usize {
+        *self.len
+    }
+
+    /// Returns a mutable reference to the element the cursor points at, or `None` if the list
+    /// is empty.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.chunk
+            .current_mut()?
+            .as_mut_slice()
+            .get_mut(self.offset)
+    }
+
+    /// Returns an immutable [`Cursor`] at the same position as this cursor. Note that this cursor
+    /// will be frozen and unusable until the returned cursor (and all its clones and returned
+    /// references) have been dropped.
+    pub fn cursor(&self) -> Cursor<'_, T, N, A> {
+        Cursor {
+            chunk: self.chunk.cursor(),
+            offset: self.offset,
+            index: self.index,
+            len: *self.len,
+        }
+    }
+
+    /// Inserts the given item **after** the current element. If the list is empty, the cursor
+    /// will point at the new element afterwards.
+    pub fn insert_after(&mut self, item: T) {
+        self.insert_at(self.offset + 1, item);
+    }
+
+    /// Inserts the given item **before** the current element, so the cursor moves one index
+    /// forward. If the list is empty, the cursor will point at the new element afterwards.
+    pub fn insert_before(&mut self, item: T) {
+        self.insert_at(self.offset, item);
+    }
+
+    /// Inserts the given item at `at` in the current chunk, splitting the chunk if it's full.
+    /// The cursor stays on the same element.
+    fn insert_at(&mut self, at: usize, item: T) {
+        let Some(chunk) = self.chunk.current_mut() else {
+            let mut chunk = Chunk::new();
+            chunk.insert(0, item);
+            self.chunk.insert_after(chunk);
+            *self.len = 1;
+            return;
+        };
+
+        let start = self.index - self.offset;
+        if at == N {
+            // appending to a full chunk, which a new one does just as well
+            let mut next = Chunk::new();
+            next.insert(0, item);
+            self.chunk.insert_after(next);
+        } else if chunk.is_full() {
+            let mut upper = chunk.split_off_half();
+            if at <= chunk.len {
+                chunk.insert(at, item);
+            } else {
+                upper.insert(at - chunk.len, item);
+            }
+            self.chunk.insert_after(upper);
+        } else {
+            chunk.insert(at, item);
+        }
+        *self.len += 1;
+
+        if at <= self.offset {
+            self.index += 1;
+        }
+        self.offset = self.index - start;
+        self.settle();
+    }
+
+    /// Removes the current element and returns it. Returns `None` if the list is empty. If its
+    /// chunk drops below half full, it's merged with or refilled from a neighbour.
+    ///
+    /// - If there is an element **after** the removed one, the cursor will point at that one.
+    /// - If the cursor is at the end of the list, the cursor will point at the element
+    ///   **before** the removed one.
+    /// - If the list only contains **one** element, the cursor will point "nowhere", since
+    ///   the list will be empty.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let item = self.chunk.current_mut()?.remove(self.offset);
+        *self.len -= 1;
+
+        let start = rebalance(&mut self.chunk, self.index - self.offset);
+        if self.index == *self.len {
+            self.index = self.index.saturating_sub(1);
+        }
+        self.offset = self.index - start;
+        self.settle();
+        Some(item)
+    }
+
+    /// Moves the chunk cursor forward until `offset` is inside the current chunk.
+    fn settle(&mut self) {
+        while let Some(chunk) = self.chunk.current() {
+            if self.offset < chunk.len {
+                break;
+            }
+            self.offset -= chunk.len;
+            self.chunk.move_next();
+        }
+    }
+}
+
+impl<T: Clone, const N: usize, A: Allocator + Clone> Clone for UnrolledList<T, N, A> {
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, A: Allocator> fmt::Debug for UnrolledList<T, N, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, A: Allocator> Extend<T> for UnrolledList<T, N, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for UnrolledList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: PartialEq, const N: usize, A: Allocator> PartialEq for UnrolledList<T, N, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize, A: Allocator> Eq for UnrolledList<T, N, A> {}
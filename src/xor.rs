@@ -0,0 +1,509 @@
+//! A doubly linked list storing only one combined link per node.
+//!
+//! Each node of an [`XorList`] stores `prev ^ next` instead of both links. Knowing one
+//! neighbour is enough to get to the other one, so walking from either end works just like in
+//! [`ReversibleList`](crate::ReversibleList), while the link overhead per node is halved. For
+//! huge lists of small elements, that's a considerable part of the memory.
+//!
+//! The catch is that a node alone doesn't tell where its neighbours are, so there are no
+//! handles and no _O_(1) removal of arbitrary nodes. Cursors know the node they came from
+//! though, so they can insert and remove in _O_(1) just fine. As a bonus, reversing the whole
+//! list is _O_(1), since only the ends need to be swapped.
+//!
+//! Nodes are stored in one growable slab and linked by their index in it, rather than by
+//! address. XOR-ing addresses would lose the provenance of the pointers, which makes it
+//! impossible to reason about under Rust's memory model. Removed nodes are reused by later
+//! insertions.
+
+use std::{fmt, iter::FusedIterator, mem::MaybeUninit};
+
+/// Index of a node in the slab, plus one. `0` means "no node".
+type Link = usize;
+
+const NONE: Link = 0;
+
+struct Slot<T> {
+    /// `prev ^ next` for live nodes, and the next free slot for free ones.
+    link: Link,
+    /// Initialized exactly for live nodes.
+    data: MaybeUninit<T>,
+}
+
+/// A doubly linked list with one XOR-combined link per node.
+///
+/// See the module docs for details.
+pub struct XorList<T> {
+    slots: Vec<Slot<T>>,
+    start: Link,
+    end: Link,
+    free: Link,
+    len: usize,
+}
+
+impl<T> XorList<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty list with room for at least `capacity` elements before reallocating.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            start: NONE,
+            end: NONE,
+            free: NONE,
+            len: 0,
+        }
+    }
+
+    /// Returns how many elements fit into the list before it needs to reallocate.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the first element, if any.
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        (self.start != NONE).then(|| self.data(self.start))
+    }
+
+    /// Returns the last element, if any.
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        (self.end != NONE).then(|| self.data(self.end))
+    }
+
+    /// Returns an iterator through this list.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            forward: (NONE, self.start),
+            backward: (NONE, self.end),
+            remaining: self.len,
+        }
+    }
+
+    /// Inserts the given item before the first element of the list, in _O_(1).
+    pub fn push_front(&mut self, item: T) {
+        self.link_between(NONE, self.start, item);
+    }
+
+    /// Appends the given item to the end of the list, in _O_(1).
+    pub fn push_back(&mut self, item: T) {
+        self.link_between(self.end, NONE, item);
+    }
+
+    /// Removes the element at the beginning of the list, in _O_(1).
+    pub fn pop_front(&mut self) -> Option<T> {
+        (self.start != NONE).then(|| self.unlink(NONE, self.start))
+    }
+
+    /// Removes the element at the end of the list, in _O_(1).
+    pub fn pop_back(&mut self) -> Option<T> {
+        (self.end != NONE).then(|| self.unlink(self.slot(self.end).link, self.end))
+    }
+
+    /// Reverses the order of all elements, in _O_(1).
+    pub fn reverse(&mut self) {
+        std::mem::swap(&mut self.start, &mut self.end);
+    }
+
+    /// Creates a cursor pointing at the **first** node in the list.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            prev: NONE,
+            node: self.start,
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Creates a cursor pointing at the **last** node in the list.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            prev: self.end_prev(),
+            node: self.end,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    pub fn cursor_mut_front(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            prev: NONE,
+            node: self.start,
+            index: 0,
+            list: self,
+        }
+    }
+
+    pub fn cursor_mut_back(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            prev: self.end_prev(),
+            node: self.end,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    fn slot(&self, link: Link) -> &Slot<T> {
+        &self.slots[link - 1]
+    }
+
+    fn slot_mut(&mut self, link: Link) -> &mut Slot<T> {
+        &mut self.slots[link - 1]
+    }
+
+    fn data(&self, link: Link) -> &T {
+        // SAFETY: Only ever called for live nodes, whose data is initialized.
+        unsafe { self.slot(link).data.assume_init_ref() }
+    }
+
+    /// Returns the node before the last one, or `NONE` if there's none.
+    fn end_prev(&self) -> Link {
+        match self.end {
+            NONE => NONE,
+            end => self.slot(end).link,
+        }
+    }
+
+    /// Given a node and one of its neighbours, returns the other neighbour.
+    fn other_neighbour(&self, node: Link, neighbour: Link) -> Link {
+        self.slot(node).link ^ neighbour
+    }
+
+    /// Stores the given item in a free slot, or a new one if there's none.
+    fn allocate(&mut self, item: T) -> Link {
+        if self.free == NONE {
+            self.slots.push(Slot {
+                link: NONE,
+                data: MaybeUninit::new(item),
+            });
+            return self.slots.len();
+        }
+
+        let node = self.free;
+        let slot = self.slot_mut(node);
+        let next_free = slot.link;
+        slot.data = MaybeUninit::new(item);
+        self.free = next_free;
+        node
+    }
+
+    /// Links a new node holding `item` between the two given adjacent nodes, either of which
+    /// may be `NONE` for the respective end of the list. Returns the new node.
+    fn link_between(&mut self, before: Link, after: Link, item: T) -> Link {
+        let node = self.allocate(item);
+        self.slot_mut(node).link = before ^ after;
+
+        match before {
+            NONE => self.start = node,
+            before => self.slot_mut(before).link ^= after ^ node,
+        }
+        match after {
+            NONE => self.end = node,
+            after => self.slot_mut(after).link ^= before ^ node,
+        }
+        self.len += 1;
+        node
+    }
+
+    /// Unlinks the given node, whose previous node is `before`, and returns its data.
+    fn unlink(&mut self, before: Link, node: Link) -> T {
+        let after = self.other_neighbour(node, before);
+
+        match before {
+            NONE => self.start = after,
+            before => self.slot_mut(before).link ^= node ^ after,
+        }
+        match after {
+            NONE => self.end = before,
+            after => self.slot_mut(after).link ^= node ^ before,
+        }
+        self.len -= 1;
+
+        let free = self.free;
+        let slot = self.slot_mut(node);
+        slot.link = free;
+        // SAFETY: The node was live up until now, and its slot is marked as free right after,
+        //         so the data is moved out exactly once.
+        let data = unsafe { slot.data.assume_init_read() };
+        self.free = node;
+        data
+    }
+}
+
+impl<T> Drop for XorList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T: Clone> Clone for XorList<T> {
+    fn clone(&self) -> Self {
+        let mut list = Self::with_capacity(self.len);
+        list.extend(self.iter().cloned());
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for XorList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for XorList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for XorList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for XorList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'list, T> IntoIterator for &'list XorList<T> {
+    type Item = &'list T;
+    type IntoIter = Iter<'list, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for XorList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for XorList<T> {}
+
+/// Iterator through an [`XorList`].
+pub struct Iter<'list, T> {
+    list: &'list XorList<T>,
+    /// The node walked from and the node to yield next, from the front.
+    forward: (Link, Link),
+    /// Same as `forward`, but from the back.
+    backward: (Link, Link),
+    remaining: usize,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list,
+            forward: self.forward,
+            backward: self.backward,
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Iter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Iter")
+            .field(&crate::iter::Remaining(self.clone()))
+            .finish()
+    }
+}
+
+impl<'list, T> Iterator for Iter<'list, T> {
+    type Item = &'list T;
+
+    fn next(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let (prev, node) = self.forward;
+        self.forward = (node, self.list.other_neighbour(node, prev));
+        Some(self.list.data(node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'list, T> DoubleEndedIterator for Iter<'list, T> {
+    fn next_back(&mut self) -> Option<&'list T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let (next, node) = self.backward;
+        self.backward = (node, self.list.other_neighbour(node, next));
+        Some(self.list.data(node))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+macro_rules! impl_common_cursor {
+    ($name:ident) => {
+        impl<'list, T> $name<'list, T> {
+            /// Returns the data stored on the current node, or `None` if the list is empty.
+            pub fn current(&self) -> Option<&T> {
+                (self.node != NONE).then(|| self.list.data(self.node))
+            }
+
+            /// Returns the index of the current node, or `None` if the list is empty.
+            pub fn index(&self) -> Option<usize> {
+                (self.node != NONE).then_some(self.index)
+            }
+
+            /// Makes this cursor look at the **previous** node in the list. If there is none, the
+            /// cursor will point at the **end** of the list. Does nothing if the list is empty.
+            pub fn move_prev(&mut self) {
+                if self.node == NONE {
+                    return;
+                }
+
+                if self.prev == NONE {
+                    self.node = self.list.end;
+                    self.prev = self.list.end_prev();
+                    self.index = self.list.len - 1;
+                } else {
+                    let prev_prev = self.list.other_neighbour(self.prev, self.node);
+                    self.node = self.prev;
+                    self.prev = prev_prev;
+                    self.index -= 1;
+                }
+            }
+
+            /// Makes this cursor look at the **next** node in the list. If there is none, the
+            /// cursor will point at the **beginning** of the list. Does nothing if the list is
+            /// empty.
+            pub fn move_next(&mut self) {
+                if self.node == NONE {
+                    return;
+                }
+
+                match self.list.other_neighbour(self.node, self.prev) {
+                    NONE => {
+                        self.node = self.list.start;
+                        self.prev = NONE;
+                        self.index = 0;
+                    }
+                    next => {
+                        self.prev = self.node;
+                        self.node = next;
+                        self.index += 1;
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Immutable cursor into an [`XorList`], like [`crate::cursor::Cursor`].
+pub struct Cursor<'list, T> {
+    /// The node before the current one. Needed to find out the next one.
+    prev: Link,
+    node: Link,
+    index: usize,
+    list: &'list XorList<T>,
+}
+
+impl_common_cursor!(Cursor);
+
+/// Mutable cursor into an [`XorList`], like [`crate::cursor::CursorMut`].
+pub struct CursorMut<'list, T> {
+    prev: Link,
+    node: Link,
+    index: usize,
+    list: &'list mut XorList<T>,
+}
+
+impl_common_cursor!(CursorMut);
+
+impl<T> CursorMut<'_, T> {
+    /// Returns a mutable reference to the data stored on the current node, or `None` if the
+    /// list is empty.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        if self.node == NONE {
+            return None;
+        }
+        // SAFETY: The current node is live, so its data is initialized.
+        Some(unsafe { self.list.slot_mut(self.node).data.assume_init_mut() })
+    }
+
+    /// Inserts the given item **after** the current node, in _O_(1).
+    pub fn insert_after(&mut self, item: T) {
+        if self.node == NONE {
+            self.node = self.list.link_between(NONE, NONE, item);
+            return;
+        }
+
+        let next = self.list.other_neighbour(self.node, self.prev);
+        self.list.link_between(self.node, next, item);
+    }
+
+    /// Inserts the given item **before** the current node, in _O_(1).
+    pub fn insert_before(&mut self, item: T) {
+        if self.node == NONE {
+            self.node = self.list.link_between(NONE, NONE, item);
+            return;
+        }
+
+        self.prev = self.list.link_between(self.prev, self.node, item);
+        self.index += 1;
+    }
+
+    /// Removes the current node and returns its data, in _O_(1). Returns `None` if the list is
+    /// empty.
+    ///
+    /// Afterwards, the cursor points at the node after the removed one, or the one before it if
+    /// there is none, just like [`crate::cursor::CursorMut::remove_current`].
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.node == NONE {
+            return None;
+        }
+
+        let next = self.list.other_neighbour(self.node, self.prev);
+        let data = self.list.unlink(self.prev, self.node);
+        if next != NONE {
+            self.node = next;
+        } else {
+            // the previous node is the last one now, so its link is just its own previous one
+            self.node = self.prev;
+            self.prev = match self.node {
+                NONE => NONE,
+                node => self.list.slot(node).link,
+            };
+            self.index = self.index.saturating_sub(1);
+        }
+        Some(data)
+    }
+}